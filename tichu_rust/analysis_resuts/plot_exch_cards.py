@@ -3,6 +3,8 @@ import matplotlib.pyplot as plt
 
 # Generate probabilities that sum to 1.0
 np.random.seed(42)
+# Exchange card distributions of the exchange analysis, copied from exchange_stats.txt and exchange_stats_filtered.txt.
+# They move to exchange_stats{,_filtered}.json once those reports are generated with --format json.
 probs = [
     # Dim 1: Normal stats /filtered stats /// Dim 2. Normal round / gt Round // Dim 3. Left in partner in, right in
     [
@@ -1,6 +1,7 @@
 from typing import Literal
 
 import  numpy as np
+from report_loader import load_entry
 
 MIN_ROUNDS = 300
 
@@ -31,6 +32,8 @@ hand_categories_absolute_frequencies_all = [482320623240, 192928249296, 19292824
 num_hands = 5804731963800
 assert sum(hand_categories_absolute_frequencies_all) == num_hands
 
+# Results of the gt and gt-win-probs analyses on the database (Dim 1: Normal stats / filtered stats), copied from earlier runs.
+# They move to gt_stats{,_filtered}.json and gt_win_probs{,_filtered}.json once those reports are generated with --format json.
 gt_call_rounds_by_cat = [
     [[56806, 51236, 45293, 48292], [10448, 9467, 8524, 8793], [8524, 7678, 6754, 7434], [1384, 1305, 1103, 1285], [136912, 100454, 112337, 97009], [22902, 17035, 19054, 16426], [20242, 15380, 16927, 14715], [3134, 2407, 2589, 2417], [119173, 91714, 97398, 88540], [19533, 14984, 15762, 14414], [18331, 14448, 14902, 14065], [2874, 2265, 2335, 2289], [110153, 84409, 99568, 84970], [15375, 12042, 14154, 11961], [15543, 12055, 13913, 12052], [1808, 1365, 1734, 1423], [91692, 77090, 74111, 73970], [14415, 12182, 11899, 11788], [13250, 11560, 10833, 11207], [2053, 1853, 1607, 1788], [277245, 194084, 241844, 191527], [38447, 26761, 33029, 26577], [38376, 27397, 33274, 26590], [4377, 3231, 3905, 3204], [242059, 178622, 208373, 176316], [34270, 25135, 29345, 24728], [35231, 26379, 30309, 25863], [4259, 3271, 3737, 3178], [78191, 65304, 72833, 65307], [8965, 7451, 8272, 7570], [9000, 7366, 8381, 7414], [819, 685, 781, 661], [109683, 89715, 90571, 86597], [15430, 12545, 12501, 12081], [15549, 13433, 12756, 12895], [2069, 1737, 1707, 1647], [102020, 81765, 93749, 82205], [11505, 9433, 10720, 9330], [11509, 9051, 10517, 9330], [1038, 808, 904, 885], [104093, 86494, 96495, 86780], [11619, 9697, 10988, 9805], [11762, 9844, 11156, 10028], [1059, 932, 1009, 951], [14845, 12878, 14250, 13114], [1322, 1175, 1245, 1158], [1302, 1152, 1297, 1114], [92, 75, 82, 87], [45061, 39437, 40808, 38678], [5182, 4405, 4715, 4468], [5252, 4430, 4787, 4494], [439, 394, 426, 426], [9451, 8210, 8801, 8124], [813, 732, 808, 718], [818, 742, 815, 732], [57, 50, 59, 51], [9729, 8519, 9143, 8532], [852, 779, 874, 732], [849, 755, 876, 716], [56, 47, 55, 50], [915, 800, 839, 862], [68, 54, 50, 52], [65, 42, 51, 55], [1, 1, 0, 4], [2021, 1759, 1892, 1780], [180, 150, 168, 179], [167, 162, 176, 173], [16, 10, 9, 11], [222, 189, 199, 193], [14, 8, 14, 8], [14, 12, 14, 14], [2, 1, 1, 1], [205, 188, 229, 216], [14, 9, 13, 14], [12, 18, 12, 8], [1, 1, 0, 2], [15, 9, 14, 19], [0, 1, 0, 1], [0, 2, 2, 0], [0, 0, 0, 0]],
     [[1598, 1894, 1367, 1811], [325, 363, 251, 334], [254, 327, 205, 269], [39, 56, 23, 48], [4398, 5313, 3848, 4753], [717, 907, 657, 851], [721, 810, 626, 753], [108, 104, 98, 100], [3647, 4319, 3162, 4067], [619, 711, 512, 659], [475, 621, 466, 574], [80, 91, 56, 101], [2940, 2876, 2804, 2827], [412, 399, 376, 394], [455, 396, 381, 405], [48, 52, 50, 41], [2762, 3127, 2370, 2999], [430, 550, 362, 473], [425, 461, 364, 427], [74, 66, 71, 67], [8509, 8701, 7933, 8534], [1238, 1223, 1096, 1147], [1180, 1265, 1107, 1140], [133, 137, 128, 138], [6936, 7372, 6486, 7060], [1025, 1025, 925, 979], [1017, 1058, 887, 1004], [121, 114, 107, 115], [1874, 1748, 1784, 1770], [199, 190, 184, 204], [233, 188, 188, 210], [21, 30, 18, 23], [2725, 2907, 2308, 2717], [387, 415, 331, 365], [380, 401, 308, 352], [39, 60, 23, 39], [2410, 2480, 2426, 2476], [260, 285, 274, 273], [286, 259, 244, 259], [29, 25, 23, 17], [2450, 2416, 2370, 2359], [286, 240, 313, 286], [262, 282, 296, 282], [38, 28, 25, 33], [353, 306, 313, 309], [29, 25, 23, 27], [29, 29, 35, 20], [2, 2, 1, 4], [907, 912, 822, 898], [110, 115, 96, 95], [97, 112, 106, 86], [12, 4, 10, 6], [208, 211, 207, 193], [23, 19, 28, 17], [11, 11, 13, 22], [1, 0, 3, 0], [188, 186, 192, 216], [14, 19, 29, 6], [16, 22, 17, 22], [2, 0, 1, 4], [18, 15, 19, 20], [3, 1, 2, 1], [2, 1, 2, 2], [0, 0, 0, 0], [40, 42, 41, 31], [4, 5, 3, 3], [3, 7, 3, 7], [1, 0, 0, 0], [10, 3, 6, 5], [0, 0, 0, 0], [1, 0, 1, 0], [0, 0, 0, 0], [6, 5, 9, 3], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 1, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]]
@@ -77,7 +80,7 @@ round_score_count_by_cat_first14_nongt = [
 round_scores = [5*x-55 for x in range(93)]
 
 
-transition_matrix = [[0.3127878618380522, 0.05362077631509467, 0.05362077631509467, 0.007447330043763148, 0.05362077631509467, 0.007447330043763148, 0.007447330043763148, 0.0008051167614879079, 0.05362077631509467, 0.007447330043763148, 0.007447330043763148, 0.0008051167614879079, 0.007447330043763148, 0.0008051167614879079, 0.0008051167614879079, 6.356184959115063e-5, 0.21448310526037867, 0.02978932017505259, 0.02978932017505259, 0.0032204670459516317, 0.02978932017505259, 0.0032204670459516317, 0.0032204670459516317, 0.0002542473983646025, 0.02978932017505259, 0.0032204670459516317, 0.0032204670459516317, 0.0002542473983646025, 0.0032204670459516317, 0.0002542473983646025, 0.0002542473983646025, 1.3038328121261667e-5, 0.04468398026257889, 0.004830700568927447, 0.004830700568927447, 0.0003813710975469037, 0.004830700568927447, 0.0003813710975469037, 0.0003813710975469037, 1.95574921818925e-5, 0.004830700568927447, 0.0003813710975469037, 0.0003813710975469037, 1.95574921818925e-5, 0.0003813710975469037, 1.95574921818925e-5, 1.95574921818925e-5, 4.889373045473125e-7, 0.0032204670459516317, 0.00025424739836460246, 0.00025424739836460246, 1.3038328121261665e-5, 0.00025424739836460246, 1.3038328121261665e-5, 1.3038328121261665e-5, 3.2595820303154164e-7, 0.00025424739836460246, 1.3038328121261665e-5, 1.3038328121261665e-5, 3.2595820303154164e-7, 1.3038328121261665e-5, 3.2595820303154164e-7, 3.2595820303154164e-7, 0.0, 6.356184959115062e-5, 3.259582030315416e-6, 3.259582030315416e-6, 8.148955075788544e-8, 3.259582030315416e-6, 8.148955075788544e-8, 8.148955075788544e-8, 0.0, 3.259582030315416e-6, 8.148955075788544e-8, 8.148955075788544e-8, 0.0, 8.148955075788544e-8, 0.0, 0.0, 0.0], [0.0, 0.3664086381531469, 0.0, 0.061068106358857815, 0.0, 0.061068106358857815, 0.0, 0.008252446805251055, 0.0, 0.061068106358857815, 0.0, 0.008252446805251055, 0.0, 0.008252446805251055, 0.0, 0.0008686786110790586, 0.0, 0.24427242543543126, 0.0, 0.03300978722100422, 0.0, 0.03300978722100422, 0.0, 0.0034747144443162343, 0.0, 0.03300978722100422, 0.0, 0.0034747144443162343, 0.0, 0.0034747144443162343, 0.0, 0.00026728572648586417, 0.0, 0.049514680831506334, 0.0, 0.00521207166647435, 0.0, 0.00521207166647435, 0.0, 0.0004009285897287962, 0.0, 0.00521207166647435, 0.0, 0.0004009285897287962, 0.0, 0.0004009285897287962, 0.0, 2.0046429486439815e-5, 0.0, 0.0034747144443162334, 0.0, 0.00026728572648586417, 0.0, 0.00026728572648586417, 0.0, 1.3364286324293206e-5, 0.0, 0.00026728572648586417, 0.0, 1.3364286324293206e-5, 0.0, 1.3364286324293206e-5, 0.0, 3.2595820303154164e-7, 0.0, 6.682143162146604e-5, 0.0, 3.3410715810733025e-6, 0.0, 3.3410715810733025e-6, 0.0, 8.148955075788544e-8, 0.0, 3.3410715810733025e-6, 0.0, 8.148955075788544e-8, 0.0, 8.148955075788544e-8, 0.0, 0.0], [0.0, 0.0, 0.3664086381531469, 0.061068106358857815, 0.0, 0.0, 0.061068106358857815, 0.008252446805251055, 0.0, 0.0, 0.061068106358857815, 0.008252446805251055, 0.0, 0.0, 0.008252446805251055, 0.0008686786110790586, 0.0, 0.0, 0.24427242543543126, 0.03300978722100422, 0.0, 0.0, 0.03300978722100422, 0.0034747144443162343, 0.0, 0.0, 0.03300978722100422, 0.0034747144443162343, 0.0, 0.0, 0.0034747144443162343, 0.00026728572648586417, 0.0, 0.0, 0.049514680831506334, 0.00521207166647435, 0.0, 0.0, 0.00521207166647435, 0.0004009285897287962, 0.0, 0.0, 0.00521207166647435, 0.0004009285897287962, 0.0, 0.0, 0.0004009285897287962, 2.0046429486439815e-5, 0.0, 0.0, 0.0034747144443162334, 0.00026728572648586417, 0.0, 0.0, 0.00026728572648586417, 1.3364286324293206e-5, 0.0, 0.0, 0.00026728572648586417, 1.3364286324293206e-5, 0.0, 0.0, 1.3364286324293206e-5, 3.2595820303154164e-7, 0.0, 0.0, 6.682143162146604e-5, 3.3410715810733025e-6, 0.0, 0.0, 3.3410715810733025e-6, 8.148955075788544e-8, 0.0, 0.0, 3.3410715810733025e-6, 8.148955075788544e-8, 0.0, 0.0, 8.148955075788544e-8, 0.0], [0.0, 0.0, 0.0, 0.42747674451200474, 0.0, 0.0, 0.0, 0.06932055316410886, 0.0, 0.0, 0.0, 0.06932055316410886, 0.0, 0.0, 0.0, 0.009121125416330114, 0.0, 0.0, 0.0, 0.27728221265643543, 0.0, 0.0, 0.0, 0.036484501665320454, 0.0, 0.0, 0.0, 0.036484501665320454, 0.0, 0.0, 0.0, 0.003742000170802098, 0.0, 0.0, 0.0, 0.054726752497980674, 0.0, 0.0, 0.0, 0.005613000256203147, 0.0, 0.0, 0.0, 0.005613000256203147, 0.0, 0.0, 0.0, 0.00042097501921523615, 0.0, 0.0, 0.0, 0.0037420001708020987, 0.0, 0.0, 0.0, 0.00028065001281015736, 0.0, 0.0, 0.0, 0.00028065001281015736, 0.0, 0.0, 0.0, 1.3690244527324749e-5, 0.0, 0.0, 0.0, 7.016250320253934e-5, 0.0, 0.0, 0.0, 3.4225611318311873e-6, 0.0, 0.0, 0.0, 3.4225611318311873e-6, 0.0, 0.0, 0.0, 8.148955075788544e-8], [0.0, 0.0, 0.0, 0.0, 0.3664086381531469, 0.061068106358857815, 0.061068106358857815, 0.008252446805251055, 0.0, 0.0, 0.0, 0.0, 0.061068106358857815, 0.008252446805251055, 0.008252446805251055, 0.0008686786110790586, 0.0, 0.0, 0.0, 0.0, 0.24427242543543126, 0.03300978722100422, 0.03300978722100422, 0.0034747144443162343, 0.0, 0.0, 0.0, 0.0, 0.03300978722100422, 0.0034747144443162343, 0.0034747144443162343, 0.00026728572648586417, 0.0, 0.0, 0.0, 0.0, 0.049514680831506334, 0.00521207166647435, 0.00521207166647435, 0.0004009285897287962, 0.0, 0.0, 0.0, 0.0, 0.00521207166647435, 0.0004009285897287962, 0.0004009285897287962, 2.0046429486439815e-5, 0.0, 0.0, 0.0, 0.0, 0.0034747144443162334, 0.00026728572648586417, 0.00026728572648586417, 1.3364286324293206e-5, 0.0, 0.0, 0.0, 0.0, 0.00026728572648586417, 1.3364286324293206e-5, 1.3364286324293206e-5, 3.2595820303154164e-7, 0.0, 0.0, 0.0, 0.0, 6.682143162146604e-5, 3.3410715810733025e-6, 3.3410715810733025e-6, 8.148955075788544e-8, 0.0, 0.0, 0.0, 0.0, 3.3410715810733025e-6, 8.148955075788544e-8, 8.148955075788544e-8, 0.0], [0.0, 0.0, 0.0, 0.0, 0.0, 0.42747674451200474, 0.0, 0.06932055316410886, 0.0, 0.0, 0.0, 0.0, 0.0, 0.06932055316410886, 0.0, 0.009121125416330114, 0.0, 0.0, 0.0, 0.0, 0.0, 0.27728221265643543, 0.0, 0.036484501665320454, 0.0, 0.0, 0.0, 0.0, 0.0, 0.036484501665320454, 0.0, 0.003742000170802098, 0.0, 0.0, 0.0, 0.0, 0.0, 0.054726752497980674, 0.0, 0.005613000256203147, 0.0, 0.0, 0.0, 0.0, 0.0, 0.005613000256203147, 0.0, 0.00042097501921523615, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0037420001708020987, 0.0, 0.00028065001281015736, 0.0, 0.0, 0.0, 0.0, 0.0, 0.00028065001281015736, 0.0, 1.3690244527324749e-5, 0.0, 0.0, 0.0, 0.0, 0.0, 7.016250320253934e-5, 0.0, 3.4225611318311873e-6, 0.0, 0.0, 0.0, 0.0, 0.0, 3.4225611318311873e-6, 0.0, 8.148955075788544e-8], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.42747674451200474, 0.06932055316410886, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.06932055316410886, 0.009121125416330114, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.27728221265643543, 0.036484501665320454, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.036484501665320454, 0.003742000170802098, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.054726752497980674, 0.005613000256203147, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.005613000256203147, 0.00042097501921523615, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0037420001708020987, 0.00028065001281015736, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.00028065001281015736, 1.3690244527324749e-5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.016250320253934e-5, 3.4225611318311873e-6, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 3.4225611318311873e-6, 8.148955075788544e-8], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.07844167858043899, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.31376671432175596, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.04022650183612256, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.06033975275418384, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.006033975275418385, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0040226501836122565, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0002943402573374821, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.358506433437052e-5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.3664086381531469, 0.061068106358857815, 0.061068106358857815, 0.008252446805251055, 0.061068106358857815, 0.008252446805251055, 0.008252446805251055, 0.0008686786110790586, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.24427242543543126, 0.03300978722100422, 0.03300978722100422, 0.0034747144443162343, 0.03300978722100422, 0.0034747144443162343, 0.0034747144443162343, 0.00026728572648586417, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.049514680831506334, 0.00521207166647435, 0.00521207166647435, 0.0004009285897287962, 0.00521207166647435, 0.0004009285897287962, 0.0004009285897287962, 2.0046429486439815e-5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0034747144443162334, 0.00026728572648586417, 0.00026728572648586417, 1.3364286324293206e-5, 0.00026728572648586417, 1.3364286324293206e-5, 1.3364286324293206e-5, 3.2595820303154164e-7, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 6.682143162146604e-5, 3.3410715810733025e-6, 3.3410715810733025e-6, 8.148955075788544e-8, 3.3410715810733025e-6, 8.148955075788544e-8, 8.148955075788544e-8, 0.0], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.42747674451200474, 0.0, 0.06932055316410886, 0.0, 0.06932055316410886, 0.0, 0.009121125416330114, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.27728221265643543, 0.0, 0.036484501665320454, 0.0, 0.036484501665320454, 0.0, 0.003742000170802098, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.054726752497980674, 0.0, 0.005613000256203147, 0.0, 0.005613000256203147, 0.0, 0.00042097501921523615, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0037420001708020987, 0.0, 0.00028065001281015736, 0.0, 0.00028065001281015736, 0.0, 1.3690244527324749e-5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.016250320253934e-5, 0.0, 3.4225611318311873e-6, 0.0, 3.4225611318311873e-6, 0.0, 8.148955075788544e-8], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.42747674451200474, 0.06932055316410886, 0.0, 0.0, 0.06932055316410886, 0.009121125416330114, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.27728221265643543, 0.036484501665320454, 0.0, 0.0, 0.036484501665320454, 0.003742000170802098, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.054726752497980674, 0.005613000256203147, 0.0, 0.0, 0.005613000256203147, 0.00042097501921523615, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0037420001708020987, 0.00028065001281015736, 0.0, 0.0, 0.00028065001281015736, 1.3690244527324749e-5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.016250320253934e-5, 3.4225611318311873e-6, 0.0, 0.0, 3.4225611318311873e-6, 8.148955075788544e-8], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.0, 0.0, 0.0, 0.07844167858043899, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.31376671432175596, 0.0, 0.0, 0.0, 0.04022650183612256, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.06033975275418384, 0.0, 0.0, 0.0, 0.006033975275418385, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0040226501836122565, 0.0, 0.0, 0.0, 0.0002943402573374821, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.358506433437052e-5, 0.0, 0.0, 0.0, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.42747674451200474, 0.06932055316410886, 0.06932055316410886, 0.009121125416330114, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.27728221265643543, 0.036484501665320454, 0.036484501665320454, 0.003742000170802098, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.054726752497980674, 0.005613000256203147, 0.005613000256203147, 0.00042097501921523615, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0037420001708020987, 0.00028065001281015736, 0.00028065001281015736, 1.3690244527324749e-5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.016250320253934e-5, 3.4225611318311873e-6, 3.4225611318311873e-6, 8.148955075788544e-8], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.0, 0.07844167858043899, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.31376671432175596, 0.0, 0.04022650183612256, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.06033975275418384, 0.0, 0.006033975275418385, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0040226501836122565, 0.0, 0.0002943402573374821, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.358506433437052e-5, 0.0, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.07844167858043899, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.31376671432175596, 0.04022650183612256, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.06033975275418384, 0.006033975275418385, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0040226501836122565, 0.0002943402573374821, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.358506433437052e-5, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.35399321615787854, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.06637372802960223, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0043169904409497365, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.3664086381531469, 0.061068106358857815, 0.061068106358857815, 0.008252446805251055, 0.061068106358857815, 0.008252446805251055, 0.008252446805251055, 0.0008686786110790586, 0.061068106358857815, 0.008252446805251055, 0.008252446805251055, 0.0008686786110790586, 0.008252446805251055, 0.0008686786110790586, 0.0008686786110790586, 6.682143162146604e-5, 0.18320431907657345, 0.024757340415753167, 0.024757340415753167, 0.0026060358332371755, 0.024757340415753167, 0.0026060358332371755, 0.0026060358332371755, 0.0002004642948643981, 0.024757340415753167, 0.0026060358332371755, 0.0026060358332371755, 0.0002004642948643981, 0.0026060358332371755, 0.0002004642948643981, 0.0002004642948643981, 1.0023214743219907e-5, 0.024757340415753164, 0.0026060358332371755, 0.0026060358332371755, 0.0002004642948643981, 0.0026060358332371755, 0.0002004642948643981, 0.0002004642948643981, 1.0023214743219909e-5, 0.0026060358332371755, 0.0002004642948643981, 0.0002004642948643981, 1.0023214743219909e-5, 0.0002004642948643981, 1.0023214743219909e-5, 1.0023214743219909e-5, 2.444686522736563e-7, 0.0008686786110790585, 6.682143162146603e-5, 6.682143162146603e-5, 3.3410715810733025e-6, 6.682143162146603e-5, 3.3410715810733025e-6, 3.3410715810733025e-6, 8.148955075788544e-8, 6.682143162146603e-5, 3.3410715810733025e-6, 3.3410715810733025e-6, 8.148955075788544e-8, 3.3410715810733025e-6, 8.148955075788544e-8, 8.148955075788544e-8, 0.0], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.42747674451200474, 0.0, 0.06932055316410886, 0.0, 0.06932055316410886, 0.0, 0.009121125416330114, 0.0, 0.06932055316410886, 0.0, 0.009121125416330114, 0.0, 0.009121125416330114, 0.0, 0.0009355000427005245, 0.0, 0.20796165949232662, 0.0, 0.02736337624899034, 0.0, 0.02736337624899034, 0.0, 0.0028065001281015736, 0.0, 0.02736337624899034, 0.0, 0.0028065001281015736, 0.0, 0.0028065001281015736, 0.0, 0.00021048750960761807, 0.0, 0.02736337624899034, 0.0, 0.0028065001281015736, 0.0, 0.0028065001281015736, 0.0, 0.00021048750960761807, 0.0, 0.0028065001281015736, 0.0, 0.00021048750960761807, 0.0, 0.00021048750960761807, 0.0, 1.0267683395493561e-5, 0.0, 0.0009355000427005246, 0.0, 7.016250320253935e-5, 0.0, 7.016250320253935e-5, 0.0, 3.4225611318311873e-6, 0.0, 7.016250320253935e-5, 0.0, 3.4225611318311873e-6, 0.0, 3.4225611318311873e-6, 0.0, 8.148955075788544e-8], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.42747674451200474, 0.06932055316410886, 0.0, 0.0, 0.06932055316410886, 0.009121125416330114, 0.0, 0.0, 0.06932055316410886, 0.009121125416330114, 0.0, 0.0, 0.009121125416330114, 0.0009355000427005245, 0.0, 0.0, 0.20796165949232662, 0.02736337624899034, 0.0, 0.0, 0.02736337624899034, 0.0028065001281015736, 0.0, 0.0, 0.02736337624899034, 0.0028065001281015736, 0.0, 0.0, 0.0028065001281015736, 0.00021048750960761807, 0.0, 0.0, 0.02736337624899034, 0.0028065001281015736, 0.0, 0.0, 0.0028065001281015736, 0.00021048750960761807, 0.0, 0.0, 0.0028065001281015736, 0.00021048750960761807, 0.0, 0.0, 0.00021048750960761807, 1.0267683395493561e-5, 0.0, 0.0, 0.0009355000427005246, 7.016250320253935e-5, 0.0, 0.0, 7.016250320253935e-5, 3.4225611318311873e-6, 0.0, 0.0, 7.016250320253935e-5, 3.4225611318311873e-6, 0.0, 0.0, 3.4225611318311873e-6, 8.148955075788544e-8], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.0, 0.0, 0.0, 0.07844167858043899, 0.0, 0.0, 0.0, 0.07844167858043899, 0.0, 0.0, 0.0, 0.01005662545903064, 0.0, 0.0, 0.0, 0.2353250357413169, 0.0, 0.0, 0.0, 0.03016987637709192, 0.0, 0.0, 0.0, 0.03016987637709192, 0.0, 0.0, 0.0, 0.0030169876377091924, 0.0, 0.0, 0.0, 0.03016987637709192, 0.0, 0.0, 0.0, 0.0030169876377091924, 0.0, 0.0, 0.0, 0.0030169876377091924, 0.0, 0.0, 0.0, 0.00022075519300311156, 0.0, 0.0, 0.0, 0.0010056625459030641, 0.0, 0.0, 0.0, 7.358506433437052e-5, 0.0, 0.0, 0.0, 7.358506433437052e-5, 0.0, 0.0, 0.0, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.42747674451200474, 0.06932055316410886, 0.06932055316410886, 0.009121125416330114, 0.0, 0.0, 0.0, 0.0, 0.06932055316410886, 0.009121125416330114, 0.009121125416330114, 0.0009355000427005245, 0.0, 0.0, 0.0, 0.0, 0.20796165949232662, 0.02736337624899034, 0.02736337624899034, 0.0028065001281015736, 0.0, 0.0, 0.0, 0.0, 0.02736337624899034, 0.0028065001281015736, 0.0028065001281015736, 0.00021048750960761807, 0.0, 0.0, 0.0, 0.0, 0.02736337624899034, 0.0028065001281015736, 0.0028065001281015736, 0.00021048750960761807, 0.0, 0.0, 0.0, 0.0, 0.0028065001281015736, 0.00021048750960761807, 0.00021048750960761807, 1.0267683395493561e-5, 0.0, 0.0, 0.0, 0.0, 0.0009355000427005246, 7.016250320253935e-5, 7.016250320253935e-5, 3.4225611318311873e-6, 0.0, 0.0, 0.0, 0.0, 7.016250320253935e-5, 3.4225611318311873e-6, 3.4225611318311873e-6, 8.148955075788544e-8], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.0, 0.07844167858043899, 0.0, 0.0, 0.0, 0.0, 0.0, 0.07844167858043899, 0.0, 0.01005662545903064, 0.0, 0.0, 0.0, 0.0, 0.0, 0.2353250357413169, 0.0, 0.03016987637709192, 0.0, 0.0, 0.0, 0.0, 0.0, 0.03016987637709192, 0.0, 0.0030169876377091924, 0.0, 0.0, 0.0, 0.0, 0.0, 0.03016987637709192, 0.0, 0.0030169876377091924, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0030169876377091924, 0.0, 0.00022075519300311156, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0010056625459030641, 0.0, 7.358506433437052e-5, 0.0, 0.0, 0.0, 0.0, 0.0, 7.358506433437052e-5, 0.0, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.07844167858043899, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.07844167858043899, 0.01005662545903064, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.2353250357413169, 0.03016987637709192, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.03016987637709192, 0.0030169876377091924, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.03016987637709192, 0.0030169876377091924, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0030169876377091924, 0.00022075519300311156, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0010056625459030641, 7.358506433437052e-5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.358506433437052e-5, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.08849830403946964, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.26549491211840887, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.033186864014801115, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.033186864014801115, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.003237742830712303, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0010792476102374343, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.42747674451200474, 0.06932055316410886, 0.06932055316410886, 0.009121125416330114, 0.06932055316410886, 0.009121125416330114, 0.009121125416330114, 0.0009355000427005245, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.20796165949232662, 0.02736337624899034, 0.02736337624899034, 0.0028065001281015736, 0.02736337624899034, 0.0028065001281015736, 0.0028065001281015736, 0.00021048750960761807, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.02736337624899034, 0.0028065001281015736, 0.0028065001281015736, 0.00021048750960761807, 0.0028065001281015736, 0.00021048750960761807, 0.00021048750960761807, 1.0267683395493561e-5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0009355000427005246, 7.016250320253935e-5, 7.016250320253935e-5, 3.4225611318311873e-6, 7.016250320253935e-5, 3.4225611318311873e-6, 3.4225611318311873e-6, 8.148955075788544e-8], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.0, 0.07844167858043899, 0.0, 0.07844167858043899, 0.0, 0.01005662545903064, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.2353250357413169, 0.0, 0.03016987637709192, 0.0, 0.03016987637709192, 0.0, 0.0030169876377091924, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.03016987637709192, 0.0, 0.0030169876377091924, 0.0, 0.0030169876377091924, 0.0, 0.00022075519300311156, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0010056625459030641, 0.0, 7.358506433437052e-5, 0.0, 7.358506433437052e-5, 0.0, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.07844167858043899, 0.0, 0.0, 0.07844167858043899, 0.01005662545903064, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.2353250357413169, 0.03016987637709192, 0.0, 0.0, 0.03016987637709192, 0.0030169876377091924, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.03016987637709192, 0.0030169876377091924, 0.0, 0.0, 0.0030169876377091924, 0.00022075519300311156, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0010056625459030641, 7.358506433437052e-5, 0.0, 0.0, 7.358506433437052e-5, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.0, 0.0, 0.0, 0.08849830403946964, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.26549491211840887, 0.0, 0.0, 0.0, 0.033186864014801115, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.033186864014801115, 0.0, 0.0, 0.0, 0.003237742830712303, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0010792476102374343, 0.0, 0.0, 0.0, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.07844167858043899, 0.07844167858043899, 0.01005662545903064, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.2353250357413169, 0.03016987637709192, 0.03016987637709192, 0.0030169876377091924, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.03016987637709192, 0.0030169876377091924, 0.0030169876377091924, 0.00022075519300311156, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0010056625459030641, 7.358506433437052e-5, 7.358506433437052e-5, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.0, 0.08849830403946964, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.26549491211840887, 0.0, 0.033186864014801115, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.033186864014801115, 0.0, 0.003237742830712303, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0010792476102374343, 0.0, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.08849830403946964, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.26549491211840887, 0.033186864014801115, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.033186864014801115, 0.003237742830712303, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0010792476102374343, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.29868177613321006, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.03642460684551341, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.42747674451200474, 0.06932055316410886, 0.06932055316410886, 0.009121125416330114, 0.06932055316410886, 0.009121125416330114, 0.009121125416330114, 0.0009355000427005245, 0.06932055316410886, 0.009121125416330114, 0.009121125416330114, 0.0009355000427005245, 0.009121125416330114, 0.0009355000427005245, 0.0009355000427005245, 7.016250320253935e-5, 0.13864110632821772, 0.018242250832660227, 0.018242250832660227, 0.001871000085401049, 0.018242250832660227, 0.001871000085401049, 0.001871000085401049, 0.0001403250064050787, 0.018242250832660227, 0.001871000085401049, 0.001871000085401049, 0.0001403250064050787, 0.001871000085401049, 0.0001403250064050787, 0.0001403250064050787, 6.8451222636623746e-6, 0.009121125416330114, 0.0009355000427005245, 0.0009355000427005245, 7.016250320253935e-5, 0.0009355000427005245, 7.016250320253935e-5, 7.016250320253935e-5, 3.4225611318311873e-6, 0.0009355000427005245, 7.016250320253935e-5, 7.016250320253935e-5, 3.4225611318311873e-6, 7.016250320253935e-5, 3.4225611318311873e-6, 3.4225611318311873e-6, 8.148955075788544e-8], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.0, 0.07844167858043899, 0.0, 0.07844167858043899, 0.0, 0.01005662545903064, 0.0, 0.07844167858043899, 0.0, 0.01005662545903064, 0.0, 0.01005662545903064, 0.0, 0.0010056625459030641, 0.0, 0.15688335716087798, 0.0, 0.02011325091806128, 0.0, 0.02011325091806128, 0.0, 0.0020113250918061282, 0.0, 0.02011325091806128, 0.0, 0.0020113250918061282, 0.0, 0.0020113250918061282, 0.0, 0.00014717012866874104, 0.0, 0.01005662545903064, 0.0, 0.0010056625459030641, 0.0, 0.0010056625459030641, 0.0, 7.358506433437052e-5, 0.0, 0.0010056625459030641, 0.0, 7.358506433437052e-5, 0.0, 7.358506433437052e-5, 0.0, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.07844167858043899, 0.0, 0.0, 0.07844167858043899, 0.01005662545903064, 0.0, 0.0, 0.07844167858043899, 0.01005662545903064, 0.0, 0.0, 0.01005662545903064, 0.0010056625459030641, 0.0, 0.0, 0.15688335716087798, 0.02011325091806128, 0.0, 0.0, 0.02011325091806128, 0.0020113250918061282, 0.0, 0.0, 0.02011325091806128, 0.0020113250918061282, 0.0, 0.0, 0.0020113250918061282, 0.00014717012866874104, 0.0, 0.0, 0.01005662545903064, 0.0010056625459030641, 0.0, 0.0, 0.0010056625459030641, 7.358506433437052e-5, 0.0, 0.0, 0.0010056625459030641, 7.358506433437052e-5, 0.0, 0.0, 7.358506433437052e-5, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.0, 0.0, 0.0, 0.08849830403946964, 0.0, 0.0, 0.0, 0.08849830403946964, 0.0, 0.0, 0.0, 0.011062288004933706, 0.0, 0.0, 0.0, 0.17699660807893927, 0.0, 0.0, 0.0, 0.022124576009867412, 0.0, 0.0, 0.0, 0.022124576009867412, 0.0, 0.0, 0.0, 0.0021584952204748683, 0.0, 0.0, 0.0, 0.011062288004933706, 0.0, 0.0, 0.0, 0.0010792476102374341, 0.0, 0.0, 0.0, 0.0010792476102374341, 0.0, 0.0, 0.0, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.07844167858043899, 0.07844167858043899, 0.01005662545903064, 0.0, 0.0, 0.0, 0.0, 0.07844167858043899, 0.01005662545903064, 0.01005662545903064, 0.0010056625459030641, 0.0, 0.0, 0.0, 0.0, 0.15688335716087798, 0.02011325091806128, 0.02011325091806128, 0.0020113250918061282, 0.0, 0.0, 0.0, 0.0, 0.02011325091806128, 0.0020113250918061282, 0.0020113250918061282, 0.00014717012866874104, 0.0, 0.0, 0.0, 0.0, 0.01005662545903064, 0.0010056625459030641, 0.0010056625459030641, 7.358506433437052e-5, 0.0, 0.0, 0.0, 0.0, 0.0010056625459030641, 7.358506433437052e-5, 7.358506433437052e-5, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.0, 0.08849830403946964, 0.0, 0.0, 0.0, 0.0, 0.0, 0.08849830403946964, 0.0, 0.011062288004933706, 0.0, 0.0, 0.0, 0.0, 0.0, 0.17699660807893927, 0.0, 0.022124576009867412, 0.0, 0.0, 0.0, 0.0, 0.0, 0.022124576009867412, 0.0, 0.0021584952204748683, 0.0, 0.0, 0.0, 0.0, 0.0, 0.011062288004933706, 0.0, 0.0010792476102374341, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0010792476102374341, 0.0, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.08849830403946964, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.08849830403946964, 0.011062288004933706, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.17699660807893927, 0.022124576009867412, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.022124576009867412, 0.0021584952204748683, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.011062288004933706, 0.0010792476102374341, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0010792476102374341, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.09956059204440336, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.19912118408880672, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.024283071230342273, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.012141535615171136, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.07844167858043899, 0.07844167858043899, 0.01005662545903064, 0.07844167858043899, 0.01005662545903064, 0.01005662545903064, 0.0010056625459030641, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.15688335716087798, 0.02011325091806128, 0.02011325091806128, 0.0020113250918061282, 0.02011325091806128, 0.0020113250918061282, 0.0020113250918061282, 0.00014717012866874104, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.01005662545903064, 0.0010056625459030641, 0.0010056625459030641, 7.358506433437052e-5, 0.0010056625459030641, 7.358506433437052e-5, 7.358506433437052e-5, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.0, 0.08849830403946964, 0.0, 0.08849830403946964, 0.0, 0.011062288004933706, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.17699660807893927, 0.0, 0.022124576009867412, 0.0, 0.022124576009867412, 0.0, 0.0021584952204748683, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.011062288004933706, 0.0, 0.0010792476102374341, 0.0, 0.0010792476102374341, 0.0, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.08849830403946964, 0.0, 0.0, 0.08849830403946964, 0.011062288004933706, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.17699660807893927, 0.022124576009867412, 0.0, 0.0, 0.022124576009867412, 0.0021584952204748683, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.011062288004933706, 0.0010792476102374341, 0.0, 0.0, 0.0010792476102374341, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.0, 0.0, 0.0, 0.09956059204440336, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.19912118408880672, 0.0, 0.0, 0.0, 0.024283071230342273, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.012141535615171136, 0.0, 0.0, 0.0, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.08849830403946964, 0.08849830403946964, 0.011062288004933706, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.17699660807893927, 0.022124576009867412, 0.022124576009867412, 0.0021584952204748683, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.011062288004933706, 0.0010792476102374341, 0.0010792476102374341, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.0, 0.09956059204440336, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.19912118408880672, 0.0, 0.024283071230342273, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.012141535615171136, 0.0, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.09956059204440336, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.19912118408880672, 0.024283071230342273, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.012141535615171136, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.7632978723404258, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.22340425531914895, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.013297872340425532], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.4967972976761135, 0.07844167858043899, 0.07844167858043899, 0.01005662545903064, 0.07844167858043899, 0.01005662545903064, 0.01005662545903064, 0.0010056625459030641, 0.07844167858043899, 0.01005662545903064, 0.01005662545903064, 0.0010056625459030641, 0.01005662545903064, 0.0010056625459030641, 0.0010056625459030641, 7.358506433437052e-5, 0.07844167858043899, 0.01005662545903064, 0.01005662545903064, 0.0010056625459030641, 0.01005662545903064, 0.0010056625459030641, 0.0010056625459030641, 7.358506433437052e-5, 0.01005662545903064, 0.0010056625459030641, 0.0010056625459030641, 7.358506433437052e-5, 0.0010056625459030641, 7.358506433437052e-5, 7.358506433437052e-5, 3.504050682589073e-6], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.0, 0.08849830403946964, 0.0, 0.08849830403946964, 0.0, 0.011062288004933706, 0.0, 0.08849830403946964, 0.0, 0.011062288004933706, 0.0, 0.011062288004933706, 0.0, 0.0010792476102374341, 0.0, 0.08849830403946964, 0.0, 0.011062288004933706, 0.0, 0.011062288004933706, 0.0, 0.0010792476102374341, 0.0, 0.011062288004933706, 0.0, 0.0010792476102374341, 0.0, 0.0010792476102374341, 0.0, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.08849830403946964, 0.0, 0.0, 0.08849830403946964, 0.011062288004933706, 0.0, 0.0, 0.08849830403946964, 0.011062288004933706, 0.0, 0.0, 0.011062288004933706, 0.0010792476102374341, 0.0, 0.0, 0.08849830403946964, 0.011062288004933706, 0.0, 0.0, 0.011062288004933706, 0.0010792476102374341, 0.0, 0.0, 0.011062288004933706, 0.0010792476102374341, 0.0, 0.0, 0.0010792476102374341, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.0, 0.0, 0.0, 0.09956059204440336, 0.0, 0.0, 0.0, 0.09956059204440336, 0.0, 0.0, 0.0, 0.012141535615171136, 0.0, 0.0, 0.0, 0.09956059204440336, 0.0, 0.0, 0.0, 0.012141535615171136, 0.0, 0.0, 0.0, 0.012141535615171136, 0.0, 0.0, 0.0, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.08849830403946964, 0.08849830403946964, 0.011062288004933706, 0.0, 0.0, 0.0, 0.0, 0.08849830403946964, 0.011062288004933706, 0.011062288004933706, 0.0010792476102374341, 0.0, 0.0, 0.0, 0.0, 0.08849830403946964, 0.011062288004933706, 0.011062288004933706, 0.0010792476102374341, 0.0, 0.0, 0.0, 0.0, 0.011062288004933706, 0.0010792476102374341, 0.0010792476102374341, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.0, 0.09956059204440336, 0.0, 0.0, 0.0, 0.0, 0.0, 0.09956059204440336, 0.0, 0.012141535615171136, 0.0, 0.0, 0.0, 0.0, 0.0, 0.09956059204440336, 0.0, 0.012141535615171136, 0.0, 0.0, 0.0, 0.0, 0.0, 0.012141535615171136, 0.0, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.09956059204440336, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.09956059204440336, 0.012141535615171136, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.09956059204440336, 0.012141535615171136, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.012141535615171136, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.7632978723404258, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.11170212765957448, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.11170212765957448, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.013297872340425532], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.08849830403946964, 0.08849830403946964, 0.011062288004933706, 0.08849830403946964, 0.011062288004933706, 0.011062288004933706, 0.0010792476102374341, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.08849830403946964, 0.011062288004933706, 0.011062288004933706, 0.0010792476102374341, 0.011062288004933706, 0.0010792476102374341, 0.0010792476102374341, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.0, 0.09956059204440336, 0.0, 0.09956059204440336, 0.0, 0.012141535615171136, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.09956059204440336, 0.0, 0.012141535615171136, 0.0, 0.012141535615171136, 0.0, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.09956059204440336, 0.0, 0.0, 0.09956059204440336, 0.012141535615171136, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.09956059204440336, 0.012141535615171136, 0.0, 0.0, 0.012141535615171136, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.7632978723404258, 0.0, 0.0, 0.0, 0.11170212765957448, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.11170212765957448, 0.0, 0.0, 0.0, 0.013297872340425532], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.09956059204440336, 0.09956059204440336, 0.012141535615171136, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.09956059204440336, 0.012141535615171136, 0.012141535615171136, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.7632978723404258, 0.0, 0.11170212765957448, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.11170212765957448, 0.0, 0.013297872340425532], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.7632978723404258, 0.11170212765957448, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.11170212765957448, 0.013297872340425532], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.8749999999999999, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.12499999999999999], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5752389762565525, 0.08849830403946964, 0.08849830403946964, 0.011062288004933706, 0.08849830403946964, 0.011062288004933706, 0.011062288004933706, 0.0010792476102374341, 0.08849830403946964, 0.011062288004933706, 0.011062288004933706, 0.0010792476102374341, 0.011062288004933706, 0.0010792476102374341, 0.0010792476102374341, 7.70891150169596e-5], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.0, 0.09956059204440336, 0.0, 0.09956059204440336, 0.0, 0.012141535615171136, 0.0, 0.09956059204440336, 0.0, 0.012141535615171136, 0.0, 0.012141535615171136, 0.0, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.09956059204440336, 0.0, 0.0, 0.09956059204440336, 0.012141535615171136, 0.0, 0.0, 0.09956059204440336, 0.012141535615171136, 0.0, 0.0, 0.012141535615171136, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.7632978723404258, 0.0, 0.0, 0.0, 0.11170212765957448, 0.0, 0.0, 0.0, 0.11170212765957448, 0.0, 0.0, 0.0, 0.013297872340425532], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.09956059204440336, 0.09956059204440336, 0.012141535615171136, 0.0, 0.0, 0.0, 0.0, 0.09956059204440336, 0.012141535615171136, 0.012141535615171136, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.7632978723404258, 0.0, 0.11170212765957448, 0.0, 0.0, 0.0, 0.0, 0.0, 0.11170212765957448, 0.0, 0.013297872340425532], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.7632978723404258, 0.11170212765957448, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.11170212765957448, 0.013297872340425532], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.8749999999999999, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.12499999999999999], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.6637372802960224, 0.09956059204440336, 0.09956059204440336, 0.012141535615171136, 0.09956059204440336, 0.012141535615171136, 0.012141535615171136, 0.001156336725254394], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.7632978723404258, 0.0, 0.11170212765957448, 0.0, 0.11170212765957448, 0.0, 0.013297872340425532], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.7632978723404258, 0.11170212765957448, 0.0, 0.0, 0.11170212765957448, 0.013297872340425532], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.8749999999999999, 0.0, 0.0, 0.0, 0.12499999999999999], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.7632978723404258, 0.11170212765957448, 0.11170212765957448, 0.013297872340425532], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.8749999999999999, 0.0, 0.12499999999999999], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.8749999999999999, 0.12499999999999999], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0]]
+transition_matrix = load_entry("first_8_transition_probabilities", "Transition matrix")
 
 #Check transition matrix
 for category in range(80):
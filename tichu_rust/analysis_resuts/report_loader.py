@@ -0,0 +1,17 @@
+import json
+import os
+
+RESULTS_DIR = os.path.dirname(os.path.abspath(__file__))
+
+
+def load_report(name):
+    # Report written by output_report in main.rs, e.g. name = "gt_stats" or "gt_stats_filtered"
+    with open(os.path.join(RESULTS_DIR, name + ".json")) as f:
+        return json.load(f)
+
+
+def load_entry(name, label):
+    for entry in load_report(name)["entries"]:
+        if entry["label"] == label:
+            return entry["value"]
+    raise KeyError(f"No entry '{label}' in {name}.json")
@@ -1,357 +1,428 @@
-use crate::analysis::{format_slice_abs_relative, format_slice_abs_relative2, format_slice_abs_relative2_i64};
+use crate::analysis::report::{AnalysisReport, ReportValue};
+use crate::analysis::statistics::{Mean, Proportion};
 use crate::bsw_binary_format::binary_format_constants::{PlayerIDInternal, CALL_GRAND_TICHU, CALL_NONE, CALL_TICHU, RANK_1};
 use crate::bsw_binary_format::player_round_hand::PlayerRoundHand;
 use crate::bsw_database::DataBase;
+use crate::database_query::RoundView;
+use crate::analysis::map_reduce::{map_reduce, Accumulator};
 use crate::hand;
 use crate::tichu_hand::{get_card_type, CardIndex, CardType, Hand, HandType, TichuHand, MASK_FOUR_OF_KIND, SPECIAL_CARD, TWO};
 
-pub fn evaluate_bombs_in_play(db: &DataBase){
+pub fn evaluate_bombs_in_play(db: &DataBase) -> AnalysisReport {
+    let mut report = AnalysisReport::new("bombs_in_play");
     let mut bombs_played: usize = 0;
     let mut four_bombs: usize = 0;
     let mut straight_bombs: usize = 0;
-    for game in db.games.iter(){
-        for (_, round_log) in game.rounds.iter(){
-            let mut iter = round_log.iter();
-            while let Some(trick) = iter.next_trick(){
-                for (_, hand) in trick.trick_log.iter(){
-                    let hand_type = hand.hand_type().unwrap();
-                    match hand_type{
-                        HandType::Bomb4(_) => {bombs_played +=1; four_bombs += 1;},
-                        HandType::BombStreet(_, _)=>{bombs_played += 1; straight_bombs += 1;},
-                        _=>{}
-                    }
+    for RoundView { round_log, .. } in db.query().rounds() {
+        let mut iter = round_log.iter();
+        while let Some(trick) = iter.next_trick(){
+            for (_, hand) in trick.trick_log.iter(){
+                let hand_type = hand.hand_type().unwrap();
+                match hand_type{
+                    HandType::Bomb4(_) => {bombs_played +=1; four_bombs += 1;},
+                    HandType::BombStreet(_, _)=>{bombs_played += 1; straight_bombs += 1;},
+                    _=>{}
                 }
             }
         }
     }
-    println!("Bombs played: {}", bombs_played);
-    println!("Four of kind bombs: {}", format_slice_abs_relative(&[four_bombs], bombs_played));
-    println!("Straight bombs: {}", format_slice_abs_relative(&[straight_bombs], bombs_played));
-
+    report.add("Bombs played", ReportValue::Integer(bombs_played as i64));
+    report.add("Four of kind bombs", ReportValue::proportions(&[four_bombs], bombs_played));
+    report.add("Straight bombs", ReportValue::proportions(&[straight_bombs], bombs_played));
+    report
+}
+//The statistics of evaluate_bomb_stats, all computed in the same pass with map_reduce.
+fn contains_bomb(hand: Hand) -> usize {
+    (hand.contains_straight_bomb() || hand.contains_four_of_kind_bomb()) as usize
+}
+fn lo_card(prh: &PlayerRoundHand) -> CardType {
+    get_card_type(prh.left_out_exchange_card())
+}
+fn ro_card(prh: &PlayerRoundHand) -> CardType {
+    get_card_type(prh.right_out_exchange_card())
+}
+fn add_counts(a: &mut [usize], b: &[usize]) {
+    a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+}
+fn add_means(a: &mut [Mean], b: &[Mean]) {
+    a.iter_mut().zip(b).for_each(|(a, b)| a.merge(*b));
 }
-pub fn evaluate_bomb_stats(db: &DataBase) {
-    //Evaluate bomb probability, first 8, first 14, final 14 for each player.
-    let rounds = db.games.iter().fold(0, |acc, inc| acc + inc.rounds.len());
-    let contains_4_bomb = |hand: Hand| hand.contains_four_of_kind_bomb() as usize;
-    let contains_straight_bomb = |hand: Hand| hand.contains_straight_bomb() as usize;
-    let contains_bomb = |hand: Hand| (hand.contains_straight_bomb() || hand.contains_four_of_kind_bomb()) as usize;
-    let bombs_first_8 = (0..4).map(|player_id| db.games.iter().fold(0, |acc, inc| acc + inc.rounds.iter().fold(
-        0, |acc_2, inc_2| acc_2 + contains_bomb(inc_2.0.player_rounds[player_id].first_8),
-    ))).collect::<Vec<_>>();
-    let bombs_first_14 = (0..4).map(|player_id| db.games.iter().fold(0, |acc, inc| acc + inc.rounds.iter().fold(
-        0, |acc_2, inc_2| acc_2 + contains_bomb(inc_2.0.player_rounds[player_id].first_14),
-    ))).collect::<Vec<_>>();
-    let bombs_final_14 = (0..4).map(|player_id| db.games.iter().fold(0, |acc, inc| acc + inc.rounds.iter().fold(
-        0, |acc_2, inc_2| acc_2 + contains_bomb(inc_2.0.player_rounds[player_id].final_14()),
-    ))).collect::<Vec<_>>();
-    let four_bombs_final_14 = (0..4).map(|player_id| db.games.iter().fold(0, |acc, inc| acc + inc.rounds.iter().fold(
-        0, |acc_2, inc_2| acc_2 + contains_4_bomb(inc_2.0.player_rounds[player_id].final_14()),
-    ))).collect::<Vec<_>>();
-    let straight_bombs_final_14 = (0..4).map(|player_id| db.games.iter().fold(0, |acc, inc| acc + inc.rounds.iter().fold(
-        0, |acc_2, inc_2| acc_2 + contains_straight_bomb(inc_2.0.player_rounds[player_id].final_14()),
-    ))).collect::<Vec<_>>();
-    println!("Bombs under first 8. {}", format_slice_abs_relative(&bombs_first_8, rounds));
-    println!("Bombs under first 14: {}", format_slice_abs_relative(&bombs_first_14, rounds));
-    println!("Bombs under final 14: {}", format_slice_abs_relative(&bombs_final_14, rounds));
-    println!("4-Bombs under final 14: {}", format_slice_abs_relative(&four_bombs_final_14, rounds));
-    println!("Straight-Bombs under final 14: {}", format_slice_abs_relative(&straight_bombs_final_14, rounds));
-
-    //Probability of bomb in team?
-    let bombs_team_rounds = (0..2).map(|team_id| db.games.iter().fold(0, |acc, inc| acc + inc.rounds.iter().fold(
-        0, |acc_2, inc_2| acc_2 +
-            (contains_bomb(inc_2.0.player_rounds[team_id].final_14()) + contains_bomb(inc_2.0.player_rounds[team_id + 2].final_14())).min(1),
-    ))).collect::<Vec<_>>();
-    println!("Bomb in team: {}", format_slice_abs_relative(&bombs_team_rounds, rounds));
 
-    //Probability of bomb in round?
-    let bombs_round = db.games.iter().fold(0, |acc, inc| acc + inc.rounds.iter().fold(
-        0, |acc_2, inc_2| acc_2 +
-            (contains_bomb(inc_2.0.player_rounds[0].final_14())
-                + contains_bomb(inc_2.0.player_rounds[1].final_14())
-                + contains_bomb(inc_2.0.player_rounds[2].final_14())
-                + contains_bomb(inc_2.0.player_rounds[3].final_14())
-            ).min(1),
-    ));
-    println!("Bomb in round: {}", format_slice_abs_relative(&[bombs_round], rounds));
+//Bomb occurrences per player.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct BombCounts {
+    pub rounds: usize,
+    pub bombs_first_8: [usize; 4],
+    pub bombs_first_14: [usize; 4],
+    pub bombs_final_14: [usize; 4],
+    pub four_bombs_final_14: [usize; 4],
+    pub straight_bombs_final_14: [usize; 4],
+    pub bombs_team_rounds: [usize; 2],
+    pub bombs_round: usize,
+}
+impl Accumulator for BombCounts {
+    fn add_round(&mut self, view: &RoundView) {
+        let final_14 = view.round.player_rounds.each_ref().map(|player_round| player_round.final_14());
+        self.rounds += 1;
+        for (player, player_round) in view.round.player_rounds.iter().enumerate() {
+            self.bombs_first_8[player] += contains_bomb(player_round.first_8);
+            self.bombs_first_14[player] += contains_bomb(player_round.first_14);
+            self.bombs_final_14[player] += contains_bomb(final_14[player]);
+            self.four_bombs_final_14[player] += final_14[player].contains_four_of_kind_bomb() as usize;
+            self.straight_bombs_final_14[player] += final_14[player].contains_straight_bomb() as usize;
+        }
+        for team in 0..2 {
+            self.bombs_team_rounds[team] += (contains_bomb(final_14[team]) + contains_bomb(final_14[team + 2])).min(1);
+        }
+        self.bombs_round += final_14.iter().any(|hand| contains_bomb(*hand) > 0) as usize;
+    }
+    fn merge(&mut self, other: Self) {
+        self.rounds += other.rounds;
+        add_counts(&mut self.bombs_first_8, &other.bombs_first_8);
+        add_counts(&mut self.bombs_first_14, &other.bombs_first_14);
+        add_counts(&mut self.bombs_final_14, &other.bombs_final_14);
+        add_counts(&mut self.four_bombs_final_14, &other.four_bombs_final_14);
+        add_counts(&mut self.straight_bombs_final_14, &other.straight_bombs_final_14);
+        add_counts(&mut self.bombs_team_rounds, &other.bombs_team_rounds);
+        self.bombs_round += other.bombs_round;
+    }
+}
 
-    //Probability of bomb in opp when calling or having bomb, also Expected round score differences when a bomb is in the team.
-    //Probability of own bomb when calling
-    let mut bombs_self_when_tcall = [0; 4];
-    let mut bombs_self_when_gtcall = [0; 4];
-    let mut bombs_self_when_tcall_two_hc_or_less = [0; 4];
-    let mut bombs_opp_when_call_no_bomb = [0; 4];
-    let mut bombs_opp_when_call = [0; 4];
-    let mut bombs_opp_when_bomb = [0; 4];
+//Probability of bomb in opp when calling or having bomb, also Expected round score differences when a bomb is in the team.
+//Probability of own bomb when calling. Arrays are indexed by player, triplet arrays by [triplet count][player].
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct BombCallCounts {
+    pub bombs_self_when_tcall: [usize; 4],
+    pub bombs_self_when_gtcall: [usize; 4],
+    pub bombs_self_when_tcall_two_hc_or_less: [usize; 4],
+    pub bombs_opp_when_call_no_bomb: [usize; 4],
+    pub bombs_opp_when_call: [usize; 4],
+    pub bombs_opp_when_bomb: [usize; 4],
 
-    let mut call_rounds = [0; 4];
-    let mut gtcall_rounds = [0; 4];
-    let mut gtcall_and_enemy_bomb_rounds = [0; 4];
-    let mut gtcall_and_enemy_bomb_successes = [0; 4];
-    let mut tcall_rounds = [0; 4];
-    let mut tcall_and_bomb_rounds = [0; 4];
-    let mut tcall_and_bomb_successes = [0; 4];
-    let mut tcall_and_enemy_bomb_rounds = [0; 4];
-    let mut tcall_and_enemy_bomb_successes = [0; 4];
-    let mut call_rounds_no_bomb = [0; 4];
-    let mut tcall_rounds_two_hc_or_less = [0; 4];
+    pub call_rounds: [usize; 4],
+    pub gtcall_rounds: [usize; 4],
+    pub gtcall_and_enemy_bomb_rounds: [usize; 4],
+    pub gtcall_and_enemy_bomb_successes: [usize; 4],
+    pub tcall_rounds: [usize; 4],
+    pub tcall_and_bomb_rounds: [usize; 4],
+    pub tcall_and_bomb_successes: [usize; 4],
+    pub tcall_and_enemy_bomb_rounds: [usize; 4],
+    pub tcall_and_enemy_bomb_successes: [usize; 4],
+    pub call_rounds_no_bomb: [usize; 4],
+    pub tcall_rounds_two_hc_or_less: [usize; 4],
 
     //Probability of bomb given triplet
-    let mut triplet_rounds = [[0; 4]; 5]; //Outer array: triplet count, inner: player_id
-    let mut bomb_self_given_triplet = [[0; 4]; 5]; //Outer array: triplet count, inner: player_id
-    let mut bomb_opp_given_triplet = [[0; 4]; 5]; //OUter array: triplet count, inner: player_id
+    pub triplet_rounds: [[usize; 4]; 5],
+    pub bomb_self_given_triplet: [[usize; 4]; 5],
+    pub bomb_opp_given_triplet: [[usize; 4]; 5],
 
-    let mut triplet_post_exch_rounds = [[0; 4]; 4];
-    let mut bomb_opp_given_post_exch_triplet = [[0; 4]; 4]; //OUter array: triplet count, inner: player_id
+    pub triplet_post_exch_rounds: [[usize; 4]; 4],
+    pub bomb_opp_given_post_exch_triplet: [[usize; 4]; 4],
 
-    let mut round_score_diff_given_bomb = [0; 2];
-    for game in db.games.iter() {
-        for (round, _) in game.rounds.iter() {
-            let p0_bombs = contains_bomb(round.player_rounds[0].final_14());
-            let p1_bombs = contains_bomb(round.player_rounds[1].final_14());
-            let p2_bombs = contains_bomb(round.player_rounds[2].final_14());
-            let p3_bombs = contains_bomb(round.player_rounds[3].final_14());
-            let p_bombs = [p0_bombs, p1_bombs, p2_bombs, p3_bombs];
-            let bombs_team_1 = (p0_bombs + p2_bombs).min(1);
-            let bombs_team_2 = (p1_bombs + p3_bombs).min(1);
-            let bombs = [bombs_team_1, bombs_team_2];
-            for player_id in 0..4 {
-                let call = round.player_rounds[0].player_call(player_id);
-                if call == CALL_NONE {
-                    continue;
-                }
-                call_rounds[player_id as usize] += 1;
-                if p_bombs[player_id as usize] == 0 {
-                    call_rounds_no_bomb[player_id as usize] += 1;
-                    bombs_opp_when_call_no_bomb[player_id as usize] += bombs[((player_id + 1) % 2) as usize];
-                }
-                bombs_opp_when_call[player_id as usize] += bombs[((player_id + 1) % 2) as usize];
-                if call == CALL_TICHU {
-                    tcall_rounds[player_id as usize] += 1;
-                    bombs_self_when_tcall[player_id as usize] += bombs[(player_id % 2) as usize];
-                    if p_bombs[player_id as usize] == 1 {
-                        tcall_and_bomb_rounds[player_id as usize] += 1;
-                        tcall_and_bomb_successes[player_id as usize] += (round.player_rounds[0].player_rank(player_id) == RANK_1) as usize;
-                    }
-                    if bombs[((player_id + 1) % 2) as usize] == 1 {
-                        tcall_and_enemy_bomb_rounds[player_id as usize] += 1;
-                        tcall_and_enemy_bomb_successes[player_id as usize] += (round.player_rounds[0].player_rank(player_id) == RANK_1) as usize;
-                    }
-                }
-                if call == CALL_TICHU && round.player_rounds[player_id as usize].final_14().get_high_card_amt() <= 2 {
-                    tcall_rounds_two_hc_or_less[player_id as usize] += 1;
-                    bombs_self_when_tcall_two_hc_or_less[player_id as usize] += bombs[(player_id % 2) as usize];
-                }
-                if call == CALL_GRAND_TICHU {
-                    gtcall_rounds[player_id as usize] += 1;
-                    bombs_self_when_gtcall[player_id as usize] += bombs[(player_id % 2) as usize];
-                    if bombs[((player_id + 1) % 2) as usize] == 1 {
-                        gtcall_and_enemy_bomb_rounds[player_id as usize] += 1;
-                        gtcall_and_enemy_bomb_successes[player_id as usize] += (round.player_rounds[0].player_rank(player_id) == RANK_1) as usize;
-                    }
-                }
-                //Triplets
-                if contains_bomb(round.player_rounds[player_id as usize].first_14) > 0{
-                    continue;
-                }
-                let triplets_on_hand = round.player_rounds[player_id as usize].first_14.count_triplets().min(3);
-                triplet_rounds[triplets_on_hand as usize][player_id as usize] += 1;
-                if p_bombs[player_id as usize] == 1 {
-                    bomb_self_given_triplet[triplets_on_hand as usize][player_id as usize] += 1;
+    pub round_score_diff_given_bomb: [Mean; 2], //By team
+}
+impl Accumulator for BombCallCounts {
+    fn add_round(&mut self, view: &RoundView) {
+        let round = view.round;
+        let p_bombs = round.player_rounds.each_ref().map(|player_round| contains_bomb(player_round.final_14()));
+        let bombs_team_1 = (p_bombs[0] + p_bombs[2]).min(1);
+        let bombs_team_2 = (p_bombs[1] + p_bombs[3]).min(1);
+        let bombs = [bombs_team_1, bombs_team_2];
+        for player_id in 0..4 {
+            let call = round.player_rounds[0].player_call(player_id);
+            if call == CALL_NONE {
+                continue;
+            }
+            let player = player_id as usize;
+            let enemy_bombs = bombs[((player_id + 1) % 2) as usize];
+            let success = (round.player_rounds[0].player_rank(player_id) == RANK_1) as usize;
+            self.call_rounds[player] += 1;
+            if p_bombs[player] == 0 {
+                self.call_rounds_no_bomb[player] += 1;
+                self.bombs_opp_when_call_no_bomb[player] += enemy_bombs;
+            }
+            self.bombs_opp_when_call[player] += enemy_bombs;
+            if call == CALL_TICHU {
+                self.tcall_rounds[player] += 1;
+                self.bombs_self_when_tcall[player] += bombs[(player_id % 2) as usize];
+                if p_bombs[player] == 1 {
+                    self.tcall_and_bomb_rounds[player] += 1;
+                    self.tcall_and_bomb_successes[player] += success;
                 }
-                bomb_opp_given_triplet[triplets_on_hand as usize][player_id as usize] += bombs[((player_id + 1) % 2) as usize];
-
-                //Post exch stats
-                if p_bombs[player_id as usize] == 1 {
-                    continue;
+                if enemy_bombs == 1 {
+                    self.tcall_and_enemy_bomb_rounds[player] += 1;
+                    self.tcall_and_enemy_bomb_successes[player] += success;
                 }
-                let triplets_on_hand_post_exch = round.player_rounds[player_id as usize].final_14().count_triplets().min(3);
-                triplet_post_exch_rounds[triplets_on_hand_post_exch as usize][player_id as usize] += 1;
-                bomb_opp_given_post_exch_triplet[triplets_on_hand_post_exch as usize][player_id as usize] += bombs[((player_id + 1) % 2) as usize];
-            }
-            if bombs_team_1 > 0 {
-                round_score_diff_given_bomb[0] += round.player_rounds[0].round_score_relative_gain() as i64;
             }
-            if bombs_team_2 > 0 {
-                round_score_diff_given_bomb[1] += round.player_rounds[1].round_score_relative_gain() as i64;
+            if call == CALL_TICHU && round.player_rounds[player].final_14().get_high_card_amt() <= 2 {
+                self.tcall_rounds_two_hc_or_less[player] += 1;
+                self.bombs_self_when_tcall_two_hc_or_less[player] += bombs[(player_id % 2) as usize];
             }
-            if p0_bombs > 0 {
-                bombs_opp_when_bomb[0] += bombs_team_2;
+            if call == CALL_GRAND_TICHU {
+                self.gtcall_rounds[player] += 1;
+                self.bombs_self_when_gtcall[player] += bombs[(player_id % 2) as usize];
+                if enemy_bombs == 1 {
+                    self.gtcall_and_enemy_bomb_rounds[player] += 1;
+                    self.gtcall_and_enemy_bomb_successes[player] += success;
+                }
             }
-            if p1_bombs > 0 {
-                bombs_opp_when_bomb[1] += bombs_team_1;
+            //Triplets
+            if contains_bomb(round.player_rounds[player].first_14) > 0 {
+                continue;
             }
-            if p2_bombs > 0 {
-                bombs_opp_when_bomb[2] += bombs_team_2;
+            let triplets_on_hand = round.player_rounds[player].first_14.count_triplets().min(3) as usize;
+            self.triplet_rounds[triplets_on_hand][player] += 1;
+            self.bomb_self_given_triplet[triplets_on_hand][player] += p_bombs[player];
+            self.bomb_opp_given_triplet[triplets_on_hand][player] += enemy_bombs;
+
+            //Post exch stats
+            if p_bombs[player] == 1 {
+                continue;
             }
-            if p3_bombs > 0 {
-                bombs_opp_when_bomb[3] += bombs_team_1;
+            let triplets_on_hand_post_exch = round.player_rounds[player].final_14().count_triplets().min(3) as usize;
+            self.triplet_post_exch_rounds[triplets_on_hand_post_exch][player] += 1;
+            self.bomb_opp_given_post_exch_triplet[triplets_on_hand_post_exch][player] += enemy_bombs;
+        }
+        if bombs_team_1 > 0 {
+            self.round_score_diff_given_bomb[0].add(round.player_rounds[0].round_score_relative_gain() as f64);
+        }
+        if bombs_team_2 > 0 {
+            self.round_score_diff_given_bomb[1].add(round.player_rounds[1].round_score_relative_gain() as f64);
+        }
+        for player in 0..4 {
+            if p_bombs[player] > 0 {
+                self.bombs_opp_when_bomb[player] += bombs[(player + 1) % 2];
             }
         }
     }
-    println!("ERS given Bomb: {}", format_slice_abs_relative2_i64(&round_score_diff_given_bomb, &bombs_team_rounds));
-    println!("Bomb on hand given TCall: {}", format_slice_abs_relative2(&bombs_self_when_tcall, &tcall_rounds));
-    println!("Bomb on hand given GTCall: {}", format_slice_abs_relative2(&bombs_self_when_gtcall, &gtcall_rounds));
-    println!("Bomb on hand given TCall & <=2 HC: {}", format_slice_abs_relative2(&bombs_self_when_tcall_two_hc_or_less, &tcall_rounds_two_hc_or_less));
-    println!("Bomb in opponent given any Call: {}", format_slice_abs_relative2(&bombs_opp_when_call, &call_rounds));
-    println!("Bomb in opponent given Call & No-self bomb: {}", format_slice_abs_relative2(&bombs_opp_when_call_no_bomb, &call_rounds_no_bomb));
-    println!("Bomb in opponent given Bomb: {}", format_slice_abs_relative2(&bombs_opp_when_bomb, &bombs_final_14));
-    println!("Tichu Call Rate given self bomb: {}", format_slice_abs_relative2(&tcall_and_bomb_rounds, &bombs_final_14));
-    println!("Tichu Success Rate given self bomb: {}", format_slice_abs_relative2(&tcall_and_bomb_successes, &tcall_and_bomb_rounds));
-    println!("Tichu Success Rate given enemy bomb: {}", format_slice_abs_relative2(&tcall_and_enemy_bomb_successes, &tcall_and_enemy_bomb_rounds));
-    println!("GTichu Success Rate given enemy bomb: {}", format_slice_abs_relative2(&gtcall_and_enemy_bomb_successes, &gtcall_and_enemy_bomb_rounds));
-    for triplet_amt in 0..4{
-        println!("Bomb on hand given {} triplets on hand before exch: {}",triplet_amt, format_slice_abs_relative2(&bomb_self_given_triplet[triplet_amt], &triplet_rounds[triplet_amt]));
-        println!("Bomb in opponent given {} triplets on hand before exch: {}",triplet_amt, format_slice_abs_relative2(&bomb_opp_given_triplet[triplet_amt], &triplet_rounds[triplet_amt]));
-        println!("Bomb in opponent given {} triplets on hand post exch: {}",triplet_amt, format_slice_abs_relative2(&bomb_opp_given_post_exch_triplet[triplet_amt], &triplet_post_exch_rounds[triplet_amt]));
+    fn merge(&mut self, other: Self) {
+        add_counts(&mut self.bombs_self_when_tcall, &other.bombs_self_when_tcall);
+        add_counts(&mut self.bombs_self_when_gtcall, &other.bombs_self_when_gtcall);
+        add_counts(&mut self.bombs_self_when_tcall_two_hc_or_less, &other.bombs_self_when_tcall_two_hc_or_less);
+        add_counts(&mut self.bombs_opp_when_call_no_bomb, &other.bombs_opp_when_call_no_bomb);
+        add_counts(&mut self.bombs_opp_when_call, &other.bombs_opp_when_call);
+        add_counts(&mut self.bombs_opp_when_bomb, &other.bombs_opp_when_bomb);
+        add_counts(&mut self.call_rounds, &other.call_rounds);
+        add_counts(&mut self.gtcall_rounds, &other.gtcall_rounds);
+        add_counts(&mut self.gtcall_and_enemy_bomb_rounds, &other.gtcall_and_enemy_bomb_rounds);
+        add_counts(&mut self.gtcall_and_enemy_bomb_successes, &other.gtcall_and_enemy_bomb_successes);
+        add_counts(&mut self.tcall_rounds, &other.tcall_rounds);
+        add_counts(&mut self.tcall_and_bomb_rounds, &other.tcall_and_bomb_rounds);
+        add_counts(&mut self.tcall_and_bomb_successes, &other.tcall_and_bomb_successes);
+        add_counts(&mut self.tcall_and_enemy_bomb_rounds, &other.tcall_and_enemy_bomb_rounds);
+        add_counts(&mut self.tcall_and_enemy_bomb_successes, &other.tcall_and_enemy_bomb_successes);
+        add_counts(&mut self.call_rounds_no_bomb, &other.call_rounds_no_bomb);
+        add_counts(&mut self.tcall_rounds_two_hc_or_less, &other.tcall_rounds_two_hc_or_less);
+        add_counts(self.triplet_rounds.as_flattened_mut(), other.triplet_rounds.as_flattened());
+        add_counts(self.bomb_self_given_triplet.as_flattened_mut(), other.bomb_self_given_triplet.as_flattened());
+        add_counts(self.bomb_opp_given_triplet.as_flattened_mut(), other.bomb_opp_given_triplet.as_flattened());
+        add_counts(self.triplet_post_exch_rounds.as_flattened_mut(), other.triplet_post_exch_rounds.as_flattened());
+        add_counts(self.bomb_opp_given_post_exch_triplet.as_flattened_mut(), other.bomb_opp_given_post_exch_triplet.as_flattened());
+        add_means(&mut self.round_score_diff_given_bomb, &other.round_score_diff_given_bomb);
     }
+}
 
-    //Probability of bomb when following even_odd duplicate strategy
-    let lo_card = |prh: &PlayerRoundHand| get_card_type(prh.left_out_exchange_card());
-    let ro_card = |prh: &PlayerRoundHand| get_card_type(prh.right_out_exchange_card());
-    let mut bombs_opp_when_exch = [0; 2];
-    let mut bombs_opp_when_not_exch = [0; 2];
-    let mut exch_rounds = [0; 2];
-    let mut not_exch_rounds = [0; 2];
-    let mut round_score_diff_given_exch = [0; 2];
+//Probability of bomb when following even_odd duplicate strategy, and other exchange strategies. Arrays are indexed by team.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ExchangeStrategyCounts {
+    pub bombs_opp_when_exch: [usize; 2],
+    pub bombs_opp_when_not_exch: [usize; 2],
+    pub exch_rounds: [usize; 2],
+    pub not_exch_rounds: [usize; 2],
+    pub round_score_diff_given_exch: [Mean; 2],
 
-    let mut double_exch_strat_rounds = [0; 2];
-    let mut bombs_opp_when_double_exch_strat = [0; 2];
-    let mut round_score_diff_given_double_exch = [0; 2];
+    pub double_exch_strat_rounds: [usize; 2],
+    pub bombs_opp_when_double_exch_strat: [usize; 2],
+    pub round_score_diff_given_double_exch: [Mean; 2],
 
-    let mut small_big_exch_strat_rounds = [0; 2];
-    let mut bombs_opp_when_small_big_exch_strat = [0; 2];
-    let mut round_score_diff_given_small_big_exch = [0; 2];
-    for game in db.games.iter() {
-        for (round, _) in game.rounds.iter() {
-            let bombs_team_1 = (contains_bomb(round.player_rounds[0].final_14()) + contains_bomb(round.player_rounds[2].final_14())).min(1);
-            let bombs_team_2 = (contains_bomb(round.player_rounds[1].final_14()) + contains_bomb(round.player_rounds[3].final_14())).min(1);
-            //Check if team_1 follows strategy
-            let pr0 = round.player_rounds.get(0).unwrap();
-            let pr1 = round.player_rounds.get(1).unwrap();
-            let pr2 = round.player_rounds.get(2).unwrap();
-            let pr3 = round.player_rounds.get(3).unwrap();
-            let team_1_exch = is_even_odd_or_duplicate_strategy((pr0.first_14, pr2.first_14), (lo_card(pr0), ro_card(pr2)), (ro_card(pr0), lo_card(pr2)));
-            let team_2_exch = is_even_odd_or_duplicate_strategy((pr1.first_14, pr3.first_14), (lo_card(pr1), ro_card(pr3)), (ro_card(pr1), lo_card(pr3)));
-            if team_1_exch {
-                exch_rounds[0] += 1;
-                bombs_opp_when_exch[0] += bombs_team_2;
-                round_score_diff_given_exch[0] += round.player_rounds[0].round_score_relative_gain() as i64;
-            } else {
-                not_exch_rounds[0] += 1;
-                bombs_opp_when_not_exch[0] += bombs_team_2;
-            }
-            if team_2_exch {
-                exch_rounds[1] += 1;
-                bombs_opp_when_exch[1] += bombs_team_1;
-                round_score_diff_given_exch[1] += round.player_rounds[1].round_score_relative_gain() as i64;
+    pub small_big_exch_strat_rounds: [usize; 2],
+    pub bombs_opp_when_small_big_exch_strat: [usize; 2],
+    pub round_score_diff_given_small_big_exch: [Mean; 2],
+}
+impl Accumulator for ExchangeStrategyCounts {
+    fn add_round(&mut self, view: &RoundView) {
+        let round = view.round;
+        let bombs_team_1 = (contains_bomb(round.player_rounds[0].final_14()) + contains_bomb(round.player_rounds[2].final_14())).min(1);
+        let bombs_team_2 = (contains_bomb(round.player_rounds[1].final_14()) + contains_bomb(round.player_rounds[3].final_14())).min(1);
+        for (team, bombs_opp) in [bombs_team_2, bombs_team_1].into_iter().enumerate() {
+            let pr_a = &round.player_rounds[team];
+            let pr_b = &round.player_rounds[team + 2];
+            let hands = (pr_a.first_14, pr_b.first_14);
+            let opp_1 = (lo_card(pr_a), ro_card(pr_b));
+            let opp_2 = (ro_card(pr_a), lo_card(pr_b));
+            let round_score_relative_gain = round.player_rounds[team].round_score_relative_gain() as f64;
+            //Check if team follows strategy
+            if is_even_odd_or_duplicate_strategy(hands, opp_1, opp_2) {
+                self.exch_rounds[team] += 1;
+                self.bombs_opp_when_exch[team] += bombs_opp;
+                self.round_score_diff_given_exch[team].add(round_score_relative_gain);
             } else {
-                not_exch_rounds[1] += 1;
-                bombs_opp_when_not_exch[1] += bombs_team_1;
+                self.not_exch_rounds[team] += 1;
+                self.bombs_opp_when_not_exch[team] += bombs_opp;
             }
-
             //Other Exchange strategies:
-            let team_1_double_exch_strat = is_duplicate_strategy((pr0.first_14, pr2.first_14), (lo_card(pr0), ro_card(pr2)), (ro_card(pr0), lo_card(pr2)));
-            let team_2_double_exch_strat = is_duplicate_strategy((pr1.first_14, pr3.first_14), (lo_card(pr1), ro_card(pr3)), (ro_card(pr1), lo_card(pr3)));
-            if team_1_double_exch_strat {
-                double_exch_strat_rounds[0] += 1;
-                bombs_opp_when_double_exch_strat[0] += bombs_team_2;
-                round_score_diff_given_double_exch[0] += round.player_rounds[0].round_score_relative_gain() as i64;
+            if is_duplicate_strategy(hands, opp_1, opp_2) {
+                self.double_exch_strat_rounds[team] += 1;
+                self.bombs_opp_when_double_exch_strat[team] += bombs_opp;
+                self.round_score_diff_given_double_exch[team].add(round_score_relative_gain);
             }
-            if team_2_double_exch_strat {
-                double_exch_strat_rounds[1] += 1;
-                bombs_opp_when_double_exch_strat[1] += bombs_team_1;
-                round_score_diff_given_double_exch[1] += round.player_rounds[1].round_score_relative_gain() as i64;
+            if is_smaller_bigger_strategy(hands, opp_1, opp_2) {
+                self.small_big_exch_strat_rounds[team] += 1;
+                self.bombs_opp_when_small_big_exch_strat[team] += bombs_opp;
+                self.round_score_diff_given_small_big_exch[team].add(round_score_relative_gain);
+            }
+        }
+    }
+    fn merge(&mut self, other: Self) {
+        add_counts(&mut self.bombs_opp_when_exch, &other.bombs_opp_when_exch);
+        add_counts(&mut self.bombs_opp_when_not_exch, &other.bombs_opp_when_not_exch);
+        add_counts(&mut self.exch_rounds, &other.exch_rounds);
+        add_counts(&mut self.not_exch_rounds, &other.not_exch_rounds);
+        add_means(&mut self.round_score_diff_given_exch, &other.round_score_diff_given_exch);
+        add_counts(&mut self.double_exch_strat_rounds, &other.double_exch_strat_rounds);
+        add_counts(&mut self.bombs_opp_when_double_exch_strat, &other.bombs_opp_when_double_exch_strat);
+        add_means(&mut self.round_score_diff_given_double_exch, &other.round_score_diff_given_double_exch);
+        add_counts(&mut self.small_big_exch_strat_rounds, &other.small_big_exch_strat_rounds);
+        add_counts(&mut self.bombs_opp_when_small_big_exch_strat, &other.bombs_opp_when_small_big_exch_strat);
+        add_means(&mut self.round_score_diff_given_small_big_exch, &other.round_score_diff_given_small_big_exch);
+    }
+}
+
+//How many bombs are due to one exchange card in particular? Arrays are indexed by team, split by whether the opponents
+//followed the even_odd duplicate strategy (good exchange) or not (poor exchange).
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct BombOriginCounts {
+    pub bomb_spawn_team: [usize; 2],
+    pub bomb_spawn_team_exch: [usize; 2],
+    pub bomb_spawn_team_poor_exch: [usize; 2],
+    pub bombs_by_partner: [usize; 2],
+    pub bombs_by_partner_exch: [usize; 2],
+    pub bombs_by_partner_poor_exch: [usize; 2],
+    pub bombs_with_one_exch_card: [usize; 2],
+    pub bombs_with_one_exch_card_exch: [usize; 2],
+    pub bombs_with_one_exch_card_poor_exch: [usize; 2],
+    pub bombs_all_exch: [usize; 2],
+    pub bombs_all_exch_poor_exch: [usize; 2],
+    pub bombs_all_exch_exch: [usize; 2],
+}
+impl Accumulator for BombOriginCounts {
+    fn add_round(&mut self, view: &RoundView) {
+        let round = view.round;
+        for team in 0..2 {
+            let bombs_team = (contains_bomb(round.player_rounds[team].final_14()) + contains_bomb(round.player_rounds[2 + team].final_14())).min(1);
+            if bombs_team == 0 {
+                continue;
             }
+            let oteam = 1 - team;
+            let o1 = &round.player_rounds[oteam];
+            let o2 = &round.player_rounds[oteam + 2];
+            //Check strategy of other team
+            let poor_exch = !is_even_odd_or_duplicate_strategy((o1.first_14, o2.first_14), (lo_card(o1), ro_card(o2)), (ro_card(o1), lo_card(o2))) as usize;
 
-            let team_1_small_big_exch_strat = is_smaller_bigger_strategy((pr0.first_14, pr2.first_14), (lo_card(pr0), ro_card(pr2)), (ro_card(pr0), lo_card(pr2)));
-            let team_2_small_big_exch_strat = is_smaller_bigger_strategy((pr1.first_14, pr3.first_14), (lo_card(pr1), ro_card(pr3)), (ro_card(pr1), lo_card(pr3)));
-            if team_1_small_big_exch_strat {
-                small_big_exch_strat_rounds[0] += 1;
-                bombs_opp_when_small_big_exch_strat[0] += bombs_team_2;
-                round_score_diff_given_small_big_exch[0] += round.player_rounds[0].round_score_relative_gain() as i64;
+            let pr = if contains_bomb(round.player_rounds[team].final_14()) > 0 { &round.player_rounds[team] } else { &round.player_rounds[team + 2] };
+            if contains_bomb(pr.first_14) > 0 {
+                self.bomb_spawn_team[team] += 1;
+                self.bomb_spawn_team_exch[team] += 1 - poor_exch;
+                self.bomb_spawn_team_poor_exch[team] += poor_exch;
+                continue;
             }
-            if team_2_small_big_exch_strat {
-                small_big_exch_strat_rounds[1] += 1;
-                bombs_opp_when_small_big_exch_strat[1] += bombs_team_1;
-                round_score_diff_given_small_big_exch[1] += round.player_rounds[1].round_score_relative_gain() as i64;
+            let mut hand = pr.first_14 ^ hand!(pr.right_out_exchange_card(), pr.left_out_exchange_card(), pr.partner_out_exchange_card());
+            if contains_bomb(hand ^ hand!(pr.partner_in_exchange_card())) > 0 {
+                self.bombs_by_partner[team] += 1;
+                self.bombs_by_partner_exch[team] += 1 - poor_exch;
+                self.bombs_by_partner_poor_exch[team] += poor_exch;
+                continue;
             }
+            hand ^= hand!(pr.partner_in_exchange_card());
+            if contains_bomb(hand ^ hand!(pr.left_in_exchange_card())) > 0 || contains_bomb(hand ^ hand!(pr.right_in_exchange_card())) > 0 {
+                self.bombs_with_one_exch_card[team] += 1;
+                self.bombs_with_one_exch_card_exch[team] += 1 - poor_exch;
+                self.bombs_with_one_exch_card_poor_exch[team] += poor_exch;
+                continue;
+            }
+            self.bombs_all_exch[team] += 1;
+            self.bombs_all_exch_exch[team] += 1 - poor_exch;
+            self.bombs_all_exch_poor_exch[team] += poor_exch;
         }
     }
-    println!("ERS given Exchange Strat: {}", format_slice_abs_relative2_i64(&round_score_diff_given_exch, &exch_rounds));
-    println!("Bomb in opponent given Exchange Strat: {}", format_slice_abs_relative2(&bombs_opp_when_exch, &exch_rounds));
-    println!("Bomb in opponent given ! Exchange Strat: {}", format_slice_abs_relative2(&bombs_opp_when_not_exch, &not_exch_rounds));
-    println!("ERS given Double Exchange Strat: {}", format_slice_abs_relative2_i64(&round_score_diff_given_double_exch, &double_exch_strat_rounds));
-    println!("Bomb in opponent given Double Exchange Strat: {}", format_slice_abs_relative2(&bombs_opp_when_double_exch_strat, &double_exch_strat_rounds));
-    println!("ERS given BigSmall Exchange Strat: {}", format_slice_abs_relative2_i64(&round_score_diff_given_small_big_exch, &small_big_exch_strat_rounds));
-    println!("Bomb in opponent given small big Exchange Strat: {}", format_slice_abs_relative2(&bombs_opp_when_small_big_exch_strat, &small_big_exch_strat_rounds));
+    fn merge(&mut self, other: Self) {
+        add_counts(&mut self.bomb_spawn_team, &other.bomb_spawn_team);
+        add_counts(&mut self.bomb_spawn_team_exch, &other.bomb_spawn_team_exch);
+        add_counts(&mut self.bomb_spawn_team_poor_exch, &other.bomb_spawn_team_poor_exch);
+        add_counts(&mut self.bombs_by_partner, &other.bombs_by_partner);
+        add_counts(&mut self.bombs_by_partner_exch, &other.bombs_by_partner_exch);
+        add_counts(&mut self.bombs_by_partner_poor_exch, &other.bombs_by_partner_poor_exch);
+        add_counts(&mut self.bombs_with_one_exch_card, &other.bombs_with_one_exch_card);
+        add_counts(&mut self.bombs_with_one_exch_card_exch, &other.bombs_with_one_exch_card_exch);
+        add_counts(&mut self.bombs_with_one_exch_card_poor_exch, &other.bombs_with_one_exch_card_poor_exch);
+        add_counts(&mut self.bombs_all_exch, &other.bombs_all_exch);
+        add_counts(&mut self.bombs_all_exch_poor_exch, &other.bombs_all_exch_poor_exch);
+        add_counts(&mut self.bombs_all_exch_exch, &other.bombs_all_exch_exch);
+    }
+}
 
-    //How many bombs are due to one exchange card in particular?
-    let mut bomb_spawn_team = [0; 2];
-    let mut bomb_spawn_team_exch = [0; 2];
-    let mut bomb_spawn_team_poor_exch = [0; 2];
-    let mut bombs_by_partner = [0; 2];
-    let mut bombs_by_partner_exch = [0; 2];
-    let mut bombs_by_partner_poor_exch = [0; 2];
-    let mut bombs_with_one_exch_card = [0; 2];
-    let mut bombs_with_one_exch_card_exch = [0; 2];
-    let mut bombs_with_one_exch_card_poor_exch = [0; 2];
-    let mut bombs_all_exch = [0; 2];
-    let mut bombs_all_exch_poor_exch = [0; 2];
-    let mut bombs_all_exch_exch = [0; 2];
+pub type BombStats = (BombCounts, BombCallCounts, ExchangeStrategyCounts, BombOriginCounts);
 
-    for game in db.games.iter() {
-        for (round, _) in game.rounds.iter() {
-            for team in 0..2 {
-                let bombs_team = (contains_bomb(round.player_rounds[team].final_14()) + contains_bomb(round.player_rounds[2 + team].final_14())).min(1);
-                if bombs_team > 0 {
-                    let oteam = 1 - team;
-                    let o1 = round.player_rounds.get(oteam).unwrap();
-                    let o2 = round.player_rounds.get(oteam + 2).unwrap();
-                    //Check strategy of other team
-                    let poor_exch = !is_even_odd_or_duplicate_strategy((o1.first_14, o2.first_14), (lo_card(o1), ro_card(o2)), (ro_card(o1), lo_card(o2))) as usize;
+pub fn evaluate_bomb_stats(db: &DataBase) -> AnalysisReport {
+    let mut report = AnalysisReport::new("bomb_stats");
+    let (counts, calls, strategies, origins) = map_reduce(&db.query(), BombStats::default);
+    //Evaluate bomb probability, first 8, first 14, final 14 for each player.
+    let rounds = counts.rounds;
+    report.add("Bombs under first 8", ReportValue::proportions(&counts.bombs_first_8, rounds));
+    report.add("Bombs under first 14", ReportValue::proportions(&counts.bombs_first_14, rounds));
+    report.add("Bombs under final 14", ReportValue::proportions(&counts.bombs_final_14, rounds));
+    report.add("4-Bombs under final 14", ReportValue::proportions(&counts.four_bombs_final_14, rounds));
+    report.add("Straight-Bombs under final 14", ReportValue::proportions(&counts.straight_bombs_final_14, rounds));
 
-                    let pr = if contains_bomb(round.player_rounds[team].final_14()) > 0 { round.player_rounds.get(team) } else { round.player_rounds.get(team + 2) }.unwrap();
-                    if contains_bomb(pr.first_14) > 0 {
-                        bomb_spawn_team[team] += 1;
-                        bomb_spawn_team_exch[team] += 1 - poor_exch;
-                        bomb_spawn_team_poor_exch[team] += poor_exch;
-                        continue;
-                    }
-                    let mut hand = pr.first_14 ^ hand!(pr.right_out_exchange_card(), pr.left_out_exchange_card(), pr.partner_out_exchange_card());
-                    if contains_bomb(hand ^ hand!(pr.partner_in_exchange_card())) > 0 {
-                        bombs_by_partner[team] += 1;
-                        bombs_by_partner_exch[team] += 1 - poor_exch;
-                        bombs_by_partner_poor_exch[team] += poor_exch;
-                        continue;
-                    }
-                    hand ^= hand!(pr.partner_in_exchange_card());
-                    if contains_bomb(hand ^ hand!(pr.left_in_exchange_card())) > 0 || contains_bomb(hand ^ hand!(pr.right_in_exchange_card())) > 0 {
-                        bombs_with_one_exch_card[team] += 1;
-                        bombs_with_one_exch_card_exch[team] += 1 - poor_exch;
-                        bombs_with_one_exch_card_poor_exch[team] += poor_exch;
-                        continue;
-                    }
-                    bombs_all_exch[team] += 1;
-                    bombs_all_exch_exch[team] += 1 - poor_exch;
-                    bombs_all_exch_poor_exch[team] += poor_exch;
-                }
-            }
-        }
+    //Probability of bomb in team?
+    report.add("Bomb in team", ReportValue::proportions(&counts.bombs_team_rounds, rounds));
+
+    //Probability of bomb in round?
+    report.add("Bomb in round", ReportValue::proportions(&[counts.bombs_round], rounds));
+
+    report.add("ERS given Bomb", ReportValue::Means(calls.round_score_diff_given_bomb.to_vec()));
+    report.add("Bomb on hand given TCall", ReportValue::proportions_pairwise(&calls.bombs_self_when_tcall, &calls.tcall_rounds));
+    report.add("Bomb on hand given GTCall", ReportValue::proportions_pairwise(&calls.bombs_self_when_gtcall, &calls.gtcall_rounds));
+    report.add("Bomb on hand given TCall & <=2 HC", ReportValue::proportions_pairwise(&calls.bombs_self_when_tcall_two_hc_or_less, &calls.tcall_rounds_two_hc_or_less));
+    report.add("Bomb in opponent given any Call", ReportValue::proportions_pairwise(&calls.bombs_opp_when_call, &calls.call_rounds));
+    report.add("Bomb in opponent given Call & No-self bomb", ReportValue::proportions_pairwise(&calls.bombs_opp_when_call_no_bomb, &calls.call_rounds_no_bomb));
+    report.add("Bomb in opponent given Bomb", ReportValue::proportions_pairwise(&calls.bombs_opp_when_bomb, &counts.bombs_final_14));
+    report.add("Tichu Call Rate given self bomb", ReportValue::proportions_pairwise(&calls.tcall_and_bomb_rounds, &counts.bombs_final_14));
+    report.add("Tichu Success Rate given self bomb", ReportValue::proportions_pairwise(&calls.tcall_and_bomb_successes, &calls.tcall_and_bomb_rounds));
+    report.add("Tichu Success Rate given enemy bomb", ReportValue::proportions_pairwise(&calls.tcall_and_enemy_bomb_successes, &calls.tcall_and_enemy_bomb_rounds));
+    report.add("GTichu Success Rate given enemy bomb", ReportValue::proportions_pairwise(&calls.gtcall_and_enemy_bomb_successes, &calls.gtcall_and_enemy_bomb_rounds));
+    for triplet_amt in 0..4{
+        report.add(format!("Bomb on hand given {} triplets on hand before exch", triplet_amt), ReportValue::proportions_pairwise(&calls.bomb_self_given_triplet[triplet_amt], &calls.triplet_rounds[triplet_amt]));
+        report.add(format!("Bomb in opponent given {} triplets on hand before exch", triplet_amt), ReportValue::proportions_pairwise(&calls.bomb_opp_given_triplet[triplet_amt], &calls.triplet_rounds[triplet_amt]));
+        report.add(format!("Bomb in opponent given {} triplets on hand post exch", triplet_amt), ReportValue::proportions_pairwise(&calls.bomb_opp_given_post_exch_triplet[triplet_amt], &calls.triplet_post_exch_rounds[triplet_amt]));
     }
-    println!("% Bomb spawn: {}", format_slice_abs_relative(&bomb_spawn_team, rounds));
-    println!("% Bomb by partner: {}", format_slice_abs_relative(&bombs_by_partner, rounds));
-    println!("% Bomb by one exch card: {}", format_slice_abs_relative(&bombs_with_one_exch_card, rounds));
-    println!("% Bomb by all exch card: {}", format_slice_abs_relative(&bombs_all_exch, rounds));
 
-    println!("% Bomb spawn (given Good Opp Exch): {}", format_slice_abs_relative2(&bomb_spawn_team_exch, &exch_rounds));
-    println!("% Bomb by partner (given GOE): {}", format_slice_abs_relative2(&bombs_by_partner_exch, &exch_rounds));
-    println!("% Bomb by one exch card (given GOE): {}", format_slice_abs_relative2(&bombs_with_one_exch_card_exch, &exch_rounds));
-    println!("% Bomb by all exch card (given GOE): {}", format_slice_abs_relative2(&bombs_all_exch_exch, &exch_rounds));
+    report.add("ERS given Exchange Strat", ReportValue::Means(strategies.round_score_diff_given_exch.to_vec()));
+    report.add("Bomb in opponent given Exchange Strat", ReportValue::proportions_pairwise(&strategies.bombs_opp_when_exch, &strategies.exch_rounds));
+    report.add("Bomb in opponent given ! Exchange Strat", ReportValue::proportions_pairwise(&strategies.bombs_opp_when_not_exch, &strategies.not_exch_rounds));
+    report.add("Bomb in opponent, Exchange Strat vs ! Exchange Strat", ReportValue::proportion_difference(
+        Proportion::new(strategies.bombs_opp_when_exch.iter().sum(), strategies.exch_rounds.iter().sum()),
+        Proportion::new(strategies.bombs_opp_when_not_exch.iter().sum(), strategies.not_exch_rounds.iter().sum())));
+    report.add("ERS given Double Exchange Strat", ReportValue::Means(strategies.round_score_diff_given_double_exch.to_vec()));
+    report.add("Bomb in opponent given Double Exchange Strat", ReportValue::proportions_pairwise(&strategies.bombs_opp_when_double_exch_strat, &strategies.double_exch_strat_rounds));
+    report.add("ERS given BigSmall Exchange Strat", ReportValue::Means(strategies.round_score_diff_given_small_big_exch.to_vec()));
+    report.add("Bomb in opponent given small big Exchange Strat", ReportValue::proportions_pairwise(&strategies.bombs_opp_when_small_big_exch_strat, &strategies.small_big_exch_strat_rounds));
+
+    report.add("% Bomb spawn", ReportValue::proportions(&origins.bomb_spawn_team, rounds));
+    report.add("% Bomb by partner", ReportValue::proportions(&origins.bombs_by_partner, rounds));
+    report.add("% Bomb by one exch card", ReportValue::proportions(&origins.bombs_with_one_exch_card, rounds));
+    report.add("% Bomb by all exch card", ReportValue::proportions(&origins.bombs_all_exch, rounds));
+
+    report.add("% Bomb spawn (given Good Opp Exch)", ReportValue::proportions_pairwise(&origins.bomb_spawn_team_exch, &strategies.exch_rounds));
+    report.add("% Bomb by partner (given GOE)", ReportValue::proportions_pairwise(&origins.bombs_by_partner_exch, &strategies.exch_rounds));
+    report.add("% Bomb by one exch card (given GOE)", ReportValue::proportions_pairwise(&origins.bombs_with_one_exch_card_exch, &strategies.exch_rounds));
+    report.add("% Bomb by all exch card (given GOE)", ReportValue::proportions_pairwise(&origins.bombs_all_exch_exch, &strategies.exch_rounds));
 
-    println!("% Bomb spawn (given Poor Opp Exch): {}", format_slice_abs_relative2(&bomb_spawn_team_poor_exch, &not_exch_rounds));
-    println!("% Bomb by partner (given POE): {}", format_slice_abs_relative2(&bombs_by_partner_poor_exch, &not_exch_rounds));
-    println!("% Bomb by one exch card (given POE): {}", format_slice_abs_relative2(&bombs_with_one_exch_card_poor_exch, &not_exch_rounds));
-    println!("% Bomb by all exch card (given POE): {}", format_slice_abs_relative2(&bombs_all_exch_poor_exch, &not_exch_rounds));
+    report.add("% Bomb spawn (given Poor Opp Exch)", ReportValue::proportions_pairwise(&origins.bomb_spawn_team_poor_exch, &strategies.not_exch_rounds));
+    report.add("% Bomb by partner (given POE)", ReportValue::proportions_pairwise(&origins.bombs_by_partner_poor_exch, &strategies.not_exch_rounds));
+    report.add("% Bomb by one exch card (given POE)", ReportValue::proportions_pairwise(&origins.bombs_with_one_exch_card_poor_exch, &strategies.not_exch_rounds));
+    report.add("% Bomb by all exch card (given POE)", ReportValue::proportions_pairwise(&origins.bombs_all_exch_poor_exch, &strategies.not_exch_rounds));
+    report
 }
 
 fn is_even_odd_or_duplicate_strategy(p_hands: (Hand, Hand), p_opp_1: (CardType, CardType), p_opp_2: (CardType, CardType)) -> bool {
@@ -1,7 +1,9 @@
-use crate::analysis::{format_slice_abs_relative, format_slice_abs_relative2, format_slice_abs_relative2_i64};
+use crate::analysis::report::{AnalysisReport, ReportValue};
+use crate::analysis::statistics::Mean;
 use crate::bsw_binary_format::binary_format_constants::{PlayerIDInternal, CALL_GRAND_TICHU, RANK_1};
 use crate::bsw_binary_format::player_round_hand::PlayerRoundHand;
 use crate::bsw_database::DataBase;
+use crate::database_query::RoundView;
 use crate::{hand, tichu_hand};
 use crate::tichu_hand::{Hand, CardIndex, MAHJONG, SPECIAL_CARD, PHOENIX, DRAGON, DOG, ACE, MASK_ACES, TichuHand};
 
@@ -19,45 +21,31 @@ pub fn get_exchange_card_type(card: CardIndex) -> u8 {
         res
     }
 }
-pub fn evaluate_exchange_stats(db: &DataBase) {
+pub fn evaluate_exchange_stats(db: &DataBase) -> AnalysisReport {
+    let mut report = AnalysisReport::new("exchange_stats");
     let exchanged_mahjong_to_enemy = |prh: &PlayerRoundHand| prh.right_out_exchange_card() == MAHJONG || prh.left_out_exchange_card() == MAHJONG;
-    let exchange_1_to_enemy_rounds = (0..4).map(|player_id| db.games.iter().fold(
-        0, |acc, game| {
-            acc + game.rounds.iter().fold(0, |acc_2, round| {
-                acc_2 + exchanged_mahjong_to_enemy(&round.0.player_rounds[player_id]) as usize
-            })
-        })).collect::<Vec<_>>();
-    let ers_exchange_1_to_enemy = (0..4).map(|player_id| db.games.iter().fold(
-        0i64, |acc, game| {
-            acc + game.rounds.iter().fold(0i64, |acc_2, round| {
-                acc_2 + if exchanged_mahjong_to_enemy(&round.0.player_rounds[player_id]) { round.0.player_rounds[player_id].round_score_relative_gain() as i64 } else { 0 }
-            })
-        })).collect::<Vec<_>>();
-    println!("ERS given exchange of Mahjong to enemy: {}", &format_slice_abs_relative2_i64(&ers_exchange_1_to_enemy, &exchange_1_to_enemy_rounds));
+    let ers_given_exchange = |exchanged: &dyn Fn(&PlayerRoundHand) -> bool| (0..4).map(|player_id| {
+        let mut ers = Mean::default();
+        for RoundView { round, .. } in db.query().rounds() {
+            if exchanged(&round.player_rounds[player_id]) {
+                ers.add(round.player_rounds[player_id].round_score_relative_gain() as f64);
+            }
+        }
+        ers
+    }).collect::<Vec<_>>();
+    report.add("ERS given exchange of Mahjong to enemy", ReportValue::Means(ers_given_exchange(&exchanged_mahjong_to_enemy)));
 
     let exchanged_dog_to_enemy = |prh: &PlayerRoundHand| prh.right_out_exchange_card() == DOG || prh.left_out_exchange_card() == DOG;
-    let exchange_dog_to_enemy_rounds = (0..4).map(|player_id| db.games.iter().fold(
-        0, |acc, game| {
-            acc + game.rounds.iter().fold(0, |acc_2, round| {
-                acc_2 + exchanged_dog_to_enemy(&round.0.player_rounds[player_id]) as usize
-            })
-        })).collect::<Vec<_>>();
-    let ers_exchange_dog_to_enemy = (0..4).map(|player_id| db.games.iter().fold(
-        0i64, |acc, game| {
-            acc + game.rounds.iter().fold(0i64, |acc_2, round| {
-                acc_2 + if exchanged_dog_to_enemy(&round.0.player_rounds[player_id]) { round.0.player_rounds[player_id].round_score_relative_gain() as i64 } else { 0 }
-            })
-        })).collect::<Vec<_>>();
-    println!("ERS given exchange of Dog to enemy: {}", &format_slice_abs_relative2_i64(&ers_exchange_dog_to_enemy, &exchange_dog_to_enemy_rounds));
+    report.add("ERS given exchange of Dog to enemy", ReportValue::Means(ers_given_exchange(&exchanged_dog_to_enemy)));
 
     //Dog+GT, Dog+Double Wins
-    let mut ers_gt_rounds_no_dog_first14_dogfinal14 = [0; 4];
+    let mut ers_gt_rounds_no_dog_first14_dogfinal14 = [Mean::default(); 4];
     let mut gt_successes_no_dog_first14_dogfinal14 = [0; 4];
     let mut double_wins_gt_rounds_no_dog_first14_dogfinal14 = [0; 4];
     let mut gt_rounds_no_dog_first14_dogfinal14 = [0; 4];
     let mut gt_rounds_no_dog_first14 = [0; 4];
 
-    let mut ers_gt_rounds = [0; 4];
+    let mut ers_gt_rounds = [Mean::default(); 4];
     let mut gt_rounds = [0; 4];
     let mut double_wins_gt_rounds = [0; 4];
     let mut gt_rounds_dog_to_partner_if_spawn = [0; 4];
@@ -70,51 +58,53 @@ pub fn evaluate_exchange_stats(db: &DataBase) {
     let mut double_wins = [0; 4];
     let rounds = db.games.iter().fold(0, |acc, inc| acc + inc.rounds.len());
 
-    for game in db.games.iter() {
-        for (round, _) in game.rounds.iter() {
-            for player_id in 0..4 {
-                let prh = &round.player_rounds[player_id];
-                let is_double_win = if player_id % 2 == 0 { prh.is_double_win_team_1() } else { prh.is_double_win_team_2() };
-                let is_gt_call = prh.player_call(player_id as PlayerIDInternal) == CALL_GRAND_TICHU;
-                let no_dog_first14 = prh.first_14 & hand!(DOG) == 0;
-                let dog_to_partner = prh.partner_out_exchange_card() == DOG;
-                let dog_final14 = prh.final_14() & hand!(DOG) != 0;
-                let dog_from_enemy = prh.left_in_exchange_card() == DOG || prh.right_in_exchange_card() == DOG;
-                let round_score_diff = prh.round_score_relative_gain();
-                let gt_success = is_gt_call && prh.player_rank(player_id as PlayerIDInternal) == RANK_1;
-
-                double_wins[player_id] += is_double_win as usize;
-                rounds_dogfromenemy[player_id] += dog_from_enemy as usize;
-                double_wins_rounds_dogfromenemy[player_id] += (dog_from_enemy & is_double_win) as usize;
-
-                double_wins_gt_rounds[player_id] += (is_double_win & is_gt_call) as usize;
-                double_wins_gt_rounds_caller_spawns_dog[player_id] += (is_gt_call & !no_dog_first14 & is_double_win) as usize;
-                gt_rounds_dog_to_partner_if_spawn[player_id] += (is_gt_call & dog_to_partner) as usize;
-                gt_rounds_caller_spawns_dog[player_id] += (is_gt_call & !no_dog_first14) as usize;
-                gt_rounds[player_id] += is_gt_call as usize;
-                ers_gt_rounds[player_id] += is_gt_call as i64 * round_score_diff as i64;
-
-                gt_rounds_no_dog_first14[player_id] += (is_gt_call & no_dog_first14) as usize;
-                gt_rounds_no_dog_first14_dogfinal14[player_id] += (is_gt_call & no_dog_first14 & dog_final14) as usize;
-                double_wins_gt_rounds_no_dog_first14_dogfinal14[player_id] += (is_gt_call & no_dog_first14 & dog_final14 & is_double_win) as usize;
-                gt_successes_no_dog_first14_dogfinal14[player_id] += (no_dog_first14 & dog_final14 & gt_success) as usize;
-                ers_gt_rounds_no_dog_first14_dogfinal14[player_id] += (is_gt_call & no_dog_first14 & dog_final14) as i64 * round_score_diff as i64;
+    for RoundView { round, .. } in db.query().rounds() {
+        for player_id in 0..4 {
+            let prh = &round.player_rounds[player_id];
+            let is_double_win = if player_id % 2 == 0 { prh.is_double_win_team_1() } else { prh.is_double_win_team_2() };
+            let is_gt_call = prh.player_call(player_id as PlayerIDInternal) == CALL_GRAND_TICHU;
+            let no_dog_first14 = prh.first_14 & hand!(DOG) == 0;
+            let dog_to_partner = prh.partner_out_exchange_card() == DOG;
+            let dog_final14 = prh.final_14() & hand!(DOG) != 0;
+            let dog_from_enemy = prh.left_in_exchange_card() == DOG || prh.right_in_exchange_card() == DOG;
+            let round_score_diff = prh.round_score_relative_gain();
+            let gt_success = is_gt_call && prh.player_rank(player_id as PlayerIDInternal) == RANK_1;
+
+            double_wins[player_id] += is_double_win as usize;
+            rounds_dogfromenemy[player_id] += dog_from_enemy as usize;
+            double_wins_rounds_dogfromenemy[player_id] += (dog_from_enemy & is_double_win) as usize;
+
+            double_wins_gt_rounds[player_id] += (is_double_win & is_gt_call) as usize;
+            double_wins_gt_rounds_caller_spawns_dog[player_id] += (is_gt_call & !no_dog_first14 & is_double_win) as usize;
+            gt_rounds_dog_to_partner_if_spawn[player_id] += (is_gt_call & dog_to_partner) as usize;
+            gt_rounds_caller_spawns_dog[player_id] += (is_gt_call & !no_dog_first14) as usize;
+            gt_rounds[player_id] += is_gt_call as usize;
+            if is_gt_call {
+                ers_gt_rounds[player_id].add(round_score_diff as f64);
+            }
+
+            gt_rounds_no_dog_first14[player_id] += (is_gt_call & no_dog_first14) as usize;
+            gt_rounds_no_dog_first14_dogfinal14[player_id] += (is_gt_call & no_dog_first14 & dog_final14) as usize;
+            double_wins_gt_rounds_no_dog_first14_dogfinal14[player_id] += (is_gt_call & no_dog_first14 & dog_final14 & is_double_win) as usize;
+            gt_successes_no_dog_first14_dogfinal14[player_id] += (no_dog_first14 & dog_final14 & gt_success) as usize;
+            if is_gt_call & no_dog_first14 & dog_final14 {
+                ers_gt_rounds_no_dog_first14_dogfinal14[player_id].add(round_score_diff as f64);
             }
         }
     }
 
-    println!("Double Wins: {}", format_slice_abs_relative(&double_wins, rounds));
-    println!("Double Wins if dog from enemy: {}", format_slice_abs_relative2(&double_wins_rounds_dogfromenemy, &rounds_dogfromenemy));
-    println!("Double Wins if GT call: {}", format_slice_abs_relative2(&double_wins_gt_rounds, &gt_rounds));
-    println!("Double Wins if GT call & Caller gets dog: {}", format_slice_abs_relative2(&double_wins_gt_rounds_no_dog_first14_dogfinal14, &gt_rounds_no_dog_first14_dogfinal14));
-    println!("Double Wins if GT call & Caller spawns with dog: {}", format_slice_abs_relative2(&double_wins_gt_rounds_caller_spawns_dog, &gt_rounds_caller_spawns_dog));
-    println!("Pr of exch Dog to Partner if GT call & Caller Spawns with dog: {} ", format_slice_abs_relative2(&gt_rounds_dog_to_partner_if_spawn, &gt_rounds_caller_spawns_dog));
+    report.add("Double Wins", ReportValue::proportions(&double_wins, rounds));
+    report.add("Double Wins if dog from enemy", ReportValue::proportions_pairwise(&double_wins_rounds_dogfromenemy, &rounds_dogfromenemy));
+    report.add("Double Wins if GT call", ReportValue::proportions_pairwise(&double_wins_gt_rounds, &gt_rounds));
+    report.add("Double Wins if GT call & Caller gets dog", ReportValue::proportions_pairwise(&double_wins_gt_rounds_no_dog_first14_dogfinal14, &gt_rounds_no_dog_first14_dogfinal14));
+    report.add("Double Wins if GT call & Caller spawns with dog", ReportValue::proportions_pairwise(&double_wins_gt_rounds_caller_spawns_dog, &gt_rounds_caller_spawns_dog));
+    report.add("Pr of exch Dog to Partner if GT call & Caller Spawns with dog", ReportValue::proportions_pairwise(&gt_rounds_dog_to_partner_if_spawn, &gt_rounds_caller_spawns_dog));
 
-    println!("ERS if GT call: {}", format_slice_abs_relative2_i64(&ers_gt_rounds, &gt_rounds));
-    println!("ERS if GT call & Caller gets dog: {}", format_slice_abs_relative2_i64(&ers_gt_rounds_no_dog_first14_dogfinal14, &gt_rounds_no_dog_first14_dogfinal14));
+    report.add("ERS if GT call", ReportValue::Means(ers_gt_rounds.to_vec()));
+    report.add("ERS if GT call & Caller gets dog", ReportValue::Means(ers_gt_rounds_no_dog_first14_dogfinal14.to_vec()));
 
-    println!("Pr of  Caller gets dog given Caller has no dog in first14: {}", format_slice_abs_relative2(&gt_rounds_no_dog_first14_dogfinal14, &gt_rounds_no_dog_first14));
-    println!("Pr of  GT Success if Caller gets dog: {}", format_slice_abs_relative2(&gt_successes_no_dog_first14_dogfinal14, &gt_rounds_no_dog_first14_dogfinal14));
+    report.add("Pr of  Caller gets dog given Caller has no dog in first14", ReportValue::proportions_pairwise(&gt_rounds_no_dog_first14_dogfinal14, &gt_rounds_no_dog_first14));
+    report.add("Pr of  GT Success if Caller gets dog", ReportValue::proportions_pairwise(&gt_successes_no_dog_first14_dogfinal14, &gt_rounds_no_dog_first14_dogfinal14));
 
 
     //Swap away cards
@@ -128,41 +118,39 @@ pub fn evaluate_exchange_stats(db: &DataBase) {
     let rounds = db.games.iter().fold(0, |acc, inc| acc + inc.rounds.len());
     let mut gt_rounds = [0; 4];
 
-    for game in db.games.iter() {
-        for (round, _) in game.rounds.iter() {
-            for player_id in 0..4 {
-                let prh = &round.player_rounds[player_id as usize];
-                let receiv_left = get_exchange_card_type(prh.left_in_exchange_card());
-                let receiv_partner = get_exchange_card_type(prh.partner_in_exchange_card());
-                let receiv_right = get_exchange_card_type(prh.right_in_exchange_card());
-
-                receiv_left_cards[receiv_left as usize][player_id as usize] += 1;
-                receiv_partner_cards[receiv_partner as usize][player_id as usize] += 1;
-                receiv_right_cards[receiv_right as usize][player_id as usize] += 1;
-
-                if prh.player_call(player_id) == CALL_GRAND_TICHU {
-                    gt_rounds[player_id as usize] += 1;
-                    receiv_left_cards_gt[receiv_left as usize][player_id as usize] += 1;
-                    receiv_partner_cards_gt[receiv_partner as usize][player_id as usize] += 1;
-                    receiv_right_cards_gt[receiv_right as usize][player_id as usize] += 1;
-                }
+    for RoundView { round, .. } in db.query().rounds() {
+        for player_id in 0..4 {
+            let prh = &round.player_rounds[player_id as usize];
+            let receiv_left = get_exchange_card_type(prh.left_in_exchange_card());
+            let receiv_partner = get_exchange_card_type(prh.partner_in_exchange_card());
+            let receiv_right = get_exchange_card_type(prh.right_in_exchange_card());
+
+            receiv_left_cards[receiv_left as usize][player_id as usize] += 1;
+            receiv_partner_cards[receiv_partner as usize][player_id as usize] += 1;
+            receiv_right_cards[receiv_right as usize][player_id as usize] += 1;
+
+            if prh.player_call(player_id) == CALL_GRAND_TICHU {
+                gt_rounds[player_id as usize] += 1;
+                receiv_left_cards_gt[receiv_left as usize][player_id as usize] += 1;
+                receiv_partner_cards_gt[receiv_partner as usize][player_id as usize] += 1;
+                receiv_right_cards_gt[receiv_right as usize][player_id as usize] += 1;
             }
         }
     }
 
-    println!("Exchange card in from left: {:?}", receiv_left_cards.map(|x| x.iter().sum::<usize>() as f64 / (4. * rounds as f64)));
-    println!("Exchange card in from partner: {:?}", receiv_partner_cards.map(|x| x.iter().sum::<usize>() as f64 / (4. * rounds as f64)));
-    println!("Exchange card in from right: {:?}", receiv_right_cards.map(|x| x.iter().sum::<usize>() as f64 / (4. * rounds as f64)));
+    report.add("Exchange card in from left", ReportValue::Floats(receiv_left_cards.map(|x| x.iter().sum::<usize>() as f64 / (4. * rounds as f64)).to_vec()));
+    report.add("Exchange card in from partner", ReportValue::Floats(receiv_partner_cards.map(|x| x.iter().sum::<usize>() as f64 / (4. * rounds as f64)).to_vec()));
+    report.add("Exchange card in from right", ReportValue::Floats(receiv_right_cards.map(|x| x.iter().sum::<usize>() as f64 / (4. * rounds as f64)).to_vec()));
 
-    println!("Exchange card in from left given GT call: {:?}", receiv_left_cards_gt.map(|x| x.iter().zip(gt_rounds.iter()).fold(
+    report.add("Exchange card in from left given GT call", ReportValue::Floats(receiv_left_cards_gt.map(|x| x.iter().zip(gt_rounds.iter()).fold(
         0., |acc, inc| acc + (*inc.0 as f64) / (*inc.1 as f64)) / 4.
-    ));
-    println!("Exchange card in from partner given GT call: {:?}", receiv_partner_cards_gt.map(|x| x.iter().zip(gt_rounds.iter()).fold(
+    ).to_vec()));
+    report.add("Exchange card in from partner given GT call", ReportValue::Floats(receiv_partner_cards_gt.map(|x| x.iter().zip(gt_rounds.iter()).fold(
         0., |acc, inc| acc + (*inc.0 as f64) / (*inc.1 as f64)) / 4.
-    ));
-    println!("Exchange card in from right given GT call: {:?}", receiv_right_cards_gt.map(|x| x.iter().zip(gt_rounds.iter()).fold(
+    ).to_vec()));
+    report.add("Exchange card in from right given GT call", ReportValue::Floats(receiv_right_cards_gt.map(|x| x.iter().zip(gt_rounds.iter()).fold(
         0., |acc, inc| acc + (*inc.0 as f64) / (*inc.1 as f64)) / 4.
-    ));
+    ).to_vec()));
 
     //Swapping away Aces...
     let mut swap_ace_to_partner_no_gt = [0; 4];
@@ -172,29 +160,28 @@ pub fn evaluate_exchange_stats(db: &DataBase) {
     let mut swap_ace_to_partner_only_high_card_no_gt = [0; 4];
 
     let mut swap_ace_to_partner_with_two_aces_no_gt = [0; 4];
-    for game in db.games.iter() {
-        for (round, _) in game.rounds.iter() {
-            for player_id in 0..4 {
-                let prh = &round.player_rounds[player_id as usize];
-                if prh.player_call((player_id + 2) % 4) == CALL_GRAND_TICHU {
-                    continue;
-                }
-                let ace_to_partner = tichu_hand::get_card_type(prh.partner_out_exchange_card()) == ACE;
-                let two_aces = (prh.first_14 & MASK_ACES).count_ones() >= 2;
-                let ace_only_high_card = prh.first_14.get_high_card_amt() == 1 && prh.first_14 & MASK_ACES != 0;
-
-                no_gt_rounds[player_id as usize] += 1;
-                swap_ace_to_partner_no_gt[player_id as usize] += ace_to_partner as usize;
-
-                swap_ace_to_partner_with_two_aces_no_gt[player_id as usize] += (ace_to_partner & two_aces) as usize;
-
-                rounds_ace_only_high_card_no_gt[player_id as usize] += ace_only_high_card as usize;
-                swap_ace_to_partner_only_high_card_no_gt[player_id as usize] += (ace_only_high_card & ace_to_partner) as usize;
+    for RoundView { round, .. } in db.query().rounds() {
+        for player_id in 0..4 {
+            let prh = &round.player_rounds[player_id as usize];
+            if prh.player_call((player_id + 2) % 4) == CALL_GRAND_TICHU {
+                continue;
             }
+            let ace_to_partner = tichu_hand::get_card_type(prh.partner_out_exchange_card()) == ACE;
+            let two_aces = (prh.first_14 & MASK_ACES).count_ones() >= 2;
+            let ace_only_high_card = prh.first_14.get_high_card_amt() == 1 && prh.first_14 & MASK_ACES != 0;
+
+            no_gt_rounds[player_id as usize] += 1;
+            swap_ace_to_partner_no_gt[player_id as usize] += ace_to_partner as usize;
+
+            swap_ace_to_partner_with_two_aces_no_gt[player_id as usize] += (ace_to_partner & two_aces) as usize;
+
+            rounds_ace_only_high_card_no_gt[player_id as usize] += ace_only_high_card as usize;
+            swap_ace_to_partner_only_high_card_no_gt[player_id as usize] += (ace_only_high_card & ace_to_partner) as usize;
         }
     }
 
-    println!("Pr of giving Partner Ace given no GT call: {}", format_slice_abs_relative2(&swap_ace_to_partner_no_gt, &no_gt_rounds));
-    println!("Pr of giving Partner Ace given no GT call & only high card: {}", format_slice_abs_relative2(&swap_ace_to_partner_only_high_card_no_gt, &rounds_ace_only_high_card_no_gt));
-    println!("Pr of having two Aces given Partner has received Ace from me & no gt call: {}", format_slice_abs_relative2(&swap_ace_to_partner_with_two_aces_no_gt, &swap_ace_to_partner_no_gt));
+    report.add("Pr of giving Partner Ace given no GT call", ReportValue::proportions_pairwise(&swap_ace_to_partner_no_gt, &no_gt_rounds));
+    report.add("Pr of giving Partner Ace given no GT call & only high card", ReportValue::proportions_pairwise(&swap_ace_to_partner_only_high_card_no_gt, &rounds_ace_only_high_card_no_gt));
+    report.add("Pr of having two Aces given Partner has received Ace from me & no gt call", ReportValue::proportions_pairwise(&swap_ace_to_partner_with_two_aces_no_gt, &swap_ace_to_partner_no_gt));
+    report
 }
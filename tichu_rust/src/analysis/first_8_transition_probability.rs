@@ -2,15 +2,18 @@
 //We calculate the probability P(A|B) where A denotes some of the 80 options under the first 14 cards given that B is some option of the first 8 cards.
 
 use crate::analysis::gt_stats::HandCategory;
+use crate::analysis::report::{AnalysisReport, ReportValue};
 
-pub fn calculate_transition_probabilities(){
+pub fn calculate_transition_probabilities() -> AnalysisReport {
+    let mut report = AnalysisReport::new("first_8_transition_probabilities");
     let mut transition_matrix = [[0.;80];80];
     for cat_1 in 0..80{
         for cat_2 in 0..80{
             transition_matrix[cat_1][cat_2]= get_transition_probability(HandCategory(cat_1), HandCategory(cat_2));
         }
     }
-    println!("{:?}", transition_matrix);
+    report.add("Transition matrix", ReportValue::float_matrix(&transition_matrix));
+    report
 }
 pub fn get_transition_probability(from_first_8: HandCategory, to_first_14: HandCategory) -> f64 {
     if from_first_8.num_aces() > to_first_14.num_aces() || from_first_8.0 & to_first_14.0 & 0b1111 != from_first_8.0 & 0b1111 {
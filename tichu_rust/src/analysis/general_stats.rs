@@ -1,14 +1,16 @@
-use crate::analysis::{format_slice_abs_relative, format_slice_abs_relative2, format_slice_abs_relative2_i64};
+use crate::analysis::report::{AnalysisReport, ReportValue};
+use crate::analysis::statistics::Mean;
 use crate::bsw_binary_format::binary_format_constants::{PlayerIDInternal, CALL_GRAND_TICHU, CALL_NONE, CALL_TICHU, RANK_1, RANK_3};
 use crate::bsw_binary_format::binary_format_constants::Team::{Team1, Team2};
 use crate::bsw_database::DataBase;
 use crate::hand;
 use crate::tichu_hand::{Hand, DRAGON};
 
-pub fn evaluate_general_stats(db: &DataBase) {
+pub fn evaluate_general_stats(db: &DataBase) -> AnalysisReport {
+    let mut report = AnalysisReport::new("general_stats");
     //Evaluate bomb probability, first 8, first 14, final 14 for each player.
     let rounds = db.games.iter().fold(0, |acc, inc| acc + inc.rounds.len());
-    println!("Rounds: {}", rounds);
+    report.add("Rounds", ReportValue::Integer(rounds as i64));
     let tichu_calls = (0..4).map(|player_id| db.games.iter().fold(0, |acc, inc| acc + inc.rounds.iter().fold(
         0, |acc_2, inc_2| acc_2 + (inc_2.0.player_rounds[0].player_call(player_id) == CALL_TICHU) as usize,
     ))).collect::<Vec<_>>();
@@ -17,8 +19,8 @@ pub fn evaluate_general_stats(db: &DataBase) {
     ))).collect::<Vec<_>>();
 
 
-    println!("Tichu Calls {}", format_slice_abs_relative(&tichu_calls, rounds));
-    println!("Grand Tichu Calls: {}", format_slice_abs_relative(&gtichu_calls, rounds));
+    report.add("Tichu Calls", ReportValue::proportions(&tichu_calls, rounds));
+    report.add("Grand Tichu Calls", ReportValue::proportions(&gtichu_calls, rounds));
 
     //Count tichu, grand tichu success rate.
     let mut tichu_successes = [0; 4];
@@ -40,14 +42,16 @@ pub fn evaluate_general_stats(db: &DataBase) {
         }
     }
 
-    println!("Tichu successes: {}", format_slice_abs_relative2(&tichu_successes, &tichu_calls));
-    println!("GTichu successes: {}", format_slice_abs_relative2(&gtichu_successes, &gtichu_calls));
-    println!("Wins(Team): {}", format_slice_abs_relative(&wins, db.games.len()));
-    println!("Draws: {}", format_slice_abs_relative(&[draws], db.games.len()));
-    println!("DoubleWins(Team): {}", format_slice_abs_relative(&double_wins, rounds));
+    report.add("Tichu successes", ReportValue::proportions_pairwise(&tichu_successes, &tichu_calls));
+    report.add("GTichu successes", ReportValue::proportions_pairwise(&gtichu_successes, &gtichu_calls));
+    report.add("Wins(Team)", ReportValue::proportions(&wins, db.games.len()));
+    report.add("Draws", ReportValue::proportions(&[draws], db.games.len()));
+    report.add("DoubleWins(Team)", ReportValue::proportions(&double_wins, rounds));
+    report
 }
 
-pub fn evaluate_general_stats_onlyr0(db: &DataBase) {
+pub fn evaluate_general_stats_onlyr0(db: &DataBase) -> AnalysisReport {
+    let mut report = AnalysisReport::new("general_stats_r0");
     //Evaluate bomb probability, first 8, first 14, final 14 for each player.
     let rounds = db.games.iter().fold(0, |acc, inc| acc + inc.rounds.len().min(1));
 
@@ -61,8 +65,8 @@ pub fn evaluate_general_stats_onlyr0(db: &DataBase) {
     )).collect::<Vec<_>>();
 
 
-    println!("Tichu Calls {}", format_slice_abs_relative(&tichu_calls, rounds));
-    println!("Grand Tichu Calls: {}", format_slice_abs_relative(&gtichu_calls, rounds));
+    report.add("Tichu Calls", ReportValue::proportions(&tichu_calls, rounds));
+    report.add("Grand Tichu Calls", ReportValue::proportions(&gtichu_calls, rounds));
 
     //Count tichu, grand tichu success rate.
     let mut tichu_successes = [0; 4];
@@ -71,7 +75,7 @@ pub fn evaluate_general_stats_onlyr0(db: &DataBase) {
     let mut wins = [0; 2];
     let mut double_wins = [0; 2];
 
-    let mut cardpoints_by_category = [[[0i64; 2]; 4];2];
+    let mut cardpoints_by_category = [[[Mean::default(); 2]; 4];2];
     let mut category_rounds = [[[0; 2]; 4];2];
     let category_descriptions = ["1. + 3.", "1. + 4.", "2. + 3.", "2. + 4."];
     for game in db.games.iter() {
@@ -100,19 +104,20 @@ pub fn evaluate_general_stats_onlyr0(db: &DataBase) {
             let category = if team_ranks[0] == RANK_1{team_ranks[1]-RANK_3} else{2+team_ranks[1]-RANK_3} as usize;
             let dragon_in_team =((pr_a.final_14() | pr_b.final_14()) & hand!(DRAGON) != 0) as usize;
             category_rounds[dragon_in_team][category][team] += 1;
-            cardpoints_by_category[dragon_in_team][category][team] += cardpoints_team;
+            cardpoints_by_category[dragon_in_team][category][team].add(cardpoints_team as f64);
         }
     }
 
-    println!("Tichu successes: {}", format_slice_abs_relative2(&tichu_successes, &tichu_calls));
-    println!("GTichu successes: {}", format_slice_abs_relative2(&gtichu_successes, &gtichu_calls));
-    println!("Wins(Team): {}", format_slice_abs_relative(&wins, db.games.len()));
-    println!("Draws: {}", format_slice_abs_relative(&[draws], db.games.len()));
-    println!("DoubleWins(Team): {}", format_slice_abs_relative(&double_wins, rounds));
+    report.add("Tichu successes", ReportValue::proportions_pairwise(&tichu_successes, &tichu_calls));
+    report.add("GTichu successes", ReportValue::proportions_pairwise(&gtichu_successes, &gtichu_calls));
+    report.add("Wins(Team)", ReportValue::proportions(&wins, db.games.len()));
+    report.add("Draws", ReportValue::proportions(&[draws], db.games.len()));
+    report.add("DoubleWins(Team)", ReportValue::proportions(&double_wins, rounds));
     for category in 0..4 {
-        for dr in 0..2{
+        for (dr, cardpoints) in cardpoints_by_category.iter().enumerate() {
             let dragon_str = if dr == 0{"Dragon in enemy team"}else{"Dragon in Team"};
-            println!("Expected card points given category {}({}): {}", category_descriptions[category], dragon_str, format_slice_abs_relative2_i64(&cardpoints_by_category[dr][category], &category_rounds[dr][category]));
+            report.add(format!("Expected card points given category {}({})", category_descriptions[category], dragon_str), ReportValue::Means(cardpoints[category].to_vec()));
         }
     }
+    report
 }
\ No newline at end of file
@@ -2,7 +2,10 @@ use generic_array::{typenum, GenericArray};
 use generic_array::typenum::U80;
 use crate::bsw_binary_format::binary_format_constants::{PlayerIDInternal, Score, CALL_GRAND_TICHU, RANK_1};
 use crate::bsw_database::DataBase;
-use crate::countable_properties::CountableProperty;
+use crate::database_query::RoundView;
+use crate::analysis::report::{AnalysisReport, ReportValue};
+use crate::analysis::statistics::Proportion;
+use crate::countable_properties::{PropertyRange, SingleValueProperty};
 use crate::enumerate_hands::count_special_card_sensitive_property;
 use crate::hand;
 use crate::tichu_hand::{Hand, DOG, DRAGON, MAHJONG, MASK_ACES, MASK_KINGS, PHOENIX};
@@ -50,7 +53,8 @@ impl HandCategory {
     }
 }
 
-pub fn evaluate_gt_stats(db: &DataBase) {
+pub fn evaluate_gt_stats(db: &DataBase) -> AnalysisReport {
+    let mut report = AnalysisReport::new("gt_stats");
     let mut gt_categories = [[0; 4]; 80];
     let mut gt_round_score_diff_by_cat = [[0i64; 4]; 80];
     let mut gt_round_score_diff_by_first14_cat = [[0i64; 4]; 80];
@@ -58,51 +62,55 @@ pub fn evaluate_gt_stats(db: &DataBase) {
     let mut gt_calls_first14 = [[0;4]; 80];
     let mut gt_successes = [[0; 4]; 80];
 
-    for game in db.games.iter() {
-        for (round, _) in game.rounds.iter() {
-            for player_id in 0..4 {
-                let prh = &round.player_rounds[player_id];
-                let category = HandCategory::categorize_hand(&prh.first_8);
-                gt_categories[category.0][player_id] += 1;
-                if prh.player_call(player_id as PlayerIDInternal) == CALL_GRAND_TICHU {
-                    gt_calls[category.0][player_id] += 1;
-                    gt_successes[category.0][player_id] += (prh.player_rank(player_id as PlayerIDInternal) == RANK_1) as usize;
-                    gt_round_score_diff_by_cat[category.0][player_id] += prh.round_score_relative_gain() as i64;
-                    let cat_14 = HandCategory::categorize_hand(&prh.first_14);
-                    gt_round_score_diff_by_first14_cat[cat_14.0][player_id] += prh.round_score_relative_gain() as i64;
-                    gt_calls_first14[cat_14.0][player_id] += 1;
-                }
+    for RoundView { round, .. } in db.query().rounds() {
+        for player_id in 0..4 {
+            let prh = &round.player_rounds[player_id];
+            let category = HandCategory::categorize_hand(&prh.first_8);
+            gt_categories[category.0][player_id] += 1;
+            if prh.player_call(player_id as PlayerIDInternal) == CALL_GRAND_TICHU {
+                gt_calls[category.0][player_id] += 1;
+                gt_successes[category.0][player_id] += (prh.player_rank(player_id as PlayerIDInternal) == RANK_1) as usize;
+                gt_round_score_diff_by_cat[category.0][player_id] += prh.round_score_relative_gain() as i64;
+                let cat_14 = HandCategory::categorize_hand(&prh.first_14);
+                gt_round_score_diff_by_first14_cat[cat_14.0][player_id] += prh.round_score_relative_gain() as i64;
+                gt_calls_first14[cat_14.0][player_id] += 1;
             }
         }
     }
-    println!("GT Calls abs: {:?}", gt_calls);
-    println!("GT Calls_14 abs: {:?}", gt_calls_first14);
-    println!("GT Round scoree diff abs: {:?}", gt_round_score_diff_by_cat);
-    println!("GT Round score diff (first 14) abs: {:?}", gt_round_score_diff_by_first14_cat);
-    println!("GT Categories abs: {:?}", gt_categories);
-    println!("GT Successes abs: {:?}", gt_successes);
+    report.add("GT Calls abs", ReportValue::integer_matrix(&gt_calls));
+    report.add("GT Calls_14 abs", ReportValue::integer_matrix(&gt_calls_first14));
+    report.add("GT Round score diff abs", ReportValue::integer_matrix(&gt_round_score_diff_by_cat));
+    report.add("GT Round score diff (first 14) abs", ReportValue::integer_matrix(&gt_round_score_diff_by_first14_cat));
+    report.add("GT Categories abs", ReportValue::integer_matrix(&gt_categories));
+    report.add("GT Successes abs", ReportValue::integer_matrix(&gt_successes));
+    for category in 0..80 {
+        let success_rate = Proportion::new(gt_successes[category].iter().sum(), gt_calls[category].iter().sum());
+        if success_rate.trials > 0 {
+            report.add(format!("GT Success category {}", category), ReportValue::Proportions(vec![success_rate]));
+        }
+    }
 
     let mut non_gt_categories = [[0; 4]; 80];
     let mut non_gt_round_score_diff = [[0i64; 4]; 80];
-    for game in db.games.iter() {
-        for (round, _) in game.rounds.iter() {
-            for player_id in 0..4 {
-                let prh = &round.player_rounds[player_id];
-                let category = HandCategory::categorize_hand(&prh.first_14);
-                if prh.player_call(player_id as PlayerIDInternal) == CALL_GRAND_TICHU {
-                    continue;
-                }
-                non_gt_categories[category.0][player_id] += 1;
-                non_gt_round_score_diff[category.0][player_id] += prh.round_score_relative_gain() as i64;
+    for RoundView { round, .. } in db.query().rounds() {
+        for player_id in 0..4 {
+            let prh = &round.player_rounds[player_id];
+            let category = HandCategory::categorize_hand(&prh.first_14);
+            if prh.player_call(player_id as PlayerIDInternal) == CALL_GRAND_TICHU {
+                continue;
             }
+            non_gt_categories[category.0][player_id] += 1;
+            non_gt_round_score_diff[category.0][player_id] += prh.round_score_relative_gain() as i64;
         }
     }
 
-    println!("Non-gt Categories abs: {:?}", non_gt_categories);
-    println!("Non-gt ERS abs: {:?}", non_gt_round_score_diff);
+    report.add("Non-gt Categories abs", ReportValue::integer_matrix(&non_gt_categories));
+    report.add("Non-gt ERS abs", ReportValue::integer_matrix(&non_gt_round_score_diff));
+    report
 }
 
-pub fn evaluate_gt_win_probs(db: &DataBase){
+pub fn evaluate_gt_win_probs(db: &DataBase) -> AnalysisReport {
+    let mut report = AnalysisReport::new("gt_win_probs");
     let mut gt_round_score_diff_by_cat14 = [[0usize; 93]; 80];
     let mut nongt_round_score_diff_by_cat14 = [[0usize; 93]; 80];
 
@@ -116,30 +124,28 @@ pub fn evaluate_gt_win_probs(db: &DataBase){
         }
     };
 
-    for game in db.games.iter() {
-        for (round, _) in game.rounds.iter() {
-            for player_id in 0..4 {
-                let prh = &round.player_rounds[player_id];
-                let category = HandCategory::categorize_hand(&prh.first_14);
-                let round_diff = prh.round_score_relative_gain();
-                let round_diff_idx = round_score_diff_to_index(round_diff);
-                if prh.player_call(player_id as PlayerIDInternal) == CALL_GRAND_TICHU {
-                    //gt_calls_bycat14[category.0][player_id] += 1;
-                    gt_round_score_diff_by_cat14[category.0][round_diff_idx] += 1;
-                }else{
-                    //nongt_calls_bycat14[category.0][player_id] += 1;
-                    nongt_round_score_diff_by_cat14[category.0][round_diff_idx] += 1;
-                }
+    for RoundView { round, .. } in db.query().rounds() {
+        for player_id in 0..4 {
+            let prh = &round.player_rounds[player_id];
+            let category = HandCategory::categorize_hand(&prh.first_14);
+            let round_diff = prh.round_score_relative_gain();
+            let round_diff_idx = round_score_diff_to_index(round_diff);
+            if prh.player_call(player_id as PlayerIDInternal) == CALL_GRAND_TICHU {
+                //gt_calls_bycat14[category.0][player_id] += 1;
+                gt_round_score_diff_by_cat14[category.0][round_diff_idx] += 1;
+            }else{
+                //nongt_calls_bycat14[category.0][player_id] += 1;
+                nongt_round_score_diff_by_cat14[category.0][round_diff_idx] += 1;
             }
         }
     }
 
-    println!("Round Score count cat14 given GT: {:?}", gt_round_score_diff_by_cat14);
-    println!("Round Score count cat14 given non GT: {:?}", nongt_round_score_diff_by_cat14);
-
-
+    report.add("Round Score count cat14 given GT", ReportValue::integer_matrix(&gt_round_score_diff_by_cat14));
+    report.add("Round Score count cat14 given non GT", ReportValue::integer_matrix(&nongt_round_score_diff_by_cat14));
+    report
 }
-pub fn evaluate_gt_call_rates(hand_category_count: GenericArray<u64, U80>) {
+pub fn evaluate_gt_call_rates(hand_category_count: GenericArray<u64, U80>) -> AnalysisReport {
+    let mut report = AnalysisReport::new("gt_call_rates");
     let gt_hands = 1420494075;
     //1. Strategy: Call GT with Ace + Joker or both Jokers
     let strat_1_hands = hand_category_count.iter().enumerate().filter(|(cat, _)| {
@@ -161,17 +167,19 @@ pub fn evaluate_gt_call_rates(hand_category_count: GenericArray<u64, U80>) {
     //3. Strategy is custom formula, which we have to enumerate GT Hands with again.
     let strat_4_hands = count_special_card_sensitive_property::<CountCustomGTStrategy, 8>(CountCustomGTStrategy).property_counted[1];
 
-    println!("GT Call Rate Strat 1(Ace+J or Two J): {}", strat_1_hands as f64 / gt_hands as f64);
-    println!("GT Call Rate Strat 2(Ace+J + 1/Dog or Two J + 1/Dog): {}", strat_2_hands as f64 / gt_hands as f64);
-    println!("GT Call Rate Strat 3(2Ace+J or Ace+Two J): {}", strat_3_hands as f64 / gt_hands as f64);
-    println!("GT Call Rate Strat 4(Custom Formula): {}", strat_4_hands as f64 / gt_hands as f64);
+    report.add("GT Call Rate Strat 1(Ace+J or Two J)", ReportValue::Float(strat_1_hands as f64 / gt_hands as f64));
+    report.add("GT Call Rate Strat 2(Ace+J + 1/Dog or Two J + 1/Dog)", ReportValue::Float(strat_2_hands as f64 / gt_hands as f64));
+    report.add("GT Call Rate Strat 3(2Ace+J or Ace+Two J)", ReportValue::Float(strat_3_hands as f64 / gt_hands as f64));
+    report.add("GT Call Rate Strat 4(Custom Formula)", ReportValue::Float(strat_4_hands as f64 / gt_hands as f64));
+    report
 }
 
 #[derive(Debug, Clone)]
 pub struct CountCustomGTStrategy;
-impl CountableProperty for CountCustomGTStrategy {
+impl PropertyRange for CountCustomGTStrategy {
     type UpperBound = typenum::U2;
-
+}
+impl SingleValueProperty for CountCustomGTStrategy {
     fn count(&self, hand: &Hand) -> usize {
         let num_aces = (hand & MASK_ACES).count_ones();
         let num_kings = (hand & MASK_KINGS).count_ones();
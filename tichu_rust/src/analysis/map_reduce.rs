@@ -0,0 +1,67 @@
+use std::thread;
+use crate::bsw_binary_format::game::Game;
+use crate::database_query::{Query, RoundView};
+
+//Statistic that can be computed on parts of the database independently and merged afterwards.
+//Every worker thread starts from its own fresh accumulator, the partial results are merged in game order.
+pub trait Accumulator: Send {
+    fn add_round(&mut self, view: &RoundView);
+    //Called once per matching game before its rounds, for statistics over whole games.
+    fn add_game(&mut self, _game: &Game) {}
+    fn merge(&mut self, other: Self);
+}
+
+//Several statistics computed in the same pass.
+macro_rules! impl_accumulator_tuple {
+    ($($name:ident $idx:tt),+) => {
+        impl<$($name: Accumulator),+> Accumulator for ($($name,)+) {
+            fn add_round(&mut self, view: &RoundView) {
+                $(self.$idx.add_round(view);)+
+            }
+            fn add_game(&mut self, game: &Game) {
+                $(self.$idx.add_game(game);)+
+            }
+            fn merge(&mut self, other: Self) {
+                $(self.$idx.merge(other.$idx);)+
+            }
+        }
+    };
+}
+impl_accumulator_tuple!(A 0, B 1);
+impl_accumulator_tuple!(A 0, B 1, C 2);
+impl_accumulator_tuple!(A 0, B 1, C 2, D 3);
+
+pub fn map_reduce<A: Accumulator>(query: &Query, new_accumulator: impl Fn() -> A + Sync) -> A {
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+    map_reduce_with_threads(query, new_accumulator, threads)
+}
+
+pub fn map_reduce_with_threads<A: Accumulator>(query: &Query, new_accumulator: impl Fn() -> A + Sync, threads: usize) -> A {
+    let games: Vec<&Game> = query.games_unchecked().collect();
+    let chunk_size = games.len().div_ceil(threads.max(1)).max(1);
+    let partial_results: Vec<A> = thread::scope(|scope| {
+        let workers: Vec<_> = games.chunks(chunk_size).map(|chunk| {
+            let new_accumulator = &new_accumulator;
+            scope.spawn(move || {
+                let mut accumulator = new_accumulator();
+                for game in chunk {
+                    let mut rounds = query.game_rounds(game).peekable();
+                    if rounds.peek().is_none() {
+                        continue;
+                    }
+                    accumulator.add_game(game);
+                    for view in rounds {
+                        accumulator.add_round(&view);
+                    }
+                }
+                accumulator
+            })
+        }).collect();
+        workers.into_iter().map(|worker| worker.join().unwrap()).collect()
+    });
+    let mut result = new_accumulator();
+    for partial_result in partial_results {
+        result.merge(partial_result);
+    }
+    result
+}
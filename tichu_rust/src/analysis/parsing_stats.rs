@@ -1,13 +1,61 @@
+use std::collections::{HashMap, HashSet};
+use crate::analysis::report::{AnalysisReport, ReportValue};
 use crate::bsw_database::DataBase;
 use crate::bsw_binary_format::game;
+use crate::bsw_binary_format::parse_diagnostic::DiagnosticKind;
 use crate::bsw_binary_format::round;
 
-pub fn evaluate_parsing_stats(db: &DataBase) {
+pub fn evaluate_parsing_stats(db: &DataBase) -> AnalysisReport {
+    if db.diagnostics.is_empty() {
+        return evaluate_parsing_flags(db);
+    }
+    let mut report = AnalysisReport::new("parsing_stats");
+    //Corrections of rounds that were excluded later on are not counted, like with the parsing flags of the kept rounds.
+    let excluded: HashSet<(Option<u32>, Option<usize>)> = db.diagnostics.iter().filter(|diagnostic| diagnostic.excludes_round())
+        .map(|diagnostic| (diagnostic.game_id, diagnostic.round)).collect();
+    let mut dragon_changes: usize = 0;
+    let mut round_result_changes: usize = 0;
+    let mut round_result_changes_without_dragon: usize = 0;
+    let mut excluded_rounds: HashMap<&'static str, usize> = HashMap::new();
+    let mut affected_games = HashSet::new();
+    for diagnostic in db.diagnostics.iter() {
+        let kept = !excluded.contains(&(diagnostic.game_id, diagnostic.round));
+        match diagnostic.kind {
+            DiagnosticKind::DragonGiftChanged { .. } if kept => dragon_changes += 1,
+            DiagnosticKind::RoundScoreChanged { dragon_changed, .. } if kept => {
+                round_result_changes += 1;
+                round_result_changes_without_dragon += !dragon_changed as usize;
+                affected_games.insert(diagnostic.game_id);
+            }
+            _ => {}
+        }
+        if diagnostic.excludes_round() {
+            *excluded_rounds.entry(diagnostic.kind.name()).or_default() += 1;
+            affected_games.insert(diagnostic.game_id);
+        }
+    }
+    report.add("Dragon changes", ReportValue::Integer(dragon_changes as i64));
+    report.add("Round-Result changes", ReportValue::Integer(round_result_changes as i64));
+    report.add("Round-Result changes without dragon", ReportValue::Integer(round_result_changes_without_dragon as i64));
+    report.add("Round-Result changes with dragon", ReportValue::Integer((round_result_changes - round_result_changes_without_dragon) as i64));
+    report.add("Affected games", ReportValue::Integer(affected_games.len() as i64));
+    let mut excluded_rounds: Vec<(&'static str, usize)> = excluded_rounds.into_iter().collect();
+    excluded_rounds.sort_by_key(|(name, count)| (usize::MAX - count, *name));
+    for (name, count) in excluded_rounds {
+        report.add(format!("Excluded rounds due to {}", name), ReportValue::Integer(count as i64));
+    }
+    report
+}
+
+//Databases imported before diagnostics were recorded only have the parsing flags of the kept rounds and games,
+//so the excluded rounds can not be broken down by reason.
+fn evaluate_parsing_flags(db: &DataBase) -> AnalysisReport {
+    let mut report = AnalysisReport::new("parsing_stats");
     let mut dragon_changes: usize = 0;
     let mut round_result_changes: usize = 0;
     let mut round_result_changes_without_dragon: usize = 0;
     let mut round_result_changes_with_dragon: usize = 0;
-    let mut affected_games = 0;
+    let mut affected_games: usize = 0;
     for game in db.games.iter() {
         for (round, _) in game.rounds.iter() {
             dragon_changes += (round.parsing_flags & round::FLAG_CHANGED_DRAGON != 0) as usize;
@@ -18,9 +66,11 @@ pub fn evaluate_parsing_stats(db: &DataBase) {
         }
         affected_games += (game.parsing_flags & (game::FLAG_CHANGED_ROUND_SCORE | game::FLAG_EXCLUDED_ROUND) != 0) as usize;
     }
-    println!("Dragon changes: {}", dragon_changes);
-    println!("Round-Result changes: {}", round_result_changes);
-    println!("Round-Result changes without dragon: {}", round_result_changes_without_dragon);
-    println!("Round-Result changes with dragon: {}", round_result_changes_with_dragon);
-    println!("Affected games: {}", affected_games);
-}
\ No newline at end of file
+    report.add("Source", ReportValue::Texts(vec!["Parsing flags, the database has no diagnostics".to_string()]));
+    report.add("Dragon changes", ReportValue::Integer(dragon_changes as i64));
+    report.add("Round-Result changes", ReportValue::Integer(round_result_changes as i64));
+    report.add("Round-Result changes without dragon", ReportValue::Integer(round_result_changes_without_dragon as i64));
+    report.add("Round-Result changes with dragon", ReportValue::Integer(round_result_changes_with_dragon as i64));
+    report.add("Affected games", ReportValue::Integer(affected_games as i64));
+    report
+}
@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+use crate::analysis::report::{AnalysisReport, ReportValue};
+use crate::bsw_binary_format::binary_format_constants::{PlayerIDGlobal, Team};
+use crate::bsw_binary_format::game::Game;
+use crate::bsw_database::DataBase;
+
+//Elo for partnerships: a team plays with the mean rating of its two players against the mean rating of the opponents,
+//and both partners receive the full rating change of their team.
+#[derive(Debug, Clone, Copy)]
+pub struct EloConfig {
+    pub initial_rating: f64,
+    pub k_factor: f64,
+    pub provisional_k_factor: f64, //Used for the first provisional_games games of a player, so new players converge faster
+    pub provisional_games: usize,
+}
+impl Default for EloConfig {
+    fn default() -> Self {
+        EloConfig { initial_rating: 1500.0, k_factor: 16.0, provisional_k_factor: 40.0, provisional_games: 20 }
+    }
+}
+
+pub struct PlayerRatings {
+    pub ratings: Vec<f64>, //Indexed by PlayerIDGlobal, rating after the last game
+    pub games: Vec<usize>, //Rated games per player
+    pub history: Vec<Vec<(u32, f64)>>, //Per player: (original_bsw_id, rating after that game), chronological
+}
+
+pub fn expected_score(rating: f64, opponent_rating: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((opponent_rating - rating) / 400.0))
+}
+
+impl PlayerRatings {
+    //Games are rated in the order of their BSW id, which follows the order they were played in.
+    pub fn compute(db: &DataBase, config: EloConfig) -> Self {
+        let mut ratings = PlayerRatings {
+            ratings: vec![config.initial_rating; db.players.len()],
+            games: vec![0; db.players.len()],
+            history: vec![Vec::new(); db.players.len()],
+        };
+        let mut games: Vec<&Game> = db.games.iter().filter(|game| !game.rounds.is_empty()).collect();
+        games.sort_by_key(|game| game.original_bsw_id);
+        for game in games {
+            ratings.rate_game(game, &config);
+        }
+        ratings
+    }
+
+    fn rate_game(&mut self, game: &Game, config: &EloConfig) {
+        let score_team_1 = match game.get_winner() {
+            Some(Team::Team1) => 1.0,
+            Some(Team::Team2) => 0.0,
+            None => 0.5,
+        };
+        let team_rating = |team: usize| (self.ratings[game.player_ids[team] as usize] + self.ratings[game.player_ids[team + 2] as usize]) / 2.0;
+        let expected_team_1 = expected_score(team_rating(0), team_rating(1));
+        for (seat, player) in game.player_ids.iter().enumerate() {
+            let player = *player as usize;
+            let k = if self.games[player] < config.provisional_games { config.provisional_k_factor } else { config.k_factor };
+            let delta = k * (score_team_1 - expected_team_1);
+            self.ratings[player] += if seat % 2 == 0 { delta } else { -delta };
+            self.games[player] += 1;
+            self.history[player].push((game.original_bsw_id, self.ratings[player]));
+        }
+    }
+
+    //Rating of the player before the given game, the initial rating if it is the first game or the game is unknown.
+    pub fn rating_before(&self, player: PlayerIDGlobal, original_bsw_id: u32, config: &EloConfig) -> f64 {
+        let history = &self.history[player as usize];
+        let idx = history.partition_point(|(game_id, _)| *game_id < original_bsw_id);
+        if idx == 0 { config.initial_rating } else { history[idx - 1].1 }
+    }
+
+    //Players for Query::only_players.
+    pub fn players_above(&self, min_rating: f64, min_games: usize) -> HashSet<PlayerIDGlobal> {
+        (0..self.ratings.len()).filter(|player| self.ratings[*player] >= min_rating && self.games[*player] >= min_games)
+            .map(|player| player as PlayerIDGlobal).collect()
+    }
+}
+
+pub fn evaluate_player_ratings(db: &DataBase) -> AnalysisReport {
+    let mut report = AnalysisReport::new("player_ratings");
+    let ratings = PlayerRatings::compute(db, EloConfig::default());
+    let mut players: Vec<usize> = (0..db.players.len()).filter(|player| ratings.games[*player] >= 100).collect();
+    players.sort_by(|a, b| ratings.ratings[*b].total_cmp(&ratings.ratings[*a]));
+    report.add("Players with at least 100 games", ReportValue::Integer(players.len() as i64));
+    //Top 20, in parallel arrays
+    let top = &players[..players.len().min(20)];
+    report.add("Top players", ReportValue::Texts(top.iter().map(|player| db.players[*player].clone()).collect()));
+    report.add("Top player ratings", ReportValue::Floats(top.iter().map(|player| ratings.ratings[*player]).collect()));
+    report.add("Top player games", ReportValue::Integers(top.iter().map(|player| ratings.games[*player] as i64).collect()));
+    report
+}
@@ -0,0 +1,186 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use serde::Serialize;
+use crate::analysis::statistics::{format_proportion_difference, two_proportion_z_test, Mean, Proportion, Z_95};
+
+//Result of an analysis: labelled values in the order they were computed.
+//Rendered as text via Display (the format of the old println dumps), or written as JSON/CSV for the scripts in analysis_resuts.
+#[derive(Debug, Clone, Serialize)]
+pub struct AnalysisReport {
+    pub name: String,
+    pub entries: Vec<ReportEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportEntry {
+    pub label: String,
+    #[serde(flatten)]
+    pub value: ReportValue,
+}
+
+//Arrays are indexed by player or team unless the label says otherwise, matrices by [category][player].
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum ReportValue {
+    Integer(i64),
+    Float(f64),
+    Integers(Vec<i64>),
+    Floats(Vec<f64>),
+    IntegerMatrix(Vec<Vec<i64>>),
+    FloatMatrix(Vec<Vec<f64>>),
+    Proportions(Vec<Proportion>),
+    Means(Vec<Mean>),
+    ProportionDifference { a: Proportion, b: Proportion, z: f64, p_value: f64 },
+    Texts(Vec<String>),
+}
+
+//Integer types the analyses count in.
+pub trait ReportInteger: Copy {
+    fn to_i64(self) -> i64;
+}
+macro_rules! impl_report_integer {
+    ($($t:ty),*) => {
+        $(impl ReportInteger for $t {
+            fn to_i64(self) -> i64 {
+                self as i64
+            }
+        })*
+    };
+}
+impl_report_integer!(usize, u64, u32, i64, i32, i16);
+
+impl ReportValue {
+    pub fn integers<T: ReportInteger>(values: &[T]) -> Self {
+        ReportValue::Integers(values.iter().map(|x| x.to_i64()).collect())
+    }
+    pub fn integer_matrix<T: ReportInteger, const N: usize>(rows: &[[T; N]]) -> Self {
+        ReportValue::IntegerMatrix(rows.iter().map(|row| row.iter().map(|x| x.to_i64()).collect()).collect())
+    }
+    pub fn float_matrix<const N: usize>(rows: &[[f64; N]]) -> Self {
+        ReportValue::FloatMatrix(rows.iter().map(|row| row.to_vec()).collect())
+    }
+    //successes[i] out of trials rounds each.
+    pub fn proportions(successes: &[usize], trials: usize) -> Self {
+        ReportValue::Proportions(successes.iter().map(|x| Proportion::new(*x, trials)).collect())
+    }
+    //successes[i] out of trials[i].
+    pub fn proportions_pairwise(successes: &[usize], trials: &[usize]) -> Self {
+        ReportValue::Proportions(successes.iter().zip(trials).map(|(x, trials)| Proportion::new(*x, *trials)).collect())
+    }
+    pub fn proportion_difference(a: Proportion, b: Proportion) -> Self {
+        let (z, p_value) = two_proportion_z_test(a, b);
+        ReportValue::ProportionDifference { a, b, z, p_value }
+    }
+}
+
+impl AnalysisReport {
+    pub fn new(name: &str) -> Self {
+        AnalysisReport { name: name.to_string(), entries: Vec::new() }
+    }
+    pub fn add(&mut self, label: impl Into<String>, value: ReportValue) {
+        self.entries.push(ReportEntry { label: label.into(), value });
+    }
+    //First entry with the given label.
+    pub fn get(&self, label: &str) -> Option<&ReportValue> {
+        self.entries.iter().find(|entry| entry.label == label).map(|entry| &entry.value)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+    //One row per number: label;row;column;value;n;low;high. Proportions and means fill n and the 95% interval.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("label;row;column;value;n;low;high\n");
+        let mut push = |label: &str, row: String, column: String, value: String, n: String, interval: Option<(f64, f64)>| {
+            let (low, high) = interval.map_or((String::new(), String::new()), |(low, high)| (low.to_string(), high.to_string()));
+            csv.push_str(&format!("{};{};{};{};{};{};{}\n", label, row, column, value, n, low, high));
+        };
+        for ReportEntry { label, value } in self.entries.iter() {
+            let mut push_proportion = |row: String, proportion: &Proportion| {
+                push(label, row, String::new(), proportion.rate().to_string(), proportion.trials.to_string(), Some(proportion.wilson_interval(Z_95)))
+            };
+            match value {
+                ReportValue::Integer(x) => push(label, String::new(), String::new(), x.to_string(), String::new(), None),
+                ReportValue::Float(x) => push(label, String::new(), String::new(), x.to_string(), String::new(), None),
+                ReportValue::Integers(values) => values.iter().enumerate()
+                    .for_each(|(row, x)| push(label, row.to_string(), String::new(), x.to_string(), String::new(), None)),
+                ReportValue::Floats(values) => values.iter().enumerate()
+                    .for_each(|(row, x)| push(label, row.to_string(), String::new(), x.to_string(), String::new(), None)),
+                ReportValue::IntegerMatrix(rows) => for (row, values) in rows.iter().enumerate() {
+                    values.iter().enumerate().for_each(|(column, x)| push(label, row.to_string(), column.to_string(), x.to_string(), String::new(), None));
+                },
+                ReportValue::FloatMatrix(rows) => for (row, values) in rows.iter().enumerate() {
+                    values.iter().enumerate().for_each(|(column, x)| push(label, row.to_string(), column.to_string(), x.to_string(), String::new(), None));
+                },
+                ReportValue::Proportions(proportions) => proportions.iter().enumerate()
+                    .for_each(|(row, proportion)| push_proportion(row.to_string(), proportion)),
+                ReportValue::Means(means) => means.iter().enumerate()
+                    .for_each(|(row, mean)| push(label, row.to_string(), String::new(), mean.mean().to_string(), mean.n.to_string(), Some(mean.confidence_interval(Z_95)))),
+                ReportValue::ProportionDifference { a, b, z, p_value } => {
+                    push_proportion("a".to_string(), a);
+                    push_proportion("b".to_string(), b);
+                    push(label, "z".to_string(), String::new(), z.to_string(), String::new(), None);
+                    push(label, "p_value".to_string(), String::new(), p_value.to_string(), String::new(), None);
+                }
+                ReportValue::Texts(texts) => texts.iter().enumerate()
+                    .for_each(|(row, text)| push(label, row.to_string(), String::new(), text.clone(), String::new(), None)),
+            }
+        }
+        csv
+    }
+    //Format by extension: .json, .csv, anything else is written as text.
+    pub fn write(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        let content = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => self.to_json(),
+            Some("csv") => self.to_csv(),
+            _ => self.to_string(),
+        };
+        std::fs::write(path, content)
+    }
+}
+
+impl Display for AnalysisReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for entry in self.entries.iter() {
+            writeln!(f, "{}: {}", entry.label, entry.value)?;
+        }
+        Ok(())
+    }
+}
+
+//Percentages with 95% Wilson intervals, means with 95% confidence half widths.
+impl Display for ReportValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportValue::Integer(x) => write!(f, "{}", x),
+            ReportValue::Float(x) => write!(f, "{}", x),
+            ReportValue::Integers(values) => write!(f, "{:?}", values),
+            ReportValue::Floats(values) => write!(f, "{:?}", values),
+            ReportValue::IntegerMatrix(rows) => write!(f, "{:?}", rows),
+            ReportValue::FloatMatrix(rows) => write!(f, "{:?}", rows),
+            ReportValue::Proportions(proportions) => {
+                let successes = proportions.iter().map(|proportion| proportion.successes).collect::<Vec<_>>();
+                let trials = proportions.iter().map(|proportion| proportion.trials).collect::<Vec<_>>();
+                let average = proportions.iter().map(|proportion| 100.0 * proportion.rate()).sum::<f64>() / proportions.len() as f64;
+                write!(f, "[{}] (Avg. {:.4}, Abs: {:?}, Div: ", join(proportions), average, successes)?;
+                if trials.windows(2).all(|pair| pair[0] == pair[1]) && !trials.is_empty() {
+                    write!(f, "{})", trials[0])
+                } else {
+                    write!(f, "{:?})", trials)
+                }
+            }
+            ReportValue::Means(means) => {
+                let mut total = Mean::default();
+                means.iter().for_each(|mean| total.merge(*mean));
+                write!(f, "[{}] (All: {}, N: {:?})", join(means), total, means.iter().map(|mean| mean.n).collect::<Vec<_>>())
+            }
+            ReportValue::ProportionDifference { a, b, .. } => write!(f, "{}", format_proportion_difference(*a, *b)),
+            ReportValue::Texts(texts) => write!(f, "{}", texts.join(", ")),
+        }
+    }
+}
+
+fn join<T: Display>(values: &[T]) -> String {
+    values.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,142 @@
+use std::fmt::{Display, Formatter};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+pub const Z_95: f64 = 1.959963984540054; //Two sided 95% quantile of the standard normal distribution
+
+//Fraction of trials that succeeded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Proportion {
+    pub successes: usize,
+    pub trials: usize,
+}
+
+impl Proportion {
+    pub fn new(successes: usize, trials: usize) -> Self {
+        Proportion { successes, trials }
+    }
+    pub fn rate(&self) -> f64 {
+        self.successes as f64 / self.trials as f64
+    }
+    //Wilson score interval, well behaved for small samples and rates close to 0 or 1. (0, 1) without trials.
+    pub fn wilson_interval(&self, z: f64) -> (f64, f64) {
+        if self.trials == 0 {
+            return (0.0, 1.0);
+        }
+        let n = self.trials as f64;
+        let p = self.rate();
+        let denominator = 1.0 + z * z / n;
+        let center = (p + z * z / (2.0 * n)) / denominator;
+        let half_width = z * (p * (1.0 - p) / n + z * z / (4.0 * n * n)).sqrt() / denominator;
+        ((center - half_width).max(0.0), (center + half_width).min(1.0))
+    }
+    pub fn merge(&mut self, other: Proportion) {
+        self.successes += other.successes;
+        self.trials += other.trials;
+    }
+}
+
+impl Display for Proportion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (low, high) = self.wilson_interval(Z_95);
+        write!(f, "{:.4} [{:.4}, {:.4}]", 100.0 * self.rate(), 100.0 * low, 100.0 * high)
+    }
+}
+
+//Serialized with the derived rate and 95% interval, so scripts do not have to recompute them.
+impl Serialize for Proportion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (low, high) = self.wilson_interval(Z_95);
+        let mut state = serializer.serialize_struct("Proportion", 5)?;
+        state.serialize_field("successes", &self.successes)?;
+        state.serialize_field("trials", &self.trials)?;
+        state.serialize_field("rate", &self.rate())?;
+        state.serialize_field("low", &low)?;
+        state.serialize_field("high", &high)?;
+        state.end()
+    }
+}
+
+//Sample mean with its standard error, accumulated value by value.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Mean {
+    pub n: usize,
+    pub sum: f64,
+    pub sum_squares: f64,
+}
+
+impl Mean {
+    pub fn add(&mut self, x: f64) {
+        self.n += 1;
+        self.sum += x;
+        self.sum_squares += x * x;
+    }
+    pub fn merge(&mut self, other: Mean) {
+        self.n += other.n;
+        self.sum += other.sum;
+        self.sum_squares += other.sum_squares;
+    }
+    pub fn mean(&self) -> f64 {
+        self.sum / self.n as f64
+    }
+    //Sample variance with Bessel's correction, NaN for less than two values.
+    pub fn variance(&self) -> f64 {
+        if self.n < 2 {
+            return f64::NAN;
+        }
+        let n = self.n as f64;
+        ((self.sum_squares - self.sum * self.sum / n) / (n - 1.0)).max(0.0)
+    }
+    pub fn standard_error(&self) -> f64 {
+        (self.variance() / self.n as f64).sqrt()
+    }
+    pub fn confidence_interval(&self, z: f64) -> (f64, f64) {
+        let half_width = z * self.standard_error();
+        (self.mean() - half_width, self.mean() + half_width)
+    }
+}
+
+impl Display for Mean {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.4} ± {:.4}", self.mean(), Z_95 * self.standard_error())
+    }
+}
+
+impl Serialize for Mean {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (low, high) = self.confidence_interval(Z_95);
+        let mut state = serializer.serialize_struct("Mean", 5)?;
+        state.serialize_field("n", &self.n)?;
+        state.serialize_field("mean", &self.mean())?;
+        state.serialize_field("standard_error", &self.standard_error())?;
+        state.serialize_field("low", &low)?;
+        state.serialize_field("high", &high)?;
+        state.end()
+    }
+}
+
+//Abramowitz and Stegun 7.1.26, absolute error below 1.5e-7.
+fn erf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.3275911 * x.abs());
+    let polynomial = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    (1.0 - polynomial * (-x * x).exp()).copysign(x)
+}
+pub fn normal_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2))
+}
+
+//Two sided z-test with pooled variance for the difference of two proportions. Returns (z, p-value), p-value is NaN if it can not be computed.
+pub fn two_proportion_z_test(a: Proportion, b: Proportion) -> (f64, f64) {
+    let pooled = (a.successes + b.successes) as f64 / (a.trials + b.trials) as f64;
+    let standard_error = (pooled * (1.0 - pooled) * (1.0 / a.trials as f64 + 1.0 / b.trials as f64)).sqrt();
+    if standard_error.is_nan() || standard_error == 0.0 {
+        return (0.0, f64::NAN);
+    }
+    let z = (a.rate() - b.rate()) / standard_error;
+    (z, 2.0 * (1.0 - normal_cdf(z.abs())))
+}
+
+pub fn format_proportion_difference(a: Proportion, b: Proportion) -> String {
+    let (z, p_value) = two_proportion_z_test(a, b);
+    format!("{} vs {} (diff {:.4}, z {:.2}, p {:.4})", a, b, 100.0 * (a.rate() - b.rate()), z, p_value)
+}
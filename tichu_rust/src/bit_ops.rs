@@ -0,0 +1,106 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+//Parallel bit deposit and extract. The BMI2 instructions are used if the CPU has them and they are fast,
+//otherwise a software loop over the set bits of the mask. Works on any target, e.g. aarch64.
+const UNKNOWN: u8 = 0;
+const HARDWARE: u8 = 1;
+const SOFTWARE: u8 = 2;
+static IMPLEMENTATION: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+//Deposits the lowest bits of src at the set bits of mask, like _pdep_u64.
+#[inline]
+pub fn pdep(src: u64, mask: u64) -> u64 {
+    #[cfg(target_arch = "x86_64")]
+    if has_fast_bmi2() {
+        return unsafe { pdep_bmi2(src, mask) };
+    }
+    pdep_software(src, mask)
+}
+
+//Gathers the bits of src at the set bits of mask into the lowest bits, like _pext_u64.
+#[inline]
+pub fn pext(src: u64, mask: u64) -> u64 {
+    #[cfg(target_arch = "x86_64")]
+    if has_fast_bmi2() {
+        return unsafe { pext_bmi2(src, mask) };
+    }
+    pext_software(src, mask)
+}
+
+pub fn pdep_software(mut src: u64, mut mask: u64) -> u64 {
+    let mut res = 0;
+    while mask != 0 {
+        let lsb = mask & mask.wrapping_neg();
+        if src & 1 != 0 {
+            res |= lsb;
+        }
+        src >>= 1;
+        mask ^= lsb;
+    }
+    res
+}
+
+pub fn pext_software(src: u64, mut mask: u64) -> u64 {
+    let mut res = 0;
+    let mut bit = 1;
+    while mask != 0 {
+        let lsb = mask & mask.wrapping_neg();
+        if src & lsb != 0 {
+            res |= bit;
+        }
+        bit <<= 1;
+        mask ^= lsb;
+    }
+    res
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2")]
+unsafe fn pdep_bmi2(src: u64, mask: u64) -> u64 {
+    std::arch::x86_64::_pdep_u64(src, mask)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2")]
+unsafe fn pext_bmi2(src: u64, mask: u64) -> u64 {
+    std::arch::x86_64::_pext_u64(src, mask)
+}
+
+//Detected once, later calls only load the cached result.
+pub fn has_fast_bmi2() -> bool {
+    match IMPLEMENTATION.load(Ordering::Relaxed) {
+        HARDWARE => true,
+        SOFTWARE => false,
+        _ => {
+            let fast = detect_fast_bmi2();
+            IMPLEMENTATION.store(if fast { HARDWARE } else { SOFTWARE }, Ordering::Relaxed);
+            fast
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn detect_fast_bmi2() -> bool {
+    is_x86_feature_detected!("bmi2") && !is_microcoded_bmi2()
+}
+#[cfg(not(target_arch = "x86_64"))]
+fn detect_fast_bmi2() -> bool {
+    false
+}
+
+//AMD before Zen 3 (family 0x19) and Hygon implement pdep/pext in microcode, taking hundreds of cycles for dense masks.
+#[cfg(target_arch = "x86_64")]
+fn is_microcoded_bmi2() -> bool {
+    use std::arch::x86_64::__cpuid;
+    let vendor = __cpuid(0);
+    let vendor_bytes = [vendor.ebx.to_le_bytes(), vendor.edx.to_le_bytes(), vendor.ecx.to_le_bytes()].concat();
+    match &vendor_bytes[..] {
+        b"HygonGenuine" => true,
+        b"AuthenticAMD" => {
+            let signature = __cpuid(1).eax;
+            let family = ((signature >> 8) & 0xF) + ((signature >> 20) & 0xFF);
+            family < 0x19
+        }
+        _ => false,
+    }
+}
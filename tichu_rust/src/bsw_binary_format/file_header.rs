@@ -0,0 +1,69 @@
+use std::io::{Error, ErrorKind};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+//Header in front of the bitcode encoded DataBase. Files written before the header was introduced start directly with the encoded LegacyDataBase.
+//Layout (little endian): magic (8 bytes) | version u32 | created_at u64 | payload length u64 | payload checksum u64
+pub const MAGIC: [u8; 8] = *b"TICHUDB\0";
+pub const HEADER_SIZE: usize = 36;
+
+pub type SchemaVersion = u32;
+pub const SCHEMA_VERSION_DIAGNOSTICS: SchemaVersion = 1; //DataBase with diagnostics
+pub const SCHEMA_VERSION_CURRENT: SchemaVersion = SCHEMA_VERSION_DIAGNOSTICS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileHeader {
+    pub version: SchemaVersion,
+    pub created_at: u64, //Seconds since the unix epoch
+    pub payload_len: u64,
+    pub checksum: u64,
+}
+
+//FNV-1a, enough to detect truncated or corrupted files.
+pub fn checksum(data: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in data {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+impl FileHeader {
+    pub fn new(payload: &[u8]) -> Self {
+        let created_at = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs());
+        FileHeader { version: SCHEMA_VERSION_CURRENT, created_at, payload_len: payload.len() as u64, checksum: checksum(payload) }
+    }
+    pub fn to_bytes(&self) -> [u8; HEADER_SIZE] {
+        let mut bytes = [0u8; HEADER_SIZE];
+        bytes[0..8].copy_from_slice(&MAGIC);
+        bytes[8..12].copy_from_slice(&self.version.to_le_bytes());
+        bytes[12..20].copy_from_slice(&self.created_at.to_le_bytes());
+        bytes[20..28].copy_from_slice(&self.payload_len.to_le_bytes());
+        bytes[28..36].copy_from_slice(&self.checksum.to_le_bytes());
+        bytes
+    }
+    //None if the data does not start with the magic bytes, i.e. it is a legacy file.
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() < HEADER_SIZE || data[0..8] != MAGIC {
+            return None;
+        }
+        let u64_at = |idx: usize| u64::from_le_bytes(data[idx..idx + 8].try_into().unwrap());
+        Some(FileHeader {
+            version: u32::from_le_bytes(data[8..12].try_into().unwrap()),
+            created_at: u64_at(12),
+            payload_len: u64_at(20),
+            checksum: u64_at(28),
+        })
+    }
+    //Returns the payload following the header after checking its length and checksum.
+    pub fn verified_payload<'a>(&self, data: &'a [u8]) -> std::io::Result<&'a [u8]> {
+        let payload = &data[HEADER_SIZE..];
+        if payload.len() as u64 != self.payload_len {
+            return Err(Error::new(ErrorKind::InvalidData, format!("Payload has {} bytes, header expects {}", payload.len(), self.payload_len)));
+        }
+        if checksum(payload) != self.checksum {
+            return Err(Error::new(ErrorKind::InvalidData, "Payload checksum does not match header"));
+        }
+        Ok(payload)
+    }
+}
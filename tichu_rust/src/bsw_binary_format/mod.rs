@@ -3,4 +3,6 @@ pub mod player_round_hand;
 pub mod round;
 pub mod round_log;
 pub mod trick;
-pub mod game;
\ No newline at end of file
+pub mod game;
+pub mod file_header;
+pub mod parse_diagnostic;
\ No newline at end of file
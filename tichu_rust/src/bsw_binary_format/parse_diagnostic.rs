@@ -0,0 +1,65 @@
+use bitcode::{Decode, Encode};
+use crate::bsw_binary_format::binary_format_constants::*;
+use crate::bsw_binary_format::player_round_hand::PlayerRoundHandIntegrityError;
+use crate::bsw_binary_format::round::RoundIntegrityError;
+use crate::bsw_binary_format::round_log::RoundLogIntegrityError;
+use crate::bsw_database::BswParseError;
+use crate::tichu_hand::Hand;
+
+//Problem found while importing the BSW dataset, saved with the database.
+//Rounds are indices into the original BSW game, before excluded rounds were removed.
+#[derive(Encode, Decode, Debug, Clone)]
+pub struct ParseDiagnostic {
+    pub game_id: Option<u32>, //Original BSW game id, None if the problem could not be attributed to a game
+    pub round: Option<usize>, //None if the problem concerns the whole game
+    pub kind: DiagnosticKind,
+}
+
+#[derive(Encode, Decode, Debug, Clone)]
+pub enum DiagnosticKind {
+    MalformedLine(BswParseError), //Only recorded when parsing leniently
+    PlayerRoundHandIntegrity(PlayerRoundHandIntegrityError),
+    Final14Mismatch { player: PlayerIDInternal, parsed: Hand, calculated: Hand },
+    RoundLogIntegrity(RoundLogIntegrityError),
+    TeamCalledGrandTichuTwice,
+    RoundNotFinishedPlaying,
+    RankMismatch { parsed: [Rank; 4], calculated: [Rank; 4] },
+    CardPointsNot100([Score; 4]),
+    DragonGiftNotFixable,
+    DragonGiftChanged { original: PlayerIDInternal, corrected: PlayerIDInternal },
+    RoundScoreChanged { parsed: (Score, Score), calculated: (Score, Score), double_win: bool, dragon_changed: bool },
+    RoundIntegrity(RoundIntegrityError),
+    GameDeleted, //All rounds of the game were excluded
+}
+
+impl DiagnosticKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DiagnosticKind::MalformedLine(_) => "MalformedLine",
+            DiagnosticKind::PlayerRoundHandIntegrity(_) => "PlayerRoundHandIntegrity",
+            DiagnosticKind::Final14Mismatch { .. } => "Final14Mismatch",
+            DiagnosticKind::RoundLogIntegrity(_) => "RoundLogIntegrity",
+            DiagnosticKind::TeamCalledGrandTichuTwice => "TeamCalledGrandTichuTwice",
+            DiagnosticKind::RoundNotFinishedPlaying => "RoundNotFinishedPlaying",
+            DiagnosticKind::RankMismatch { .. } => "RankMismatch",
+            DiagnosticKind::CardPointsNot100(_) => "CardPointsNot100",
+            DiagnosticKind::DragonGiftNotFixable => "DragonGiftNotFixable",
+            DiagnosticKind::DragonGiftChanged { .. } => "DragonGiftChanged",
+            DiagnosticKind::RoundScoreChanged { .. } => "RoundScoreChanged",
+            DiagnosticKind::RoundIntegrity(_) => "RoundIntegrity",
+            DiagnosticKind::GameDeleted => "GameDeleted",
+        }
+    }
+}
+
+impl ParseDiagnostic {
+    //Whether the round was removed from the database because of this problem. Corrections keep the round.
+    pub fn excludes_round(&self) -> bool {
+        self.round.is_some() && !matches!(self.kind, DiagnosticKind::DragonGiftChanged { .. } | DiagnosticKind::RoundScoreChanged { .. } | DiagnosticKind::GameDeleted)
+    }
+    pub fn to_csv_line(&self) -> String {
+        let optional = |x: Option<String>| x.unwrap_or_default();
+        format!("{};{};{};{};{:?}", optional(self.game_id.map(|x| x.to_string())), optional(self.round.map(|x| x.to_string())),
+                self.excludes_round() as u8, self.kind.name(), self.kind)
+    }
+}
@@ -12,7 +12,7 @@ pub struct PlayerRoundHand {
     pub extras: u64,
 }
 
-#[derive(Debug)]
+#[derive(Encode, Decode, Debug, Clone)]
 pub enum PlayerRoundHandIntegrityError {
     First8Count(u32),
     First8Invalid,
@@ -12,12 +12,12 @@ pub const FLAG_CHANGED_ROUND_SCORE: ParsingFlagRound = crate::bsw_binary_format:
 
 pub const FLAG_CHANGED_ROUND_SCORE_WITHOUT_DRAGON: ParsingFlagRound = crate::bsw_binary_format::game::FLAG_CHANGED_ROUND_SCORE_WITHOUT_DRAGON;
 
-#[derive(Encode, Decode, Default, DataSize)]
+#[derive(Encode, Decode, Default, DataSize, Clone)]
 pub struct Round {
     pub player_rounds: [PlayerRoundHand; 4],
     pub parsing_flags: ParsingFlagRound,
 }
-#[derive(Debug)]
+#[derive(Encode, Decode, Debug, Clone)]
 pub enum RoundIntegrityError {
     Child(PlayerRoundHandIntegrityError),
     NotAllCardsDistributed(u32),
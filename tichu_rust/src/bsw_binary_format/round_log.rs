@@ -4,13 +4,14 @@ use datasize::DataSize;
 use crate::tichu_hand::*;
 use crate::bsw_binary_format::binary_format_constants::*;
 use crate::bsw_binary_format::{round::Round};
-use crate::bsw_binary_format::round_log::RoundLogIntegrityError::{Child, StartTrickIsNotNextInLine};
+use crate::bsw_binary_format::round_log::RoundLogIntegrityError::{Child, StartTrickIsNotNextInLine, WishNotFulfilled};
 use crate::bsw_binary_format::round_log::TrickIntegrityError::EmptyTrickLog;
 use crate::bsw_binary_format::trick::{Trick, TrickIntegrityError};
 use crate::hand;
+use crate::move_generation::{fulfills_wish, generate_legal_moves};
 
 
-#[derive(Encode, Decode, Default, DataSize)]
+#[derive(Encode, Decode, Default, DataSize, Clone)]
 pub struct RoundLog {
     pub mahjong_wish: Option<CardIndex>,
     pub dragon_player_gift: Option<PlayerIDInternal>,
@@ -91,10 +92,90 @@ impl<'a> RoundLogIterator<'a> {
 
     }
 }
-#[derive(Debug)]
+//State of the round right before a move of the log is played.
+#[derive(Clone, Debug)]
+pub struct RoundState {
+    pub hands: [Hand; 4], //Remaining hands of all players
+    pub played_cards: Hand,
+    pub trick_num: usize,
+    pub trick: Trick, //Moves of the current trick played so far
+    pub top: Option<HandType>, //Hand to beat, None if the move starts the trick
+    pub leader: PlayerIDInternal, //Player that started the current trick
+    pub ranks: [Rank; 4], //Same layout as RoundLog::play_round, players still playing are RANK_4
+    pub next_rank: Rank,
+    pub points: [Score; 4], //Card points of all finished tricks, dragon tricks are counted for the gifted player
+    pub wish: Option<CardType>, //Active unfulfilled mahjong wish
+}
+
+pub struct RoundStateIterator<'a> {
+    round_log: &'a RoundLog,
+    tricks: RoundLogIterator<'a>,
+    current_trick: Option<Trick>,
+    move_idx: usize,
+    state: RoundState,
+}
+impl<'a> Iterator for RoundStateIterator<'a> {
+    type Item = (RoundState, (PlayerIDInternal, Hand)); //State before the move, move
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(trick) = &self.current_trick && self.move_idx < trick.trick_log.len() {
+                let (player, hand) = trick.trick_log[self.move_idx];
+                let res = self.state.clone();
+                let state = &mut self.state;
+                state.hands[player as usize] ^= hand;
+                state.played_cards |= hand;
+                state.trick.trick_log.push((player, hand));
+                state.top = hand.hand_type().map(|hand_type| hand_type.on_top_of(state.top.as_ref()));
+                if state.wish.is_some_and(|wish| fulfills_wish(hand, wish)) {
+                    state.wish = None;
+                }
+                if hand & hand!(MAHJONG) != 0u64 {
+                    state.wish = self.round_log.mahjong_wish.filter(|card| *card >= TWO);
+                }
+                if state.hands[player as usize] == 0u64 && state.ranks[player as usize] == RANK_4 && state.next_rank < RANK_4 {
+                    state.ranks[player as usize] = state.next_rank;
+                    state.next_rank += 1;
+                }
+                self.move_idx += 1;
+                return Some((res, (player, hand)));
+            }
+            if let Some(trick) = self.current_trick.take() {
+                let card_points = trick.played_cards().get_card_points();
+                if trick.has_to_gift_trick() {
+                    self.state.points[self.round_log.dragon_player_gift.unwrap() as usize] += card_points;
+                } else {
+                    self.state.points[trick.get_trick_winner() as usize] += card_points;
+                }
+                self.state.trick_num += 1;
+            }
+            let trick = self.tricks.next_trick()?;
+            self.state.trick = Trick { trick_type: trick.trick_type, trick_log: Vec::with_capacity(trick.trick_log.len()) };
+            self.state.top = None;
+            self.state.leader = trick.get_starting_player();
+            self.move_idx = 0;
+            self.current_trick = Some(trick);
+        }
+    }
+}
+
+//Action of a seat in the reconstructed turn order of a trick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnAction {
+    Play(Hand),
+    Pass,
+    Skipped, //The player is already out
+    Interrupt(Hand), //Bomb played out of turn, by any other player than the next active one after the previous move
+    GiftDragon(PlayerIDInternal), //The trick winner gifts the dragon trick to the player
+}
+
+#[derive(Encode, Decode, Debug, Clone)]
 pub enum RoundLogIntegrityError {
     StartTrickIsNotNextInLine { trick_num: usize, starting_player: PlayerIDInternal, should_start: PlayerIDInternal },
     Child(usize, TrickIntegrityError),
+    //Player could have fulfilled the active wish but passed or played without the wished card. move_idx is the move in the trick before which the player passed,
+    //or the length of the trick log if the player passed at the end of the trick.
+    WishNotFulfilled { trick_num: usize, move_idx: usize, player: PlayerIDInternal, wish: CardType },
 }
 
 #[derive(Debug)]
@@ -104,18 +185,89 @@ impl RoundLog {
     pub fn iter(&self) -> RoundLogIterator {
         RoundLogIterator { round_log: self, current_index: 0 }
     }
+    pub fn states<'a>(&'a self, round: &Round) -> RoundStateIterator<'a> {
+        let state = RoundState {
+            hands: round.get_starting_hands(),
+            played_cards: 0u64,
+            trick_num: 0,
+            trick: Trick::default(),
+            top: None,
+            leader: PLAYER_0,
+            ranks: [RANK_4; 4],
+            next_rank: RANK_1,
+            points: [0; 4],
+            wish: None,
+        };
+        RoundStateIterator { round_log: self, tricks: self.iter(), current_trick: None, move_idx: 0, state }
+    }
+    //Reconstructs the full turn order of every trick, including the passes that are implicit in the log.
+    //Seats between two consecutive moves passed (or are skipped if out), and after the last move every other seat passed unless the round ended.
+    //The log has no passes, so a bomb is taken at its logged player: unless that player is the next active seat, the bomb is an interrupt
+    //and the seats in between did not get their turn.
+    pub fn turns(&self, round: &Round) -> Vec<Vec<(PlayerIDInternal, TurnAction)>> {
+        let mut player_hands = round.get_starting_hands();
+        let mut finished: Vec<PlayerIDInternal> = Vec::new();
+        let pass_or_skip = |player_hands: &[Hand; 4], seat: PlayerIDInternal| (seat, if player_hands[seat as usize] == 0u64 { TurnAction::Skipped } else { TurnAction::Pass });
+        let mut res = Vec::new();
+        let mut iter = self.iter();
+        while let Some(trick) = iter.next_trick() {
+            let mut turns = Vec::new();
+            let mut prev_player: Option<PlayerIDInternal> = None;
+            for (player, hand) in trick.trick_log.iter() {
+                let is_bomb = hand.hand_type().is_some_and(|hand_type| hand_type.get_trick_type() >= TRICK_BOMB4);
+                let next_active = prev_player.map(|prev| {
+                    let mut seat = (prev + 1) % 4;
+                    while player_hands[seat as usize] == 0u64 && seat != prev {
+                        seat = (seat + 1) % 4;
+                    }
+                    seat
+                });
+                if is_bomb && next_active.is_some_and(|seat| seat != *player) {
+                    turns.push((*player, TurnAction::Interrupt(*hand)));
+                } else {
+                    if let Some(prev) = prev_player {
+                        let mut seat = (prev + 1) % 4;
+                        while seat != *player {
+                            turns.push(pass_or_skip(&player_hands, seat));
+                            seat = (seat + 1) % 4;
+                        }
+                    }
+                    turns.push((*player, TurnAction::Play(*hand)));
+                }
+                player_hands[*player as usize] ^= hand;
+                if player_hands[*player as usize] == 0u64 && !finished.contains(player) {
+                    finished.push(*player);
+                }
+                prev_player = Some(*player);
+            }
+            let round_over = finished.len() >= 3 || finished.len() == 2 && finished[1] == TEAMMATE_PLAYERS[finished[0] as usize];
+            if let (Some(last_player), false, false) = (prev_player, round_over, trick.trick_type == TRICK_DOG) {
+                for seat in (1..4).map(|offset| (last_player + offset) % 4) {
+                    turns.push(pass_or_skip(&player_hands, seat));
+                }
+            }
+            if let (true, Some(gift_player)) = (trick.has_to_gift_trick(), self.dragon_player_gift) {
+                turns.push((trick.get_trick_winner(), TurnAction::GiftDragon(gift_player)));
+            }
+            res.push(turns);
+        }
+        res
+    }
     pub fn integrity_check(&self, round: &Round) -> Result<(), RoundLogIntegrityError> {
         let mut player_hands = round.get_starting_hands();
         let mut prev_trick_winner: Option<PlayerIDInternal> = None;
         let mut iter = self.iter();
         let mut trick_num = 0;
+        let mut wish = None;
         while let Some(trick) = iter.next_trick(){
             if let Some(prev) = prev_trick_winner {
                 if trick.get_starting_player() != prev {
                     return Err(StartTrickIsNotNextInLine { trick_num, starting_player: trick.get_starting_player(), should_start: prev });
                 }
             }
+            let hands_before_trick = player_hands;
             trick.integrity_check(&mut player_hands).map_err(|x| Child(trick_num, x))?;
+            self.wish_integrity_check(&trick, trick_num, hands_before_trick, &mut wish)?;
             let mut trick_winner = trick.get_trick_winner();
             while player_hands[trick_winner as usize] == 0u64 {
                 trick_winner = (trick_winner + 1) % 4;
@@ -125,6 +277,57 @@ impl RoundLog {
         }
         Ok(())
     }
+    //Replays a trick that passed Trick::integrity_check and checks that every player holding the wished card played it when legally possible.
+    //Passes are implicit in the log: players between two consecutive moves passed, and every other active player passed at the end of the trick.
+    //Bombs may be played out of turn, so players skipped by a bomb are not checked and a bomb never violates the wish.
+    //Nobody has to bomb for the wish: a player who could only fulfill it with a bomb may pass or play something else.
+    fn wish_integrity_check(&self, trick: &Trick, trick_num: usize, mut player_hands: [Hand; 4], wish: &mut Option<CardType>) -> Result<(), RoundLogIntegrityError> {
+        let mut moves = Vec::new();
+        let mut could_fulfill = |hand: Hand, top: Option<&HandType>, wish: CardType| {
+            moves.clear();
+            generate_legal_moves(hand, top, Some(wish), &mut moves);
+            moves.iter().any(|(cards, hand_type)| fulfills_wish(*cards, wish) && hand_type.get_trick_type() < TRICK_BOMB4)
+        };
+        let mut top: Option<HandType> = None;
+        let mut prev_player: Option<PlayerIDInternal> = None;
+        for (move_idx, (player, hand)) in trick.trick_log.iter().enumerate() {
+            let hand_type = hand.hand_type().unwrap();
+            let is_bomb = hand_type.get_trick_type() >= TRICK_BOMB4;
+            if let (Some(current), false) = (*wish, is_bomb) {
+                //Players between the previous and this move passed.
+                if let Some(prev) = prev_player {
+                    let mut passing = (prev + 1) % 4;
+                    while passing != *player {
+                        if player_hands[passing as usize] != 0u64 && could_fulfill(player_hands[passing as usize], top.as_ref(), current) {
+                            return Err(WishNotFulfilled { trick_num, move_idx, player: passing, wish: current });
+                        }
+                        passing = (passing + 1) % 4;
+                    }
+                }
+                if !fulfills_wish(*hand, current) && could_fulfill(player_hands[*player as usize], top.as_ref(), current) {
+                    return Err(WishNotFulfilled { trick_num, move_idx, player: *player, wish: current });
+                }
+            }
+            top = Some(hand_type.on_top_of(top.as_ref()));
+            if wish.is_some_and(|current| fulfills_wish(*hand, current)) {
+                *wish = None;
+            }
+            if hand & hand!(MAHJONG) != 0u64 {
+                *wish = self.mahjong_wish.filter(|card| *card >= TWO);
+            }
+            player_hands[*player as usize] ^= hand;
+            prev_player = Some(*player);
+        }
+        //Every other active player passed on the last move of the trick.
+        if let (Some(current), Some(last_player), false) = (*wish, prev_player, trick.trick_type == TRICK_DOG) {
+            for passing in (1..4).map(|offset| (last_player + offset) % 4) {
+                if player_hands[passing as usize] != 0u64 && could_fulfill(player_hands[passing as usize], top.as_ref(), current) {
+                    return Err(WishNotFulfilled { trick_num, move_idx: trick.trick_log.len(), player: passing, wish: current });
+                }
+            }
+        }
+        Ok(())
+    }
     pub fn try_fix_dragon_gifting(&mut self, round: &Round) -> Option<bool> {
         //The BSW dataset has a bug where the dragon is sometimes gifted to the player that plays it
         //or is gifted to an enemy that is no longer playing.
@@ -8,7 +8,7 @@ use crate::bsw_binary_format::trick::TrickIntegrityError::{DogTrickTooLong, Empt
 use crate::street_detection_tricks::phoenix_used_as_street_extension;
 
 
-#[derive(Debug)]
+#[derive(Encode, Decode, Debug, Clone)]
 pub enum TrickIntegrityError {
     EmptyTrickLog,
     DogTrickTooLong,
@@ -21,7 +21,7 @@ pub enum TrickIntegrityError {
     HandTooSmall { hand: String, hand_type: HandType, prev_hand: String, prev_hand_type: HandType, move_idx: usize },
 }
 
-#[derive(Encode, Decode, Default, DataSize)]
+#[derive(Encode, Decode, Default, DataSize, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct Trick {
     pub trick_type: TrickType,
     pub trick_log: Vec<(PlayerIDInternal, Hand)>,
@@ -97,7 +97,7 @@ impl Trick {
                 }
             }
             trick_type = new_trick_type;
-            prev_hand = Some(hand_type);
+            prev_hand = Some(hand_type.on_top_of(prev_hand.as_ref()));
             prev_player = Some(player);
         }
         Ok(())
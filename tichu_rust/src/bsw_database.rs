@@ -2,23 +2,26 @@ use std::cmp::PartialEq;
 use crate::bsw_binary_format::binary_format_constants::{PlayerIDGlobal, Score, PLAYER_0, PLAYER_1, PLAYER_2, PLAYER_3, CALL_TICHU, CALL_GRAND_TICHU, CALL_NONE, PlayerIDInternal, Rank, Team};
 use crate::bsw_binary_format::game::{Game, FLAG_EXCLUDED_ROUND, FLAG_GAME_STOPPED_WITHIN_ROUND, FLAG_NO_WINNER_BSW};
 use crate::bsw_binary_format::player_round_hand::PlayerRoundHand;
-use crate::bsw_binary_format::round::Round;
+use crate::bsw_binary_format::round::{Round, RoundIntegrityError};
 use crate::bsw_binary_format::round_log::RoundLog;
+use crate::bsw_binary_format::parse_diagnostic::{DiagnosticKind, ParseDiagnostic};
+use crate::bsw_binary_format::file_header::{FileHeader, HEADER_SIZE, SCHEMA_VERSION_CURRENT, SCHEMA_VERSION_DIAGNOSTICS};
 use crate::tichu_hand::*;
 use bitcode::{Decode, Encode};
 use memmap2::MmapOptions;
 use std::collections::HashMap;
 use std::fs;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::str::FromStr;
 use crate::bsw_binary_format::{game, round};
 use crate::bsw_binary_format::trick::{Trick};
 use crate::hand;
 use datasize::{data_size, DataSize};
 
 
-pub fn trick_type_str_to_trick_type(trick_type: &str) -> TrickType {
-    match trick_type {
+pub fn trick_type_str_to_trick_type(trick_type: &str) -> Option<TrickType> {
+    Some(match trick_type {
         "1" => TRICK_SINGLETON,
         "2" => TRICK_PAIRS,
         "3" => TRICK_TRIPLETS,
@@ -50,18 +53,124 @@ pub fn trick_type_str_to_trick_type(trick_type: &str) -> TrickType {
         "B11" => TRICK_BOMB11,
         "B12" => TRICK_BOMB12,
         "B13" => TRICK_BOMB13,
-        _ => unreachable!(),
+        _ => return None,
+    })
+}
+
+pub fn card_wish_to_cardtype(card_wish: char) -> Option<CardType> {
+    ".23456789TJQKA".find(card_wish).map(|card_type| card_type as CardType)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMode {
+    Strict, //Abort on the first malformed line
+    Lenient, //Skip malformed lines, record them and exclude the affected round
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+pub enum BswParseErrorKind {
+    Io(String),
+    MissingField,
+    InvalidNumber(String),
+    InvalidCard(char),
+    DuplicateCard(char),
+    InvalidTrickType(String),
+    InvalidWish(char),
+    InvalidPlayer(String),
+    UnknownGame(u32),
+    DuplicateGame(u32),
+    GameIdMismatch { expected: u32, found: u32 },
+    RoundOutOfOrder { expected: usize, found: usize },
+    TrickLengthMismatch { expected: usize, found: usize },
+    DuplicateMahjongWish,
+    DuplicateDragonGift,
+    WishNotFulfilled, //A hand marked as fulfilling the wish does not contain the wished card
+    TrailingCharacters(String),
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+pub struct BswParseError {
+    pub file: String,
+    pub line: usize, //1-based, 0 if the error is not tied to a line
+    pub column: usize, //1-based index of the ';' separated field, 0 if the error is not tied to a field
+    pub kind: BswParseErrorKind,
+}
+impl BswParseError {
+    fn io(file: &str, line: usize, err: std::io::Error) -> Self {
+        BswParseError { file: file.to_string(), line, column: 0, kind: BswParseErrorKind::Io(err.to_string()) }
+    }
+}
+
+//Fields of one line of a BSW csv file, keeping track of the position for error reporting.
+struct LineFields<'a> {
+    parts: std::str::Split<'a, char>,
+    file: &'a str,
+    line: usize,
+    column: usize,
+}
+impl<'a> LineFields<'a> {
+    fn new(line_str: &'a str, file: &'a str, line: usize) -> Self {
+        LineFields { parts: line_str.split(';'), file, line, column: 0 }
+    }
+    fn error(&self, kind: BswParseErrorKind) -> BswParseError {
+        BswParseError { file: self.file.to_string(), line: self.line, column: self.column, kind }
+    }
+    fn skip(&mut self) {
+        self.column += 1;
+        self.parts.next();
+    }
+    fn next_str(&mut self) -> Result<&'a str, BswParseError> {
+        self.column += 1;
+        self.parts.next().ok_or_else(|| self.error(BswParseErrorKind::MissingField))
+    }
+    fn next_parse<T: FromStr>(&mut self) -> Result<T, BswParseError> {
+        let field = self.next_str()?;
+        field.parse::<T>().map_err(|_| self.error(BswParseErrorKind::InvalidNumber(field.to_string())))
+    }
+    //1-based numbers in the csv files, returned 0-based.
+    fn next_index(&mut self) -> Result<usize, BswParseError> {
+        let field = self.next_str()?;
+        field.parse::<usize>().ok().and_then(|x| x.checked_sub(1)).ok_or_else(|| self.error(BswParseErrorKind::InvalidNumber(field.to_string())))
+    }
+    fn next_player(&mut self) -> Result<PlayerIDInternal, BswParseError> {
+        let field = self.next_str()?;
+        field.parse::<PlayerIDInternal>().ok().filter(|player| *player <= PLAYER_3).ok_or_else(|| self.error(BswParseErrorKind::InvalidPlayer(field.to_string())))
+    }
+    fn card(&self, c: char) -> Result<CardIndex, BswParseError> {
+        TICHU_ONE_ENCODING.get(&c).copied().ok_or_else(|| self.error(BswParseErrorKind::InvalidCard(c)))
+    }
+    fn next_hand(&mut self) -> Result<Hand, BswParseError> {
+        let field = self.next_str()?;
+        let mut hand = 0u64;
+        for c in field.chars() {
+            hand |= hand!(self.card(c)?);
+        }
+        Ok(hand)
     }
 }
 
-pub fn card_wish_to_cardtype(card_wish: char) -> CardType {
-    ".23456789TJQKA".find(card_wish).unwrap() as CardType
+fn handle_line_error(mode: ParseMode, diagnostics: &mut Vec<ParseDiagnostic>, game_round: Option<(u32, usize)>, err: BswParseError) -> Result<(), BswParseError> {
+    match mode {
+        ParseMode::Strict => Err(err),
+        ParseMode::Lenient => {
+            diagnostics.push(ParseDiagnostic { game_id: game_round.map(|x| x.0), round: game_round.map(|x| x.1), kind: DiagnosticKind::MalformedLine(err) });
+            Ok(())
+        }
+    }
+}
+
+//Schema version 0, see file_header.
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyDataBase {
+    pub games: Vec<Game>,
+    pub players: Vec<String>,
 }
 
 #[derive(Encode, Decode)]
 pub struct DataBase {
     pub games: Vec<Game>,
     pub players: Vec<String>, //Indexed by PlayerIDGlobal
+    pub diagnostics: Vec<ParseDiagnostic>, //Problems found and corrections made while importing from BSW
 }
 
 impl DataBase {
@@ -90,55 +199,93 @@ impl DataBase {
     pub fn write(&self, path: &str) -> std::io::Result<()> {
         let encoded = bitcode::encode(self);
         let mut file = File::create(path)?;
+        file.write_all(&FileHeader::new(&encoded).to_bytes())?;
         file.write_all(&encoded)?;
         Ok(())
     }
+    //Reads the current and all older versions of the format, older versions are migrated on the fly.
     pub fn read(path: &str) -> std::io::Result<DataBase> {
         let file = File::open(path)?;
         let mmap = unsafe { MmapOptions::new().map(&file)? };
-        Ok(bitcode::decode(&mmap[..]).unwrap())
+        let invalid_data = |err: bitcode::Error| std::io::Error::new(std::io::ErrorKind::InvalidData, format!("Can not decode {}: {}", path, err));
+        let Some(header) = FileHeader::from_bytes(&mmap[..]) else {
+            //Headerless files were written before the diagnostics were added.
+            let legacy = bitcode::decode::<LegacyDataBase>(&mmap[..]).map_err(invalid_data)?;
+            return Ok(DataBase { games: legacy.games, players: legacy.players, diagnostics: Vec::new() });
+        };
+        let payload = header.verified_payload(&mmap[..])?;
+        match header.version {
+            SCHEMA_VERSION_DIAGNOSTICS => bitcode::decode::<DataBase>(payload).map_err(invalid_data),
+            version => Err(std::io::Error::new(std::io::ErrorKind::InvalidData,
+                                               format!("{} has schema version {}, newest supported is {}", path, version, SCHEMA_VERSION_CURRENT))),
+        }
+    }
+    //None for legacy files without header.
+    pub fn read_header(path: &str) -> std::io::Result<Option<FileHeader>> {
+        let mut bytes = Vec::with_capacity(HEADER_SIZE);
+        File::open(path)?.take(HEADER_SIZE as u64).read_to_end(&mut bytes)?;
+        Ok(FileHeader::from_bytes(&bytes))
+    }
+    pub fn diagnostics_of_game(&self, game_id: u32) -> impl Iterator<Item = &ParseDiagnostic> {
+        self.diagnostics.iter().filter(move |diagnostic| diagnostic.game_id == Some(game_id))
+    }
+    pub fn write_diagnostics_csv(&self, path: &str) -> std::io::Result<()> {
+        let mut file = File::create(path)?;
+        writeln!(file, "game;round;excluded;kind;details")?;
+        for diagnostic in self.diagnostics.iter() {
+            writeln!(file, "{}", diagnostic.to_csv_line())?;
+        }
+        Ok(())
+    }
+    fn add_diagnostic(diagnostics: &mut Vec<ParseDiagnostic>, game_id: u32, round: usize, kind: DiagnosticKind) {
+        diagnostics.push(ParseDiagnostic { game_id: Some(game_id), round: Some(round), kind });
     }
     fn add_skip_round(exclude_rounds: &mut HashMap<u32, Vec<usize>>, game: &mut Game, round: usize) {
-        if exclude_rounds.contains_key(&game.original_bsw_id) {
-            exclude_rounds.get_mut(&game.original_bsw_id).unwrap().push(round);
-        } else {
-            exclude_rounds.insert(game.original_bsw_id, vec![round]);
+        //Kept sorted and free of duplicates, rounds are removed back to front.
+        let game_rounds = exclude_rounds.entry(game.original_bsw_id).or_default();
+        if let Err(pos) = game_rounds.binary_search(&round) {
+            game_rounds.insert(pos, round);
         }
         game.parsing_flags |= FLAG_EXCLUDED_ROUND;
     }
     pub fn from_bsw() -> std::io::Result<DataBase> {
+        DataBase::from_bsw_dir("../tichulog_csv/", ParseMode::Strict)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{:?}", err)))
+    }
+    //Parses the BSW csv export in path. In lenient mode, malformed lines are recorded in the diagnostics instead of aborting the import.
+    pub fn from_bsw_dir(path: &str, mode: ParseMode) -> Result<DataBase, BswParseError> {
         let mut database = DataBase {
             games: Vec::new(),
             players: Vec::new(),
+            diagnostics: Vec::new(),
         };
         let mut player_str_to_id: HashMap<String, PlayerIDGlobal> = HashMap::new();
         let mut bsw_id_to_game: HashMap<u32, Game> = HashMap::new();
         let mut round_results: HashMap<u32, Vec<(Score, Score)>> = HashMap::new();
         let mut exclude_rounds: HashMap<u32, Vec<usize>> = HashMap::new();
+        let mut diagnostics: Vec<ParseDiagnostic> = Vec::new();
 
-        for path in fs::read_dir("../tichulog_csv/")? {
-            let name = path?.path().display().to_string();
-            if name.contains("Spiel_") {
-                DataBase::parse_spiel_file(
-                    &mut database,
-                    &mut player_str_to_id,
-                    &mut bsw_id_to_game,
-                    &mut round_results,
-                    &name,
-                );
-            }
+        let mut names = Vec::new();
+        for entry in fs::read_dir(path).map_err(|err| BswParseError::io(path, 0, err))? {
+            names.push(entry.map_err(|err| BswParseError::io(path, 0, err))?.path().display().to_string());
         }
-        for path in fs::read_dir("../tichulog_csv/")? {
-            let name = path?.path().display().to_string();
-            if name.contains("Runde_") {
-                DataBase::parse_runde_file(&mut bsw_id_to_game, &mut round_results, &mut exclude_rounds, &name);
-            }
+        names.sort();
+        for name in names.iter().filter(|name| name.contains("Spiel_")) {
+            DataBase::parse_spiel_file(
+                &mut database,
+                &mut player_str_to_id,
+                &mut bsw_id_to_game,
+                &mut round_results,
+                name,
+                mode,
+                &mut diagnostics,
+            )?;
         }
-        for path in fs::read_dir("../tichulog_csv/")? {
-            let name = path?.path().display().to_string();
-            if name.contains("Zugfolge_") {
-                DataBase::parse_zugfolge_file(&mut bsw_id_to_game, &mut exclude_rounds, &name);
-            }
+        for name in names.iter().filter(|name| name.contains("Runde_")) {
+            DataBase::parse_runde_file(&mut bsw_id_to_game, &mut round_results, &mut exclude_rounds, name, mode, &mut diagnostics)?;
+        }
+        for name in names.iter().filter(|name| name.contains("Zugfolge_")) {
+            DataBase::parse_zugfolge_file(&mut bsw_id_to_game, &mut exclude_rounds, name, mode, &mut diagnostics)?;
         }
         println!("Estimated heap size: {}", data_size(&bsw_id_to_game)); // 9574560 for small dataset. (name.contains Spiel_570)
         println!("Finished parsing! Starting correction!");
@@ -153,8 +300,7 @@ impl DataBase {
                 }
                 if let Some(err) = round_log.integrity_check(round).err() {
                     //Any of these errors can not be recovered from. The round is trash.
-                    println!("Skipping Game {} round {}: {:?} in round_log integrity check. Printing Trick Log:", game_idx, round_num, err);
-                    println!("{}", round_log.to_debug_str(round));
+                    DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::RoundLogIntegrity(err));
                     DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
                     continue;
                 }
@@ -174,10 +320,13 @@ impl DataBase {
                 pr1 |= ranks << 46;
                 pr2 |= ranks << 46;
                 pr3 |= ranks << 46;
-                assert_eq!(pr0 & !0xFFC0000000000000, pr0);
-                assert_eq!(pr1 & !0xFFC0000000000000, pr1);
-                assert_eq!(pr2 & !0xFFC0000000000000, pr2);
-                assert_eq!(pr3 & !0xFFC0000000000000, pr3);
+                //The card score bits are only set below. Anything already there is a field that spilled over while parsing.
+                let occupied = (pr0 | pr1 | pr2 | pr3) >> 54;
+                if occupied != 0 {
+                    DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::RoundIntegrity(RoundIntegrityError::CardScoreTooLarge(occupied as Score - 25)));
+                    DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
+                    continue;
+                }
                 round.player_rounds[0].extras = pr0;
                 round.player_rounds[1].extras = pr1;
                 round.player_rounds[2].extras = pr2;
@@ -185,13 +334,13 @@ impl DataBase {
                 //Skip the round if both players of a team call GT.
                 if round.player_rounds[0].player_0_call() == CALL_GRAND_TICHU && round.player_rounds[0].player_2_call() == CALL_GRAND_TICHU ||
                     round.player_rounds[0].player_1_call() == CALL_GRAND_TICHU && round.player_rounds[0].player_3_call() == CALL_GRAND_TICHU {
-                    println!("Skipping Game {} round {}: Two players of a team both called Grand Tichu.", game_idx, round_num);
+                    DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::TeamCalledGrandTichuTwice);
                     DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
                     continue;
                 }
                 let res = round_log.play_round(round);
                 if res.is_err() {
-                    println!("Skipping Game {} round {}: {:?} in play_round.", game_idx, round_num, res.err().unwrap());
+                    DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::RoundNotFinishedPlaying);
                     DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
                     continue;
                 }
@@ -203,50 +352,62 @@ impl DataBase {
                 round_log_ranks |= (log_ranks[PLAYER_2 as usize] as u64) << 4;
                 round_log_ranks |= (log_ranks[PLAYER_3 as usize] as u64) << 6;
                 if ranks != round_log_ranks {
-                    println!("Skipping Game {} round {}: Calculated ranks do not match parsed ranks. Printing Trick Log:", game_idx, round_num);
-                    println!("{}", round_log.to_debug_str(round));
+                    let parsed = [0, 1, 2, 3].map(|player| ((ranks >> (2 * player)) & 0b11) as Rank);
+                    DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::RankMismatch { parsed, calculated: log_ranks });
                     DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
                     continue;
                 }
 
                 //We have two different sources of round result.
                 //First is round_results vector
-                let parsed_round_result = round_results[game_idx][round_num];
+                let Some(&parsed_round_result) = round_results.get(game_idx).and_then(|results| results.get(round_num)) else {
+                    let err = BswParseError { file: path.to_string(), line: 0, column: 0, kind: BswParseErrorKind::MissingField };
+                    DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::MalformedLine(err));
+                    DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
+                    continue;
+                };
                 //Second is from the round log + calls points. They must match!
                 let mut card_score_team_1 = score[PLAYER_0 as usize] + score[PLAYER_2 as usize];
 
                 //In case of double wins, no card points must be set.
-                if round.player_rounds[0].is_double_win_team_1()
-                    || round.player_rounds[0].is_double_win_team_2()
-                {
-                    assert!(is_double_win);
+                let parsed_double_win = round.player_rounds[0].is_double_win_team_1() || round.player_rounds[0].is_double_win_team_2();
+                if parsed_double_win != is_double_win {
+                    let parsed = [0, 1, 2, 3].map(|player| ((ranks >> (2 * player)) & 0b11) as Rank);
+                    DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::RankMismatch { parsed, calculated: log_ranks });
+                    DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
+                    continue;
+                }
+                if parsed_double_win {
                     if parsed_round_result != round.player_rounds[0].round_score() {
-                        println!("Game {} round {}: Parsed round result {:?} does not match calculated round result {:?}(double win). Continuing with our calculated result. Trick Log:", game_idx, round_num, parsed_round_result, round.player_rounds[0].round_score());
-                        println!("{}", round_log.to_debug_str(round));
+                        //Continuing with our calculated result.
+                        DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::RoundScoreChanged {
+                            parsed: parsed_round_result, calculated: round.player_rounds[0].round_score(), double_win: true, dragon_changed: false,
+                        });
                         game.parsing_flags |= game::FLAG_CHANGED_ROUND_SCORE | game::FLAG_CHANGED_ROUND_SCORE_WITHOUT_DRAGON;
                         round.parsing_flags |= round::FLAG_CHANGED_ROUND_SCORE | round::FLAG_CHANGED_ROUND_SCORE_WITHOUT_DRAGON;
                     }
                 } else {
-                    assert!(!is_double_win);
                     if score.iter().sum::<Score>() != 100 {
-                        println!("Skipping Game {} round {}: Card points {:?} do not add up to 100. Printing Trick Log:", game_idx, round_num, score);
-                        println!("{}", round_log.to_debug_str(round));
+                        DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::CardPointsNot100(score));
                         DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
                         continue;
                     }
+                    let original_dragon_gift = round_log.dragon_player_gift;
                     if let Some(fixed) = round_log.try_fix_dragon_gifting(&round) {
                         if fixed {
+                            DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::DragonGiftChanged {
+                                original: original_dragon_gift.unwrap(), corrected: round_log.dragon_player_gift.unwrap(),
+                            });
                             //Recalculate card_scores
                             let res = round_log.play_round(round);
                             if res.is_err() {
-                                println!("Skipping Game {} round {}: {:?} in play_round.", game_idx, round_num, res.err().unwrap());
+                                DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::RoundNotFinishedPlaying);
                                 DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
                                 continue;
                             }
                             let new_score = res.unwrap().1;
                             if new_score.iter().sum::<Score>() != 100 {
-                                println!("Skipping Game {} round {}: (Recalculated) Card points {:?} do not add up to 100. Printing Trick Log:", game_idx, round_num, new_score);
-                                println!("{}", round_log.to_debug_str(round));
+                                DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::CardPointsNot100(new_score));
                                 DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
                                 continue;
                             }
@@ -254,13 +415,16 @@ impl DataBase {
                             round.parsing_flags |= round::FLAG_CHANGED_DRAGON;
                             card_score_team_1 = new_score[PLAYER_0 as usize] + new_score[PLAYER_2 as usize];
                         } else {
-                            println!("Skipping Game {} round {}: Dragon gifting can not be fixed. Printing Trick Log:", game_idx, round_num);
-                            println!("{}", round_log.to_debug_str(round));
+                            DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::DragonGiftNotFixable);
                             DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
                             continue;
                         }
                     }
-                    assert!(card_score_team_1 >= -25);
+                    if !(-25..=125).contains(&card_score_team_1) {
+                        DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::RoundIntegrity(RoundIntegrityError::CardScoreTooLarge(card_score_team_1)));
+                        DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
+                        continue;
+                    }
                     round.player_rounds[0].extras |= ((card_score_team_1 + 25) as u64) << 54;
                     round.player_rounds[1].extras |= ((card_score_team_1 + 25) as u64) << 54;
                     round.player_rounds[2].extras |= ((card_score_team_1 + 25) as u64) << 54;
@@ -272,15 +436,17 @@ impl DataBase {
                         if !dragon_changed {
                             game.parsing_flags |= game::FLAG_CHANGED_ROUND_SCORE_WITHOUT_DRAGON;
                             round.parsing_flags |= round::FLAG_CHANGED_ROUND_SCORE_WITHOUT_DRAGON;
-                            println!("Game {} round {}: Parsed round result {:?} does not match calculated round result {:?} (no dragon gift change). Continuing with our calculated result. Trick Log:", game_idx, round_num, parsed_round_result, round.player_rounds[0].round_score());
-                            println!("{}", round_log.to_debug_str(round));
                         }
+                        //Continuing with our calculated result.
+                        DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::RoundScoreChanged {
+                            parsed: parsed_round_result, calculated: round.player_rounds[0].round_score(), double_win: false, dragon_changed,
+                        });
                     }
                 }
 
                 if let Some(err) = round.integrity_check().err() {
                     //Any of these errors can not be recovered from. The round is trash.
-                    println!("Skipping Game {} round {}: {:?} in Round integrity check.", game_idx, round_num, err);
+                    DataBase::add_diagnostic(&mut diagnostics, *game_idx, round_num, DiagnosticKind::RoundIntegrity(err));
                     DataBase::add_skip_round(&mut exclude_rounds, game, round_num);
                     continue;
                 }
@@ -291,7 +457,7 @@ impl DataBase {
         for (game_idx, exclude_rounds) in exclude_rounds.iter() {
             let game: &mut Game = bsw_id_to_game.get_mut(game_idx).unwrap();
             if game.rounds.len() == exclude_rounds.len() {
-                println!("Deleting game {} from database! No more rounds.", *game_idx);
+                diagnostics.push(ParseDiagnostic { game_id: Some(*game_idx), round: None, kind: DiagnosticKind::GameDeleted });
                 bsw_id_to_game.remove(game_idx);
                 continue;
             }
@@ -303,6 +469,7 @@ impl DataBase {
         }
         //Collect bsw_id_to_game into database
         database.games = bsw_id_to_game.into_values().collect();
+        database.diagnostics = diagnostics;
         Ok(database)
     }
     fn parse_spiel_file(
@@ -310,228 +477,319 @@ impl DataBase {
         player_str_to_id: &mut HashMap<String, PlayerIDGlobal>,
         game_id_to_idx: &mut HashMap<u32, Game>,
         round_results: &mut HashMap<u32, Vec<(Score, Score)>>,
-        path: &str, )
+        path: &str,
+        mode: ParseMode,
+        diagnostics: &mut Vec<ParseDiagnostic>,
+    ) -> Result<(), BswParseError>
     {
-        let file = File::open(path).unwrap();
+        let file = File::open(path).map_err(|err| BswParseError::io(path, 0, err))?;
         let reader = BufReader::new(file);
-        let mut lines = reader.lines();
-
-        let parse_line = |line: &str| {
-            let mut parts = line.split(";");
-            (
-                parts.next().unwrap().parse::<u32>().unwrap(),
-                parts.next().unwrap().parse::<String>().unwrap(),
-                {
-                    parts.next(); //PlayerIdInternal, not important
-                    parts.next().unwrap().parse::<u8>().unwrap() //Win or not
-                }
-            )
+        let parse_line = |line: &str, line_num: usize| -> Result<(u32, String, u8), BswParseError> {
+            let mut fields = LineFields::new(line, path, line_num);
+            let game_id = fields.next_parse::<u32>()?;
+            let player_name = fields.next_str()?.to_string();
+            fields.skip(); //PlayerIdInternal, not important
+            let win = fields.next_parse::<u8>()?; //Win or not
+            Ok((game_id, player_name, win))
         };
-        while let (Some(line1), Some(line2), Some(line3), Some(line4)) =
-            (lines.next(), lines.next(), lines.next(), lines.next())
-        {
-            // Process the chunk of 4 lines
-            let chunk = [
-                parse_line(&line1.unwrap()),
-                parse_line(&line2.unwrap()),
-                parse_line(&line3.unwrap()),
-                parse_line(&line4.unwrap()),
-            ];
-            assert_eq!(chunk[0].0, chunk[1].0);
-            assert_eq!(chunk[1].0, chunk[2].0);
-            assert_eq!(chunk[2].0, chunk[3].0);
-            assert!(!game_id_to_idx.contains_key(&chunk[0].0));
-            let mut player_ids: [PlayerIDGlobal; 4] = [0; 4];
-            for player in 0..4 {
-                let player_id = {
-                    let player_name = &chunk[player].1;
-                    if let Some(x) = player_str_to_id.get(player_name) {
-                        *x
-                    } else {
-                        let player_id = database.players.len() as PlayerIDGlobal;
-                        player_str_to_id.insert(player_name.to_string(), player_id);
-                        database.players.push(player_name.to_string());
-                        player_id
-                    }
-                };
-                player_ids[player] = player_id;
+        //The four lines of a game follow each other. Lines are grouped by game id instead of in fixed chunks of four, so that a missing
+        //or extra line only loses its own game: the group is cut off at the next game id and the lines after it are read as usual.
+        let mut group: Vec<(usize, (u32, String, u8))> = Vec::with_capacity(4);
+        for (line_idx, line) in reader.lines().enumerate() {
+            let parsed = line.map_err(|err| BswParseError::io(path, line_idx + 1, err)).and_then(|line| parse_line(&line, line_idx + 1));
+            let parsed = match parsed {
+                Ok(parsed) => parsed,
+                Err(err) => {
+                    handle_line_error(mode, diagnostics, None, err)?;
+                    continue;
+                }
+            };
+            if let Some((_, first)) = group.first() && first.0 != parsed.0 {
+                let err = BswParseError { file: path.to_string(), line: line_idx + 1, column: 1, kind: BswParseErrorKind::GameIdMismatch { expected: first.0, found: parsed.0 } };
+                group.clear();
+                handle_line_error(mode, diagnostics, None, err)?;
+            }
+            group.push((line_idx + 1, parsed));
+            if group.len() == 4 {
+                if let Err(err) = DataBase::add_spiel_game(database, player_str_to_id, game_id_to_idx, round_results, path, &group) {
+                    handle_line_error(mode, diagnostics, None, err)?;
+                }
+                group.clear();
             }
-            let parsing_flags = if chunk.iter().all(|x| x.2 == 0) { FLAG_NO_WINNER_BSW } else { 0 };
-            game_id_to_idx.insert(chunk[0].0, Game {
-                rounds: Vec::new(),
-                player_ids,
-                original_bsw_id: chunk[0].0,
-                parsing_flags,
-            });
-            round_results.insert(chunk[0].0, Vec::new());
         }
+        if let Some((line_num, _)) = group.last() {
+            //The file ends within a game, the line after the last one is missing.
+            handle_line_error(mode, diagnostics, None, BswParseError { file: path.to_string(), line: line_num + 1, column: 1, kind: BswParseErrorKind::MissingField })?;
+        }
+        Ok(())
+    }
+    //Adds the game of four lines with the same game id, one per player.
+    fn add_spiel_game(
+        database: &mut DataBase,
+        player_str_to_id: &mut HashMap<String, PlayerIDGlobal>,
+        game_id_to_idx: &mut HashMap<u32, Game>,
+        round_results: &mut HashMap<u32, Vec<(Score, Score)>>,
+        path: &str,
+        group: &[(usize, (u32, String, u8))],
+    ) -> Result<(), BswParseError>
+    {
+        let (first_line, (game_id, _, _)) = &group[0];
+        if game_id_to_idx.contains_key(game_id) {
+            return Err(BswParseError { file: path.to_string(), line: *first_line, column: 1, kind: BswParseErrorKind::DuplicateGame(*game_id) });
+        }
+        let mut player_ids: [PlayerIDGlobal; 4] = [0; 4];
+        for (player, (_, (_, player_name, _))) in group.iter().enumerate() {
+            player_ids[player] = if let Some(x) = player_str_to_id.get(player_name) {
+                *x
+            } else {
+                let player_id = database.players.len() as PlayerIDGlobal;
+                player_str_to_id.insert(player_name.to_string(), player_id);
+                database.players.push(player_name.to_string());
+                player_id
+            };
+        }
+        let parsing_flags = if group.iter().all(|(_, (_, _, win))| *win == 0) { FLAG_NO_WINNER_BSW } else { 0 };
+        game_id_to_idx.insert(*game_id, Game {
+            rounds: Vec::new(),
+            player_ids,
+            original_bsw_id: *game_id,
+            parsing_flags,
+        });
+        round_results.insert(*game_id, Vec::new());
+        Ok(())
     }
     fn parse_runde_file(
         game_id_to_idx: &mut HashMap<u32, Game>,
         round_results: &mut HashMap<u32, Vec<(Score, Score)>>,
         exclude_rounds: &mut HashMap<u32, Vec<usize>>,
         path: &str,
-    )
+        mode: ParseMode,
+        diagnostics: &mut Vec<ParseDiagnostic>,
+    ) -> Result<(), BswParseError>
     {
-        let file = File::open(path).unwrap();
+        let file = File::open(path).map_err(|err| BswParseError::io(path, 0, err))?;
         let reader = BufReader::new(file);
-        let mut lines = reader.lines();
-        while let Some(line) = lines.next() {
-            let line = line.unwrap();
-            let mut parts = line.split(";");
-            let game_id = parts.next().unwrap().parse::<u32>().unwrap();
-            let round = parts.next().unwrap().parse::<usize>().unwrap() - 1;
-
-            assert!(game_id_to_idx.contains_key(&game_id));
-            let game: &mut Game = game_id_to_idx.get_mut(&game_id).unwrap();
-            let game_round_results: &mut Vec<(Score, Score)> = round_results.get_mut(&game_id).unwrap();
-
-            assert!(game.rounds.len() >= round);
-            if game.rounds.len() == round {
-                game.rounds.push((Round::default(), RoundLog::default()));
-                assert_eq!(game_round_results.len(), round);
-                game_round_results.push((0, 0));
+        for (line_num, line) in reader.lines().enumerate() {
+            let line = line.map_err(|err| BswParseError::io(path, line_num + 1, err))?;
+            let mut fields = LineFields::new(&line, path, line_num + 1);
+            let mut parsed_round: Option<(u32, usize)> = None;
+            let res = DataBase::parse_runde_line(game_id_to_idx, round_results, exclude_rounds, diagnostics, &mut fields, &mut parsed_round);
+            if let Err(err) = res {
+                if let Some((game_id, round)) = parsed_round && let Some(game) = game_id_to_idx.get_mut(&game_id) {
+                    DataBase::add_skip_round(exclude_rounds, game, round);
+                }
+                handle_line_error(mode, diagnostics, parsed_round, err)?;
             }
-            assert_eq!(game.rounds.len(), round + 1);
-
+        }
+        Ok(())
+    }
+    fn parse_runde_line(
+        game_id_to_idx: &mut HashMap<u32, Game>,
+        round_results: &mut HashMap<u32, Vec<(Score, Score)>>,
+        exclude_rounds: &mut HashMap<u32, Vec<usize>>,
+        diagnostics: &mut Vec<ParseDiagnostic>,
+        fields: &mut LineFields,
+        parsed_round: &mut Option<(u32, usize)>,
+    ) -> Result<(), BswParseError>
+    {
+        let game_id = fields.next_parse::<u32>()?;
+        let game: &mut Game = game_id_to_idx.get_mut(&game_id).ok_or_else(|| fields.error(BswParseErrorKind::UnknownGame(game_id)))?;
+        let round = fields.next_index()?;
+        let game_round_results: &mut Vec<(Score, Score)> = round_results.get_mut(&game_id).ok_or_else(|| fields.error(BswParseErrorKind::UnknownGame(game_id)))?;
 
-            let player: PlayerIDInternal = parts.next().unwrap().parse().unwrap();
-            let call = match parts.next().unwrap() {
-                "T" => CALL_TICHU,
-                "GT" => CALL_GRAND_TICHU,
-                _ => CALL_NONE,
-            };
-            let rank = parts.next().unwrap().parse::<Rank>().unwrap() - 1;
-            parts.next(); // Punkte (sadly, this field is wrong!) We have to fix it ourselves..
-            if player == PLAYER_0 || player == PLAYER_1 {
-                let ergebnis = parts.next().unwrap().parse::<Score>().unwrap();
-                let old_score = game_round_results[round];
-                let new_score = if player == PLAYER_0 { (ergebnis, old_score.1) } else { (old_score.0, ergebnis) };
-                game_round_results[round] = new_score;
-            } else {
-                parts.next();
-            }
-            parts.next(); //vorsprung
-            let first_8 = parts.next().unwrap();
-            let first_14 = parts.next().unwrap();
-            let mut exch_out = parts.next().unwrap().chars();
-            let mut exch_in = parts.next().unwrap().chars();
-            let final_14 = parts.next().unwrap();
-            let player_round_hand: &mut PlayerRoundHand = game
-                .rounds
-                .get_mut(round)
-                .unwrap().0
-                .player_rounds
-                .get_mut(player as usize)
-                .unwrap();
-            player_round_hand.first_8 = tichu_one_str_to_hand(first_8);
-            player_round_hand.first_14 = tichu_one_str_to_hand(first_14);
-            for i in 0..3 {
-                player_round_hand.extras ^=
-                    (TICHU_ONE_ENCODING[&exch_out.next().unwrap()] as u64) << (i * 6);
+        if game.rounds.len() < round {
+            return Err(fields.error(BswParseErrorKind::RoundOutOfOrder { expected: game.rounds.len(), found: round }));
+        }
+        if game.rounds.len() == round {
+            if game_round_results.len() != round {
+                return Err(fields.error(BswParseErrorKind::RoundOutOfOrder { expected: game_round_results.len(), found: round }));
             }
-            for i in 0..3 {
-                player_round_hand.extras ^=
-                    (TICHU_ONE_ENCODING[&exch_in.next().unwrap()] as u64) << ((i + 3) * 6);
+            game.rounds.push((Round::default(), RoundLog::default()));
+            game_round_results.push((0, 0));
+        }
+        if game.rounds.len() != round + 1 {
+            return Err(fields.error(BswParseErrorKind::RoundOutOfOrder { expected: game.rounds.len() - 1, found: round }));
+        }
+        *parsed_round = Some((game_id, round));
+
+        let player: PlayerIDInternal = fields.next_player()?;
+        let call = match fields.next_str()? {
+            "T" => CALL_TICHU,
+            "GT" => CALL_GRAND_TICHU,
+            _ => CALL_NONE,
+        };
+        let rank = fields.next_index()?;
+        if rank > 3 {
+            //A larger rank would spill into the card score bits of the extras.
+            return Err(fields.error(BswParseErrorKind::InvalidNumber((rank + 1).to_string())));
+        }
+        let rank = rank as Rank;
+        fields.skip(); // Punkte (sadly, this field is wrong!) We have to fix it ourselves..
+        if player == PLAYER_0 || player == PLAYER_1 {
+            let ergebnis = fields.next_parse::<Score>()?;
+            let old_score = game_round_results[round];
+            let new_score = if player == PLAYER_0 { (ergebnis, old_score.1) } else { (old_score.0, ergebnis) };
+            game_round_results[round] = new_score;
+        } else {
+            fields.skip();
+        }
+        fields.skip(); //vorsprung
+        let first_8 = fields.next_hand()?;
+        let first_14 = fields.next_hand()?;
+        let mut exchange = [0 as CardIndex; 6];
+        for (cards, exchange_str) in exchange.chunks_mut(3).zip([fields.next_str()?, fields.next_str()?]) {
+            let mut chars = exchange_str.chars();
+            for card in cards.iter_mut() {
+                *card = fields.card(chars.next().ok_or_else(|| fields.error(BswParseErrorKind::MissingField))?)?;
             }
+        }
+        let final_14 = fields.next_hand()?;
+        let player_round_hand: &mut PlayerRoundHand = game
+            .rounds
+            .get_mut(round)
+            .unwrap().0
+            .player_rounds
+            .get_mut(player as usize)
+            .unwrap();
+        player_round_hand.first_8 = first_8;
+        player_round_hand.first_14 = first_14;
+        for (i, card) in exchange.iter().enumerate() {
+            player_round_hand.extras ^= (*card as u64) << (i * 6);
+        }
 
-            player_round_hand.extras ^= (call as u64) << (36 + 2 * player);
-            player_round_hand.extras ^= (player as u64) << 44;
-            player_round_hand.extras ^= (rank as u64) << (46 + 2 * player);
+        player_round_hand.extras ^= (call as u64) << (36 + 2 * player);
+        player_round_hand.extras ^= (player as u64) << 44;
+        player_round_hand.extras ^= (rank as u64) << (46 + 2 * player);
 
-            if let Some(err) = player_round_hand.integrity_check().err() {
-                //Any of these errors can not be recovered from. The round is trash.
-                println!("Skipping Game {} round {}: {:?} in PlayerRoundHand {:?}. File {}.", game_id, round, err, player_round_hand, path);
-                DataBase::add_skip_round(exclude_rounds, game, round);
-                continue;
-            }
-            if player_round_hand.final_14() != tichu_one_str_to_hand(final_14) {
-                //This is also enough reason to discard the round and invite further investigation.
-                println!("Skipping Game {} round {}: PlayerRoundHand {} does not match parsed final 14 {}. File {}.",
-                         game_id, round, player_round_hand.final_14().pretty_print(),
-                         tichu_one_str_to_hand(final_14).pretty_print(), path);
-                DataBase::add_skip_round(exclude_rounds, game, round);
-            }
+        if let Some(err) = player_round_hand.integrity_check().err() {
+            //Any of these errors can not be recovered from. The round is trash.
+            DataBase::add_diagnostic(diagnostics, game_id, round, DiagnosticKind::PlayerRoundHandIntegrity(err));
+            DataBase::add_skip_round(exclude_rounds, game, round);
+            return Ok(());
+        }
+        if player_round_hand.final_14() != final_14 {
+            //This is also enough reason to discard the round and invite further investigation.
+            DataBase::add_diagnostic(diagnostics, game_id, round, DiagnosticKind::Final14Mismatch { player, parsed: final_14, calculated: player_round_hand.final_14() });
+            DataBase::add_skip_round(exclude_rounds, game, round);
         }
+        Ok(())
     }
 
     fn parse_zugfolge_file(
         game_id_to_idx: &mut HashMap<u32, Game>,
         exclude_rounds: &mut HashMap<u32, Vec<usize>>,
         path: &str,
-    )
+        mode: ParseMode,
+        diagnostics: &mut Vec<ParseDiagnostic>,
+    ) -> Result<(), BswParseError>
     {
-        let file = File::open(path).unwrap();
+        let file = File::open(path).map_err(|err| BswParseError::io(path, 0, err))?;
         let reader = BufReader::new(file);
-        let mut lines = reader.lines();
-        while let Some(line) = lines.next() {
-            let line = line.unwrap();
-            let mut parts = line.split(";");
-            let game_id = parts.next().unwrap().parse::<u32>().unwrap();
-
-            assert!(game_id_to_idx.contains_key(&game_id));
-            let game: &mut Game = game_id_to_idx.get_mut(&game_id).unwrap();
-            let round = parts.next().unwrap().parse::<usize>().unwrap() - 1;
-            if exclude_rounds.contains_key(&game_id) && exclude_rounds[&game_id].contains(&round) {
-                continue;
-            }
-            if game.rounds.len() <= round {
-                game.parsing_flags |= FLAG_GAME_STOPPED_WITHIN_ROUND;
-                continue;
+        for (line_num, line) in reader.lines().enumerate() {
+            let line = line.map_err(|err| BswParseError::io(path, line_num + 1, err))?;
+            let mut fields = LineFields::new(&line, path, line_num + 1);
+            let mut parsed_round: Option<(u32, usize)> = None;
+            let res = DataBase::parse_zugfolge_line(game_id_to_idx, exclude_rounds, &mut fields, &mut parsed_round);
+            if let Err(err) = res {
+                if let Some((game_id, round)) = parsed_round && let Some(game) = game_id_to_idx.get_mut(&game_id) {
+                    DataBase::add_skip_round(exclude_rounds, game, round);
+                }
+                handle_line_error(mode, diagnostics, parsed_round, err)?;
             }
-            let _ = parts.next().unwrap().parse::<usize>().unwrap() - 1; //Trick num.
-
-            let (_, round_log) = game.rounds.get_mut(round).unwrap();
+        }
+        Ok(())
+    }
+    fn parse_zugfolge_line(
+        game_id_to_idx: &mut HashMap<u32, Game>,
+        exclude_rounds: &mut HashMap<u32, Vec<usize>>,
+        fields: &mut LineFields,
+        parsed_round: &mut Option<(u32, usize)>,
+    ) -> Result<(), BswParseError>
+    {
+        let game_id = fields.next_parse::<u32>()?;
+        let game: &mut Game = game_id_to_idx.get_mut(&game_id).ok_or_else(|| fields.error(BswParseErrorKind::UnknownGame(game_id)))?;
+        let round = fields.next_index()?;
+        if exclude_rounds.contains_key(&game_id) && exclude_rounds[&game_id].contains(&round) {
+            return Ok(());
+        }
+        if game.rounds.len() <= round {
+            game.parsing_flags |= FLAG_GAME_STOPPED_WITHIN_ROUND;
+            return Ok(());
+        }
+        *parsed_round = Some((game_id, round));
+        let _ = fields.next_index()?; //Trick num.
 
+        let (_, round_log) = game.rounds.get_mut(round).unwrap();
 
-            let mut trick = Trick::default();
-            let trick_type_str = parts.next().unwrap();
-            trick.trick_type = trick_type_str_to_trick_type(trick_type_str);
+        let mut trick = Trick::default();
+        let trick_type_str = fields.next_str()?;
+        trick.trick_type = trick_type_str_to_trick_type(trick_type_str).ok_or_else(|| fields.error(BswParseErrorKind::InvalidTrickType(trick_type_str.to_string())))?;
 
-            let trick_length = parts.next().unwrap().parse::<usize>().unwrap();
-            trick.trick_log = Vec::with_capacity(trick_length);
-            let trick_players: Vec<PlayerIDInternal> = parts.next().unwrap().chars()
-                .map(|c| c.to_digit(10).unwrap() as PlayerIDInternal)
-                .collect();
-            assert_eq!(trick_length, trick_players.len());
-            let trick_cards = parts.next().unwrap();
-            let trick_hands = trick_cards.split("|");
+        let trick_length = fields.next_parse::<usize>()?;
+        trick.trick_log = Vec::with_capacity(trick_length);
+        let trick_players_str = fields.next_str()?;
+        let trick_players: Vec<PlayerIDInternal> = trick_players_str.chars()
+            .map(|c| c.to_digit(10).filter(|player| *player <= PLAYER_3 as u32).map(|player| player as PlayerIDInternal))
+            .collect::<Option<Vec<PlayerIDInternal>>>()
+            .ok_or_else(|| fields.error(BswParseErrorKind::InvalidPlayer(trick_players_str.to_string())))?;
+        if trick_length != trick_players.len() {
+            return Err(fields.error(BswParseErrorKind::TrickLengthMismatch { expected: trick_length, found: trick_players.len() }));
+        }
+        let trick_cards = fields.next_str()?;
+        let trick_hands = trick_cards.split("|");
 
-            for (i, trick_hand) in trick_hands.enumerate() {
-                if trick_hand.len() == 0 {
-                    continue;
+        for trick_hand in trick_hands {
+            if trick_hand.is_empty() {
+                continue;
+            }
+            if trick.trick_log.len() == trick_length {
+                return Err(fields.error(BswParseErrorKind::TrickLengthMismatch { expected: trick_length, found: trick_length + 1 }));
+            }
+            let mut hand_bb = 0u64;
+            let mut chars = trick_hand.chars().peekable();
+            while let Some(c) = chars.next() {
+                let new_card = hand!(fields.card(c)?);
+                if new_card & hand_bb != 0u64 {
+                    return Err(fields.error(BswParseErrorKind::DuplicateCard(c)));
                 }
-                let mut hand_bb = 0u64;
-                let mut chars = trick_hand.chars().peekable();
-                while let Some(c) = chars.next() {
-                    let new_card_index = TICHU_ONE_ENCODING[&c];
-                    let new_card = hand!(new_card_index);
-                    assert_eq!(new_card & hand_bb, 0u64);
-                    hand_bb ^= new_card;
-                    if chars.peek() == Some(&'(') {
-                        chars.next(); // consume '('
-                        let next = chars.next().unwrap();
-                        if hand_bb & hand!(MAHJONG) != 0u64 {
-                            assert!(round_log.mahjong_wish.is_none());
-                            round_log.mahjong_wish = Some(card_wish_to_cardtype(next));
-                        } else if hand_bb & hand!(DRAGON) != 0u64 {
-                            assert!(round_log.dragon_player_gift.is_none());
-                            round_log.dragon_player_gift = Some(next.to_digit(10).unwrap() as PlayerIDInternal);
-                        } else {
-                            //Mahjong is served. Check that it holds
-                            assert!(round_log.mahjong_wish.is_some());
-                            let wish = round_log.mahjong_wish.unwrap();
-                            assert_ne!(hand_bb & MASK_FOUR_OF_KIND[(wish - 1) as usize], 0u64);
+                hand_bb ^= new_card;
+                if chars.peek() == Some(&'(') {
+                    chars.next(); // consume '('
+                    let next = chars.next().ok_or_else(|| fields.error(BswParseErrorKind::MissingField))?;
+                    if hand_bb & hand!(MAHJONG) != 0u64 {
+                        if round_log.mahjong_wish.is_some() {
+                            return Err(fields.error(BswParseErrorKind::DuplicateMahjongWish));
+                        }
+                        let wish = ".23456789TJQKA".find(next).ok_or_else(|| fields.error(BswParseErrorKind::InvalidWish(next)))?;
+                        round_log.mahjong_wish = Some(wish as CardType);
+                    } else if hand_bb & hand!(DRAGON) != 0u64 {
+                        if round_log.dragon_player_gift.is_some() {
+                            return Err(fields.error(BswParseErrorKind::DuplicateDragonGift));
+                        }
+                        let gift_player = next.to_digit(10).filter(|player| *player <= PLAYER_3 as u32).ok_or_else(|| fields.error(BswParseErrorKind::InvalidPlayer(next.to_string())))?;
+                        round_log.dragon_player_gift = Some(gift_player as PlayerIDInternal);
+                    } else {
+                        //Mahjong is served. Check that it holds
+                        let wish = round_log.mahjong_wish.filter(|wish| *wish >= TWO).ok_or_else(|| fields.error(BswParseErrorKind::WishNotFulfilled))?;
+                        if hand_bb & MASK_FOUR_OF_KIND[(wish - 1) as usize] == 0u64 {
+                            return Err(fields.error(BswParseErrorKind::WishNotFulfilled));
                         }
-                        chars.next(); // consume ')'
-                        assert!(chars.next().is_none());
                     }
+                    chars.next(); // consume ')'
+                    let rest: String = chars.collect();
+                    if !rest.is_empty() {
+                        return Err(fields.error(BswParseErrorKind::TrailingCharacters(rest)));
+                    }
+                    break;
                 }
-                trick.trick_log.push((trick_players[i], hand_bb));
             }
+            trick.trick_log.push((trick_players[trick.trick_log.len()], hand_bb));
+        }
 
-            assert_eq!(trick.trick_log.len(), trick_length);
-            trick.serialize_into(round_log);
+        if trick.trick_log.len() != trick_length {
+            return Err(fields.error(BswParseErrorKind::TrickLengthMismatch { expected: trick_length, found: trick.trick_log.len() }));
         }
+        trick.serialize_into(round_log);
+        Ok(())
     }
 }
@@ -0,0 +1,247 @@
+use std::fmt::Debug;
+use std::path::PathBuf;
+use std::time::Instant;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use crate::analysis::bomb_stats::{evaluate_bomb_stats, evaluate_bombs_in_play};
+use crate::analysis::exchange_stats::evaluate_exchange_stats;
+use crate::analysis::first_8_transition_probability::calculate_transition_probabilities;
+use crate::analysis::general_stats::{evaluate_general_stats, evaluate_general_stats_onlyr0};
+use crate::analysis::gt_stats::{evaluate_gt_call_rates, evaluate_gt_stats, evaluate_gt_win_probs, HandCategory};
+use crate::analysis::parsing_stats::evaluate_parsing_stats;
+use crate::analysis::player_rating::{evaluate_player_ratings, EloConfig, PlayerRatings};
+use crate::analysis::report::AnalysisReport;
+use crate::analysis::street_stats::{evaluate_lose_four_to_queen, evaluate_lose_tichujana_hand, evaluate_streets_in_play, evaluate_streets_size_four_become_real_streets};
+use crate::bsw_binary_format::binary_format_constants::PlayerIDGlobal;
+use crate::bsw_database::{DataBase, ParseMode};
+use crate::enumeration_cache::{EnumerationCache, DEFAULT_CACHE_DIR};
+use crate::enumeration_results;
+
+#[derive(Parser, Debug)]
+#[command(about = "Tichu hand enumeration and analysis of the BSW game logs")]
+pub struct Cli {
+    #[arg(long, global = true, default_value = "bsw.db", help = "Database file to read, or to write for import")]
+    pub db: PathBuf,
+    #[arg(long, global = true, default_value = DEFAULT_CACHE_DIR, help = "Directory of stored enumeration results, filled by enumerate")]
+    pub cache: PathBuf,
+    #[command(flatten)]
+    pub players: PlayerFilterArgs,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+//Restrict the database to some players before filter and stats.
+#[derive(Args, Debug, Default, PartialEq)]
+pub struct PlayerFilterArgs {
+    #[arg(long = "player", global = true, help = "Only games in which all of these players take part (repeatable)")]
+    pub players: Vec<String>,
+    #[arg(long, global = true, help = "Only games in which every player has at least this Elo rating")]
+    pub min_rating: Option<f64>,
+    #[arg(long, global = true, default_value_t = 0, help = "Minimum number of rated games for --min-rating")]
+    pub min_games: usize,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    #[command(about = "Parse the BSW csv dump into the database file given by --db")]
+    Import {
+        #[arg(long, default_value = "../tichulog_csv/", help = "Directory of the BSW csv files")]
+        source: String,
+        #[arg(long, help = "Record malformed lines as diagnostics instead of aborting")]
+        lenient: bool,
+        #[arg(long, help = "Also write the diagnostics of the import as csv")]
+        diagnostics: Option<String>,
+    },
+    #[command(about = "Write the games matching the player filters to a new database, e.g. filter --min-rating 1600 --min-games 100 bsw_filtered.db")]
+    Filter {
+        #[arg(help = "Database file to write")]
+        output: String,
+    },
+    #[command(about = "Run an analysis on the database")]
+    Stats {
+        name: StatsName,
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        #[arg(long, help = "Write to this file instead of stdout. The scripts in analysis_resuts read <report name>.json")]
+        output: Option<PathBuf>,
+    },
+    #[command(about = "Count a property over all hands by exhaustive enumeration, without reading the database. Results are stored in --cache")]
+    Enumerate {
+        property: EnumerationProperty,
+    },
+    #[command(about = "Print hands and tricks of a round")]
+    Replay {
+        #[arg(help = "Original BSW game id")]
+        game: u32,
+        #[arg(help = "Index of the round in the stored game, excluded rounds are not counted")]
+        round: usize,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatsName {
+    Parsing,
+    General,
+    GeneralR0,
+    Bombs,
+    BombsInPlay,
+    StreetsInPlay,
+    Street4BecomesStreet5,
+    LoseTichujanaHand,
+    LoseFourToQueen,
+    Exchange,
+    Gt,
+    GtWinProbs,
+    GtCallRates,
+    TransitionProbabilities,
+    PlayerRatings,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnumerationProperty {
+    TichuHands,
+    Bombs,
+    FourOfKindBombs,
+    StraightBombs,
+    GtHands,
+    GtBombs,
+    GtFourAces,
+    GtHandCategory,
+    First14HandCategory,
+    First14BombsStraightCategory,
+    LongestStraight,
+    LongestStraightFlush,
+    CategoryList,
+}
+
+fn exit_on_error<T, E: Debug>(result: Result<T, E>, context: &str) -> T {
+    result.unwrap_or_else(|err| {
+        eprintln!("{}: {:?}", context, err);
+        std::process::exit(1);
+    })
+}
+
+fn read_db(cli: &Cli) -> DataBase {
+    let db = exit_on_error(DataBase::read(&cli.db.to_string_lossy()), &format!("Could not read {}", cli.db.display()));
+    eprintln!("Loaded {} games and {} rounds!", db.games.len(), db.games.iter().fold(0, |acc, inc| acc + inc.rounds.len()));
+    db
+}
+
+impl PlayerFilterArgs {
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty() && self.min_rating.is_none()
+    }
+    pub fn apply(&self, db: DataBase) -> Result<DataBase, String> {
+        if self.is_empty() {
+            return Ok(db);
+        }
+        let player_ids = self.players.iter().map(|name| {
+            db.players.iter().position(|player| player == name).map(|id| id as PlayerIDGlobal).ok_or(format!("Unknown player {}", name))
+        }).collect::<Result<Vec<_>, _>>()?;
+        let mut query = db.query().with_players(&player_ids);
+        if let Some(min_rating) = self.min_rating {
+            let ratings = PlayerRatings::compute(&db, EloConfig::default());
+            query = query.only_players(ratings.players_above(min_rating, self.min_games));
+        }
+        Ok(query.to_database())
+    }
+}
+
+pub fn run(cli: Cli) {
+    match &cli.command {
+        Command::Import { source, lenient, diagnostics } => {
+            let mode = if *lenient { ParseMode::Lenient } else { ParseMode::Strict };
+            let db = exit_on_error(DataBase::from_bsw_dir(source, mode), "Import failed");
+            if let Some(path) = diagnostics {
+                exit_on_error(db.write_diagnostics_csv(path), "Could not write diagnostics");
+            }
+            exit_on_error(db.write(&cli.db.to_string_lossy()), "Could not write database");
+        }
+        Command::Filter { output } => {
+            let db = read_db(&cli);
+            println!("All players: {}", db.players.len());
+            let new_db = exit_on_error(cli.players.apply(db), "Invalid player filter");
+            println!("Filtered games: {} and {} rounds", new_db.games.len(), new_db.games.iter().fold(0, |acc, inc| acc + inc.rounds.len()));
+            exit_on_error(new_db.write(output), "Could not write database");
+        }
+        Command::Stats { name, format, output } => {
+            let report = run_stats(&cli, *name);
+            let content = match format {
+                OutputFormat::Text => report.to_string(),
+                OutputFormat::Json => report.to_json(),
+                OutputFormat::Csv => report.to_csv(),
+            };
+            match output {
+                Some(path) => exit_on_error(std::fs::write(path, content), "Could not write report"),
+                None => println!("{}", content),
+            }
+        }
+        Command::Enumerate { property } => run_enumeration(&EnumerationCache::new(&cli.cache), *property),
+        Command::Replay { game, round } => {
+            let db = read_db(&cli);
+            println!("{}", exit_on_error(replay(&db, *game, *round), "Replay failed"));
+        }
+    }
+}
+
+fn run_stats(cli: &Cli, name: StatsName) -> AnalysisReport {
+    //Analyses on the database
+    let evaluate: fn(&DataBase) -> AnalysisReport = match name {
+        StatsName::GtCallRates => return evaluate_gt_call_rates(enumeration_results::count_gt_hand_category(&EnumerationCache::new(&cli.cache))),
+        StatsName::TransitionProbabilities => return calculate_transition_probabilities(),
+        StatsName::Parsing => evaluate_parsing_stats,
+        StatsName::General => evaluate_general_stats,
+        StatsName::GeneralR0 => evaluate_general_stats_onlyr0,
+        StatsName::Bombs => evaluate_bomb_stats,
+        StatsName::BombsInPlay => evaluate_bombs_in_play,
+        StatsName::StreetsInPlay => evaluate_streets_in_play,
+        StatsName::Street4BecomesStreet5 => evaluate_streets_size_four_become_real_streets,
+        StatsName::LoseTichujanaHand => evaluate_lose_tichujana_hand,
+        StatsName::LoseFourToQueen => evaluate_lose_four_to_queen,
+        StatsName::Exchange => evaluate_exchange_stats,
+        StatsName::Gt => evaluate_gt_stats,
+        StatsName::GtWinProbs => evaluate_gt_win_probs,
+        StatsName::PlayerRatings => evaluate_player_ratings,
+    };
+    let db = exit_on_error(cli.players.apply(read_db(cli)), "Invalid player filter");
+    evaluate(&db)
+}
+
+fn run_enumeration(cache: &EnumerationCache, property: EnumerationProperty) {
+    let start = Instant::now();
+    match property {
+        EnumerationProperty::TichuHands => println!("{}", enumeration_results::count_tichu_hands(cache)),
+        EnumerationProperty::Bombs => println!("{}", enumeration_results::count_bombs_0_1(cache)),
+        EnumerationProperty::FourOfKindBombs => println!("{}", enumeration_results::count_four_of_kind_bombs_0_1(cache)),
+        EnumerationProperty::StraightBombs => println!("{}", enumeration_results::count_straight_bombs_0_1(cache)),
+        EnumerationProperty::GtHands => println!("{}", enumeration_results::count_gt_hands(cache)),
+        EnumerationProperty::GtBombs => println!("{}", enumeration_results::count_gt_bombs_0_1(cache)),
+        EnumerationProperty::GtFourAces => println!("{}", enumeration_results::count_gt_hand_has_four_aces(cache)),
+        EnumerationProperty::GtHandCategory => println!("{:?}", enumeration_results::count_gt_hand_category(cache)),
+        EnumerationProperty::First14HandCategory => println!("{:?}", enumeration_results::count_first14_hand_category(cache)),
+        EnumerationProperty::First14BombsStraightCategory => println!("{:?}", enumeration_results::count_first14_bombs_straight_category(cache)),
+        EnumerationProperty::LongestStraight => println!("{}", enumeration_results::count_longest_straight_distribution(cache)),
+        EnumerationProperty::LongestStraightFlush => println!("{}", enumeration_results::count_longest_straight_flush_distribution(cache)),
+        EnumerationProperty::CategoryList => HandCategory::print_category_lists(),
+    }
+    println!("Time elapsed is: {:?}", start.elapsed());
+}
+
+pub fn replay(db: &DataBase, game_id: u32, round_idx: usize) -> Result<String, String> {
+    let game = db.games.iter().find(|game| game.original_bsw_id == game_id).ok_or(format!("No game with BSW id {}", game_id))?;
+    let (round, round_log) = game.rounds.get(round_idx).ok_or(format!("Game {} has only {} rounds", game_id, game.rounds.len()))?;
+    let pr0 = &round.player_rounds[0];
+    let mut res_str = format!("Game {}, round {}\n", game_id, round_idx);
+    for player in 0..4 {
+        res_str.push_str(&format!("P{}: {} (call {}, rank {})\n", player, db.players[game.player_ids[player] as usize], pr0.player_call(player as u8), pr0.player_rank(player as u8)));
+    }
+    res_str.push_str(&format!("Round score: {:?}\n", pr0.round_score()));
+    res_str.push_str(&round_log.to_debug_str(round));
+    Ok(res_str)
+}
@@ -3,21 +3,53 @@ use std::fmt;
 use std::fmt::Debug;
 use std::ops::{Add, Mul};
 
+use generic_array::typenum::{Prod, Sum, Unsigned};
 use generic_array::{typenum, ArrayLength, GenericArray};
 use crate::analysis::gt_stats::HandCategory;
+use crate::analysis::statistics::{Mean, Proportion};
+use crate::bsw_binary_format::binary_format_constants::PlayerIDInternal;
 use crate::hand;
 
-pub trait CountableProperty: Debug + Clone {
+//Number of values a property can take. Shared by properties of single hands and of whole deals, so both are counted in a Counter.
+pub trait PropertyRange: Debug + Clone {
     type UpperBound: ArrayLength;
+    //Bump when the counted values change, so stored counters of the old implementation are not loaded, see EnumerationCache.
+    const VERSION: u32 = 1;
+}
+//Anything a Counter can be filled with: adds the multiplicity of the hand to one or more entries of property_counted.
+pub trait CountableProperty: PropertyRange {
+    fn count_into(&self, hand: &Hand, hand_multiplicity: u64, property_counted: &mut [u64]);
+}
+//Property with one value per hand. Only these can be combined with CountJoint, PlayerHand and PlayersWith.
+pub trait SingleValueProperty: PropertyRange {
     fn count(&self, hand: &Hand) -> usize;
 }
-pub struct Counter<P: CountableProperty> {
+impl<P: SingleValueProperty> CountableProperty for P {
+    #[inline(always)]
+    fn count_into(&self, hand: &Hand, hand_multiplicity: u64, property_counted: &mut [u64]) {
+        property_counted[self.count(hand)] += hand_multiplicity;
+    }
+}
+//Property of the four hands of a deal, e.g. how many players hold a bomb. Too expensive to enumerate, see sample_deals.
+pub trait CountableDealProperty: PropertyRange {
+    fn count_deal_into(&self, deal: &[Hand; 4], property_counted: &mut [u64]);
+}
+pub trait SingleValueDealProperty: PropertyRange {
+    fn count_deal(&self, deal: &[Hand; 4]) -> usize;
+}
+impl<P: SingleValueDealProperty> CountableDealProperty for P {
+    #[inline(always)]
+    fn count_deal_into(&self, deal: &[Hand; 4], property_counted: &mut [u64]) {
+        property_counted[self.count_deal(deal)] += 1;
+    }
+}
+pub struct Counter<P: PropertyRange> {
     pub property: P,
     pub hands_evaluated: u64,
     pub hands_counted: u64,
     pub property_counted: GenericArray<u64, P::UpperBound>,
 }
-impl<P: CountableProperty> Counter<P> {
+impl<P: PropertyRange> Counter<P> {
     pub fn new(property: P) -> Self {
         Counter {
             property,
@@ -26,14 +58,41 @@ impl<P: CountableProperty> Counter<P> {
             property_counted: GenericArray::default(),
         }
     }
+    //Empty histogram of another property over the same hands.
+    fn for_same_hands<Q: PropertyRange>(&self, property: Q) -> Counter<Q> {
+        Counter { hands_evaluated: self.hands_evaluated, hands_counted: self.hands_counted, ..Counter::new(property) }
+    }
 
+    //Share of the counted hands per property value. Exact for enumerated counters, with sampling error for sampled ones.
+    pub fn proportions(&self) -> Vec<Proportion> {
+        self.property_counted.iter().map(|count| Proportion::new(*count as usize, self.hands_counted as usize)).collect()
+    }
+    //Mean property value. The standard error is only meaningful for sampled counters, which count every hand once.
+    pub fn mean(&self) -> Mean {
+        let mut mean = Mean { n: self.hands_counted as usize, ..Mean::default() };
+        for (value, count) in self.property_counted.iter().enumerate() {
+            mean.sum += (value as u64 * count) as f64;
+            mean.sum_squares += (value as u64 * value as u64 * count) as f64;
+        }
+        mean
+    }
+}
+impl<P: CountableProperty> Counter<P> {
     pub fn count_hand(&mut self, hand: &Hand, hand_multiplicity: u64) {
         self.hands_evaluated += 1;
         self.hands_counted += hand_multiplicity;
-        self.property_counted[self.property.count(hand)] += hand_multiplicity;
+        self.property.count_into(hand, hand_multiplicity, &mut self.property_counted);
+    }
+}
+impl<P: CountableDealProperty> Counter<P> {
+    //Every deal is counted once, hands_counted is the number of deals.
+    pub fn count_deal(&mut self, deal: &[Hand; 4]) {
+        self.hands_evaluated += 1;
+        self.hands_counted += 1;
+        self.property.count_deal_into(deal, &mut self.property_counted);
     }
 }
-impl<P: CountableProperty> Add for Counter<P> {
+impl<P: PropertyRange> Add for Counter<P> {
     type Output = Self;
 
     fn add(mut self, other: Self) -> Self {
@@ -49,7 +108,7 @@ impl<P: CountableProperty> Add for Counter<P> {
     }
 }
 
-impl<P: CountableProperty> Mul<u64> for Counter<P> {
+impl<P: PropertyRange> Mul<u64> for Counter<P> {
     type Output = Self;
     fn mul(mut self, other: u64) -> Self {
         for i in 0..self.property_counted.len() {
@@ -64,7 +123,7 @@ impl<P: CountableProperty> Mul<u64> for Counter<P> {
     }
 }
 
-impl<P: CountableProperty> fmt::Display for Counter<P> {
+impl<P: PropertyRange> fmt::Display for Counter<P> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut res_str = String::new();
         res_str.push_str(&format!(
@@ -78,6 +137,134 @@ impl<P: CountableProperty> fmt::Display for Counter<P> {
     }
 }
 
+//Joint histogram of two properties: entry a * |B| + b counts the hands with value a for the first and b for the second property.
+//Nest for more than two properties. Both properties have to be special card invariant to be counted with count_special_card_invariant_property.
+#[derive(Debug, Clone)]
+pub struct CountJoint<A, B>(pub A, pub B);
+
+//Independent histograms of two properties, filled in the same enumeration. property_counted is the histogram of A followed by the one of B.
+//Nest for more than two properties, e.g. CountIndependent(CountBombs0_1, CountIndependent(CountLongestStraight, CountHandCategory)).
+//It fills several entries per hand, so it is not a SingleValueProperty and can not be nested in CountJoint or PlayerHand.
+#[derive(Debug, Clone)]
+pub struct CountIndependent<A, B>(pub A, pub B);
+
+//Property of the hand of one player in a deal.
+#[derive(Debug, Clone)]
+pub struct PlayerHand<P>(pub PlayerIDInternal, pub P);
+
+//Number of players whose hand has the 0/1 property, e.g. PlayersWith(CountBombs0_1) for the number of players holding a bomb.
+#[derive(Debug, Clone)]
+pub struct PlayersWith<P>(pub P);
+
+impl<A: PropertyRange, B: PropertyRange> PropertyRange for CountJoint<A, B>
+where
+    A::UpperBound: Mul<B::UpperBound>,
+    Prod<A::UpperBound, B::UpperBound>: ArrayLength,
+{
+    type UpperBound = Prod<A::UpperBound, B::UpperBound>;
+    const VERSION: u32 = A::VERSION + B::VERSION;
+}
+impl<A: SingleValueProperty, B: SingleValueProperty> SingleValueProperty for CountJoint<A, B>
+where
+    CountJoint<A, B>: PropertyRange,
+{
+    #[inline(always)]
+    fn count(&self, hand: &Hand) -> usize {
+        self.0.count(hand) * B::UpperBound::USIZE + self.1.count(hand)
+    }
+}
+impl<A: SingleValueDealProperty, B: SingleValueDealProperty> SingleValueDealProperty for CountJoint<A, B>
+where
+    CountJoint<A, B>: PropertyRange,
+{
+    fn count_deal(&self, deal: &[Hand; 4]) -> usize {
+        self.0.count_deal(deal) * B::UpperBound::USIZE + self.1.count_deal(deal)
+    }
+}
+
+impl<A: PropertyRange, B: PropertyRange> PropertyRange for CountIndependent<A, B>
+where
+    A::UpperBound: Add<B::UpperBound>,
+    Sum<A::UpperBound, B::UpperBound>: ArrayLength,
+{
+    type UpperBound = Sum<A::UpperBound, B::UpperBound>;
+    const VERSION: u32 = A::VERSION + B::VERSION;
+}
+impl<A: CountableProperty, B: CountableProperty> CountableProperty for CountIndependent<A, B>
+where
+    CountIndependent<A, B>: PropertyRange,
+{
+    #[inline(always)]
+    fn count_into(&self, hand: &Hand, hand_multiplicity: u64, property_counted: &mut [u64]) {
+        let (counted_a, counted_b) = property_counted.split_at_mut(A::UpperBound::USIZE);
+        self.0.count_into(hand, hand_multiplicity, counted_a);
+        self.1.count_into(hand, hand_multiplicity, counted_b);
+    }
+}
+impl<A: CountableDealProperty, B: CountableDealProperty> CountableDealProperty for CountIndependent<A, B>
+where
+    CountIndependent<A, B>: PropertyRange,
+{
+    fn count_deal_into(&self, deal: &[Hand; 4], property_counted: &mut [u64]) {
+        let (counted_a, counted_b) = property_counted.split_at_mut(A::UpperBound::USIZE);
+        self.0.count_deal_into(deal, counted_a);
+        self.1.count_deal_into(deal, counted_b);
+    }
+}
+
+impl<P: PropertyRange> PropertyRange for PlayerHand<P> {
+    type UpperBound = P::UpperBound;
+    const VERSION: u32 = P::VERSION;
+}
+impl<P: SingleValueProperty> SingleValueDealProperty for PlayerHand<P> {
+    fn count_deal(&self, deal: &[Hand; 4]) -> usize {
+        self.1.count(&deal[self.0 as usize])
+    }
+}
+
+impl<P: SingleValueProperty<UpperBound = typenum::U2>> PropertyRange for PlayersWith<P> {
+    type UpperBound = typenum::U5;
+    const VERSION: u32 = P::VERSION;
+}
+impl<P: SingleValueProperty<UpperBound = typenum::U2>> SingleValueDealProperty for PlayersWith<P> {
+    fn count_deal(&self, deal: &[Hand; 4]) -> usize {
+        deal.iter().map(|hand| self.0.count(hand)).sum()
+    }
+}
+
+impl<A: PropertyRange, B: PropertyRange> Counter<CountJoint<A, B>>
+where
+    CountJoint<A, B>: PropertyRange,
+{
+    pub fn joint(&self, a: usize, b: usize) -> u64 {
+        self.property_counted[a * B::UpperBound::USIZE + b]
+    }
+    //Histograms of the single properties.
+    pub fn marginals(&self) -> (Counter<A>, Counter<B>) {
+        let mut counter_a = self.for_same_hands(self.property.0.clone());
+        let mut counter_b = self.for_same_hands(self.property.1.clone());
+        for (i, value) in self.property_counted.iter().enumerate() {
+            counter_a.property_counted[i / B::UpperBound::USIZE] += value;
+            counter_b.property_counted[i % B::UpperBound::USIZE] += value;
+        }
+        (counter_a, counter_b)
+    }
+}
+
+impl<A: PropertyRange, B: PropertyRange> Counter<CountIndependent<A, B>>
+where
+    CountIndependent<A, B>: PropertyRange,
+{
+    pub fn split(&self) -> (Counter<A>, Counter<B>) {
+        let mut counter_a = self.for_same_hands(self.property.0.clone());
+        let mut counter_b = self.for_same_hands(self.property.1.clone());
+        let (counted_a, counted_b) = self.property_counted.split_at(A::UpperBound::USIZE);
+        counter_a.property_counted.copy_from_slice(counted_a);
+        counter_b.property_counted.copy_from_slice(counted_b);
+        (counter_a, counter_b)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CountAll; //Every Hand passes this
 #[derive(Debug, Clone)]
@@ -102,49 +289,62 @@ pub struct CountLongestStraight;
 #[derive(Debug, Clone)]
 pub struct CountLongestStraightFlush;
 
-impl CountableProperty for CountAll {
+impl PropertyRange for CountAll {
     type UpperBound = typenum::U1;
-
+}
+impl SingleValueProperty for CountAll {
     fn count(&self, _: &Hand) -> usize {
         0
     }
 }
-impl CountableProperty for CountBombs0_1 {
+impl PropertyRange for CountBombs0_1 {
     type UpperBound = typenum::U2;
+}
+impl SingleValueProperty for CountBombs0_1 {
     fn count(&self, hand: &Hand) -> usize {
         (hand.contains_four_of_kind_bomb() || hand.contains_straight_bomb()) as usize
     }
 }
 
-impl CountableProperty for CountBombsFourOfKind0_1 {
+impl PropertyRange for CountBombsFourOfKind0_1 {
     type UpperBound = typenum::U2;
+}
+impl SingleValueProperty for CountBombsFourOfKind0_1 {
     fn count(&self, hand: &Hand) -> usize {
         hand.contains_four_of_kind_bomb() as usize
     }
 }
 
-impl CountableProperty for CountBombsStraights0_1 {
+impl PropertyRange for CountBombsStraights0_1 {
     type UpperBound = typenum::U2;
+}
+impl SingleValueProperty for CountBombsStraights0_1 {
     fn count(&self, hand: &Hand) -> usize {
         hand.contains_straight_bomb() as usize
     }
 }
 
-impl CountableProperty for CountHandCategory {
+impl PropertyRange for CountHandCategory {
     type UpperBound = typenum::U80;
+}
+impl SingleValueProperty for CountHandCategory {
     fn count(&self, hand: &Hand) -> usize {
         HandCategory::categorize_hand(hand).0
     }
 }
-impl CountableProperty for CountHasFourAces0_1 {
+impl PropertyRange for CountHasFourAces0_1 {
     type UpperBound = typenum::U2;
+}
+impl SingleValueProperty for CountHasFourAces0_1 {
     fn count(&self, hand: &Hand) -> usize {
         ((hand & MASK_ACES).count_ones() == 4) as usize
     }
 }
 
-impl CountableProperty for CountLongestStraight {
+impl PropertyRange for CountLongestStraight {
     type UpperBound = typenum::U13;
+}
+impl SingleValueProperty for CountLongestStraight {
     fn count(&self, hand: &Hand) -> usize {
         let mut hand_in_yellow = ((hand >> BLUE) | (hand >> GREEN) | (hand >> RED) | hand) & MASK_YELLOW;
         let mut straight_length = 1;
@@ -156,8 +356,10 @@ impl CountableProperty for CountLongestStraight {
     }
 }
 
-impl CountableProperty for CountLongestStraightFlush {
+impl PropertyRange for CountLongestStraightFlush {
     type UpperBound = typenum::U13;
+}
+impl SingleValueProperty for CountLongestStraightFlush {
     fn count(&self, hand: &Hand) -> usize {
         let mut hand = hand & MASK_NORMAL_CARDS;
         let mut straight_length = 1;
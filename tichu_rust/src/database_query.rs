@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+use std::ops::RangeBounds;
+use crate::bsw_binary_format::binary_format_constants::*;
+use crate::bsw_binary_format::game::{Game, ParsingFlagGame};
+use crate::bsw_binary_format::player_round_hand::PlayerRoundHand;
+use crate::bsw_binary_format::round::Round;
+use crate::bsw_binary_format::round_log::RoundLog;
+use crate::bsw_database::DataBase;
+
+//Borrowed view of a round together with its game.
+#[derive(Clone, Copy)]
+pub struct RoundView<'a> {
+    pub game: &'a Game,
+    pub round_idx: usize,
+    pub round: &'a Round,
+    pub round_log: &'a RoundLog,
+    pub score_before: (Score, Score), //Game score of team 1 and team 2 before this round
+}
+impl<'a> RoundView<'a> {
+    pub fn player_round(&self, player: PlayerIDInternal) -> &'a PlayerRoundHand {
+        &self.round.player_rounds[player as usize]
+    }
+    pub fn call(&self, player: PlayerIDInternal) -> TichuCall {
+        self.round.player_rounds[0].player_call(player)
+    }
+    pub fn rank(&self, player: PlayerIDInternal) -> Rank {
+        self.round.player_rounds[0].player_rank(player)
+    }
+}
+
+//Filters are Sync so a query can be evaluated from several threads, see analysis::map_reduce.
+type GameFilter<'a> = Box<dyn Fn(&Game) -> bool + Sync + 'a>;
+type RoundFilter<'a> = Box<dyn Fn(&RoundView) -> bool + Sync + 'a>;
+
+//Composable filter over a DataBase. Games and rounds are only borrowed, to_database copies the result.
+//Game filters drop whole games, round filters drop single rounds. A game is part of the result if it has at least one matching round.
+pub struct Query<'a> {
+    db: &'a DataBase,
+    game_filters: Vec<GameFilter<'a>>,
+    round_filters: Vec<RoundFilter<'a>>,
+}
+
+impl DataBase {
+    pub fn query(&self) -> Query<'_> {
+        Query { db: self, game_filters: Vec::new(), round_filters: Vec::new() }
+    }
+}
+
+impl<'a> Query<'a> {
+    pub fn filter_games(mut self, filter: impl Fn(&Game) -> bool + Sync + 'a) -> Self {
+        self.game_filters.push(Box::new(filter));
+        self
+    }
+    pub fn filter_rounds(mut self, filter: impl Fn(&RoundView) -> bool + Sync + 'a) -> Self {
+        self.round_filters.push(Box::new(filter));
+        self
+    }
+
+    //Games in which all given players take part.
+    pub fn with_players(self, players: &[PlayerIDGlobal]) -> Self {
+        let players = players.to_vec();
+        self.filter_games(move |game| players.iter().all(|player| game.player_ids.contains(player)))
+    }
+    //Games in which every seat is taken by one of the given players.
+    pub fn only_players(self, players: HashSet<PlayerIDGlobal>) -> Self {
+        self.filter_games(move |game| game.player_ids.iter().all(|player| players.contains(player)))
+    }
+    pub fn with_parsing_flags(self, flags: ParsingFlagGame) -> Self {
+        self.filter_games(move |game| game.parsing_flags & flags == flags)
+    }
+    pub fn without_parsing_flags(self, flags: ParsingFlagGame) -> Self {
+        self.filter_games(move |game| game.parsing_flags & flags == 0)
+    }
+
+    pub fn round_index(self, range: impl RangeBounds<usize> + Sync + 'a) -> Self {
+        self.filter_rounds(move |view| range.contains(&view.round_idx))
+    }
+    //Rounds in which some player made the call. CALL_NONE matches rounds without any call.
+    pub fn with_call(self, call: TichuCall) -> Self {
+        if call == CALL_NONE {
+            return self.filter_rounds(|view| (0..4).all(|player| view.call(player) == CALL_NONE));
+        }
+        self.filter_rounds(move |view| (0..4).any(|player| view.call(player) == call))
+    }
+    pub fn with_player_call(self, player: PlayerIDInternal, call: TichuCall) -> Self {
+        self.filter_rounds(move |view| view.call(player) == call)
+    }
+    pub fn with_player_hand(self, player: PlayerIDInternal, predicate: impl Fn(&PlayerRoundHand) -> bool + Sync + 'a) -> Self {
+        self.filter_rounds(move |view| predicate(view.player_round(player)))
+    }
+    pub fn with_any_hand(self, predicate: impl Fn(&PlayerRoundHand) -> bool + Sync + 'a) -> Self {
+        self.filter_rounds(move |view| view.round.player_rounds.iter().any(&predicate))
+    }
+    //Rounds by the game score of team 1 and team 2 before the round.
+    pub fn with_score_before(self, predicate: impl Fn((Score, Score)) -> bool + Sync + 'a) -> Self {
+        self.filter_rounds(move |view| predicate(view.score_before))
+    }
+
+    fn matching_game(&self, game: &Game) -> bool {
+        self.game_filters.iter().all(|filter| filter(game))
+    }
+    pub(crate) fn game_rounds<'b>(&'b self, game: &'a Game) -> impl Iterator<Item = RoundView<'a>> + 'b {
+        let mut score_before = (0, 0);
+        game.rounds.iter().enumerate().map(move |(round_idx, (round, round_log))| {
+            let view = RoundView { game, round_idx, round, round_log, score_before };
+            let round_score = round.player_rounds[0].round_score();
+            score_before = (score_before.0 + round_score.0, score_before.1 + round_score.1);
+            view
+        }).filter(|view| self.round_filters.iter().all(|filter| filter(view)))
+    }
+
+    pub fn rounds(&self) -> impl Iterator<Item = RoundView<'a>> + '_ {
+        self.db.games.iter().filter(|game| self.matching_game(game)).flat_map(|game| self.game_rounds(game))
+    }
+    pub fn games(&self) -> impl Iterator<Item = &'a Game> + '_ {
+        self.db.games.iter().filter(|game| self.matching_game(game) && self.game_rounds(game).next().is_some())
+    }
+    //Games passing the game filters, without checking for matching rounds.
+    pub(crate) fn games_unchecked(&self) -> impl Iterator<Item = &'a Game> + '_ {
+        self.db.games.iter().filter(|game| self.matching_game(game))
+    }
+    pub fn count_rounds(&self) -> usize {
+        self.rounds().count()
+    }
+
+    //Copies the matching rounds into a new database, e.g. to save a filtered snapshot. Players and diagnostics are kept as they are.
+    pub fn to_database(&self) -> DataBase {
+        let games = self.db.games.iter().filter(|game| self.matching_game(game)).filter_map(|game| {
+            let rounds: Vec<(Round, RoundLog)> = self.game_rounds(game).map(|view| (view.round.clone(), view.round_log.clone())).collect();
+            if rounds.is_empty() {
+                return None;
+            }
+            Some(Game { rounds, player_ids: game.player_ids, original_bsw_id: game.original_bsw_id, parsing_flags: game.parsing_flags })
+        }).collect();
+        DataBase { games, players: self.db.players.clone(), diagnostics: self.db.diagnostics.clone() }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::bsw_binary_format::binary_format_constants::{PlayerIDInternal, Rank, Score, TichuCall};
+use crate::bsw_binary_format::round::{Round, RoundIntegrityError};
+use crate::monte_carlo::DealRng;
+use crate::tichu_hand::{CardIndex, Hand, TichuHand, MASK_ALL};
+use crate::hand;
+
+//Seeded generator of synthetic deals in the format of the BSW rounds. The same seed and constraints always give the same deals,
+//e.g. to compare strategies on identical deals. Deals are uniform among all deals satisfying the constraints.
+pub struct Dealer {
+    rng: DealRng,
+    fixed_first_8: [Hand; 4],
+    fixed_first_14: [Hand; 4],
+}
+
+//The first 8 cards (before Grand Tichu) and all 14 cards (before the exchange) of each player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deal {
+    pub first_8: [Hand; 4],
+    pub first_14: [Hand; 4],
+}
+
+//Cards passed by each player, to the left opponent, the partner and the right opponent. The right opponent of player p is p + 1.
+pub type Exchange = [[CardIndex; 3]; 4];
+
+//A deal with its exchange, before any play. It has no result, so it can not be mistaken for a played Round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DealtRound {
+    pub deal: Deal,
+    pub exchange: Exchange,
+}
+
+//Outcome of playing a DealtRound, e.g. in a simulation. card_points_team_1 are the card points of team 1, from -25 to 125.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundResult {
+    pub ranks: [Rank; 4],
+    pub calls: [TichuCall; 4],
+    pub card_points_team_1: Score,
+}
+
+impl Dealer {
+    pub fn new(seed: u64) -> Self {
+        Dealer { rng: DealRng::new(seed), fixed_first_8: [0; 4], fixed_first_14: [0; 4] }
+    }
+    //The player gets these cards within the first 8.
+    pub fn with_first_8(mut self, player: PlayerIDInternal, cards: Hand) -> Self {
+        self.fix_cards(player, cards);
+        self.fixed_first_8[player as usize] |= cards;
+        assert!(self.fixed_first_8[player as usize].count_ones() <= 8, "More than 8 fixed cards in the first 8 of player {}", player);
+        self
+    }
+    //The player gets these cards within the 14 cards before the exchange.
+    pub fn with_first_14(mut self, player: PlayerIDInternal, cards: Hand) -> Self {
+        self.fix_cards(player, cards);
+        self
+    }
+    fn fix_cards(&mut self, player: PlayerIDInternal, cards: Hand) {
+        assert_eq!(cards & !MASK_ALL, 0, "Invalid cards");
+        let fixed_elsewhere = (0..4).filter(|other| *other != player as usize).fold(0, |acc, other| acc | self.fixed_first_14[other]);
+        assert_eq!(cards & fixed_elsewhere, 0, "Cards are already fixed for another player");
+        self.fixed_first_14[player as usize] |= cards;
+        assert!(self.fixed_first_14[player as usize].count_ones() <= 14, "More than 14 fixed cards for player {}", player);
+    }
+
+    pub fn deal(&mut self) -> Deal {
+        let first_14 = self.rng.deal_from(self.fixed_first_14);
+        let mut first_8 = self.fixed_first_8;
+        for (player, hand) in first_8.iter_mut().enumerate() {
+            *hand |= self.rng.subset(first_14[player] & !*hand, 8 - hand.count_ones());
+        }
+        Deal { first_8, first_14 }
+    }
+    //Deal with three random cards passed by every player, in random order to the left opponent, the partner and the right opponent.
+    pub fn deal_round(&mut self) -> DealtRound {
+        let deal = self.deal();
+        let mut exchange = [[0; 3]; 4];
+        for (player, cards) in exchange.iter_mut().enumerate() {
+            let mut remaining = deal.first_14[player];
+            for card in cards.iter_mut() {
+                let passed = self.rng.card_of(remaining);
+                *card = passed.get_lsb_card();
+                remaining ^= passed;
+            }
+        }
+        DealtRound { deal, exchange }
+    }
+}
+
+impl DealtRound {
+    //Hands after the exchange, to start a GameState.
+    pub fn get_starting_hands(&self) -> [Hand; 4] {
+        let mut hands = self.deal.first_14;
+        for (player, cards) in self.exchange.iter().enumerate() {
+            for (i, card) in cards.iter().enumerate() {
+                //Left opponent is player + 3, partner player + 2 and right opponent player + 1.
+                hands[player] ^= hand!(*card);
+                hands[(player + 3 - i) % 4] ^= hand!(*card);
+            }
+        }
+        hands
+    }
+    //Round in the BSW format once the result of playing the deal is known. Fails if the exchange does not fit the deal.
+    pub fn to_round(&self, result: RoundResult) -> Result<Round, RoundIntegrityError> {
+        assert!((-25..=125).contains(&result.card_points_team_1), "Card points {} out of range", result.card_points_team_1);
+        let exchange = self.exchange;
+        let mut shared = ((result.card_points_team_1 + 25) as u64) << 54;
+        for player in 0..4 {
+            shared |= (result.calls[player] as u64) << (36 + 2 * player) | (result.ranks[player] as u64) << (46 + 2 * player);
+        }
+        let mut round = Round::default();
+        for (player, player_round) in round.player_rounds.iter_mut().enumerate() {
+            player_round.first_8 = self.deal.first_8[player];
+            player_round.first_14 = self.deal.first_14[player];
+            //From the left opponent, who passes to the right, from the partner and from the right opponent.
+            let incoming = [exchange[(player + 3) % 4][2], exchange[(player + 2) % 4][1], exchange[(player + 1) % 4][0]];
+            for (i, card) in exchange[player].iter().chain(incoming.iter()).enumerate() {
+                player_round.extras |= (*card as u64) << (i * 6);
+            }
+            player_round.extras |= (player as u64) << 44 | shared;
+        }
+        round.integrity_check()?;
+        Ok(round)
+    }
+}
@@ -0,0 +1,214 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+use crate::tichu_hand::*;
+use crate::bsw_binary_format::binary_format_constants::*;
+use crate::bsw_binary_format::round::Round;
+use crate::game_state::{Action, GameState};
+
+//Perfect information solver for a round: every player sees all hands, team 1 (players 0 and 2) maximizes and team 2 minimizes
+//the round score difference team 1 - team 2 (tichu calls, double win or card points, as PlayerRoundHand::round_score).
+//Moves follow GameState::legal_actions, so bombs are only played in turn and interrupts (GameState::apply_bomb) are not searched.
+//The transposition table has a fixed number of slots and the search stops after node_budget nodes, solve then returns None (unknown).
+//Full 14 card rounds mostly run into the budget, endgames of a few tricks are solved exactly.
+pub struct DoubleDummySolver {
+    calls: [TichuCall; 4],
+    transposition_table: Vec<Option<(SolverKey, TranspositionEntry)>>, //Allocated on the first solve, the length is a power of two
+    table_size: usize,
+    node_budget: u64,
+    node_limit: u64,
+    pub nodes: u64,
+}
+
+pub const DEFAULT_TABLE_SIZE: usize = 1 << 18;
+pub const DEFAULT_NODE_BUDGET: u64 = 50_000_000;
+
+#[derive(Debug, Clone)]
+pub struct SolverResult {
+    pub value: Score, //Round score of team 1 minus round score of team 2 under optimal play
+    pub ranks: [Rank; 4],
+    pub card_points: [Score; 4],
+    pub double_win: bool,
+    pub principal_variation: Vec<Action>,
+}
+
+//Everything of a GameState that influences the rest of the round. The trick log itself only matters through its last player and its points.
+#[derive(PartialEq, Eq, Hash)]
+struct SolverKey {
+    hands: [Hand; 4],
+    top: Option<HandType>,
+    turn: PlayerIDInternal,
+    passes: u8,
+    trick_player: Option<PlayerIDInternal>,
+    trick_points: Score,
+    wish: Option<CardType>,
+    ranks: [Rank; 4],
+    points: [Score; 4],
+    dragon_gift_pending: bool,
+}
+impl SolverKey {
+    fn new(state: &GameState) -> Self {
+        SolverKey {
+            hands: state.hands,
+            top: state.top,
+            turn: state.turn,
+            passes: state.passes,
+            trick_player: state.trick.trick_log.last().map(|(player, _)| *player),
+            trick_points: state.trick.played_cards().get_card_points(),
+            wish: state.wish,
+            ranks: state.ranks,
+            points: state.points,
+            dragon_gift_pending: state.dragon_gift_pending,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+struct TranspositionEntry {
+    value: Score,
+    bound: Bound,
+    best_action: Action,
+    cards_left: u8, //Size of the searched subtree. A slot keeps the entry with more cards left, ties are replaced by the newer entry.
+}
+
+pub fn is_team_1(player: PlayerIDInternal) -> bool {
+    player == PLAYER_0 || player == PLAYER_2
+}
+
+impl DoubleDummySolver {
+    pub fn new(calls: [TichuCall; 4]) -> Self {
+        DoubleDummySolver::with_limits(calls, DEFAULT_TABLE_SIZE, DEFAULT_NODE_BUDGET)
+    }
+    //table_size is rounded up to a power of two, node_budget counts the nodes of a single solve call.
+    pub fn with_limits(calls: [TichuCall; 4], table_size: usize, node_budget: u64) -> Self {
+        DoubleDummySolver { calls, transposition_table: Vec::new(), table_size: table_size.max(1).next_power_of_two(), node_budget, node_limit: 0, nodes: 0 }
+    }
+    pub fn from_round(round: &Round) -> Self {
+        let player_round = &round.player_rounds[0];
+        DoubleDummySolver::new([player_round.player_0_call(), player_round.player_1_call(), player_round.player_2_call(), player_round.player_3_call()])
+    }
+
+    //Solves the starting position of a round, as dealt after the exchange.
+    pub fn solve_round(round: &Round) -> Option<SolverResult> {
+        DoubleDummySolver::from_round(round).solve(&GameState::from_round(round))
+    }
+
+    //None if the node budget ran out before the value was proven.
+    pub fn solve(&mut self, state: &GameState) -> Option<SolverResult> {
+        if self.transposition_table.is_empty() {
+            self.transposition_table.resize_with(self.table_size, || None);
+        }
+        self.node_limit = self.nodes.saturating_add(self.node_budget);
+        let value = self.alpha_beta(state, Score::MIN, Score::MAX)?;
+        //Follow an action keeping the optimal value to the end of the round. Full window searches are exact and mostly answered by the table.
+        let mut principal_variation = Vec::new();
+        let mut current = state.clone();
+        while !current.is_terminal() {
+            let mut next = None;
+            for action in current.legal_actions() {
+                let mut child = current.clone();
+                child.apply_unchecked(action);
+                if self.alpha_beta(&child, Score::MIN, Score::MAX)? == value {
+                    next = Some((action, child));
+                    break;
+                }
+            }
+            let (action, child) = next.unwrap();
+            principal_variation.push(action);
+            current = child;
+        }
+        let (ranks, card_points, double_win) = current.round_result().unwrap();
+        Some(SolverResult { value, ranks, card_points, double_win, principal_variation })
+    }
+
+    fn terminal_value(&self, state: &GameState) -> Score {
+        let (ranks, card_points, double_win) = state.round_result().unwrap();
+        let team_sign = |player: usize| if is_team_1(player as PlayerIDInternal) { 1 } else { -1 };
+        let mut value: Score = 0;
+        for (player, (rank, call)) in ranks.iter().zip(self.calls).enumerate() {
+            let call_sign = if *rank == RANK_1 { 1 } else { -1 };
+            value += team_sign(player) * call_sign * call as Score * 100;
+        }
+        if double_win {
+            let first_player = ranks.iter().position(|rank| *rank == RANK_1).unwrap();
+            value += team_sign(first_player) * 200;
+        } else {
+            value += (0..4).map(|player| team_sign(player) * card_points[player]).sum::<Score>();
+        }
+        value
+    }
+
+    fn table_slot(&self, key: &SolverKey) -> usize {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        hasher.finish() as usize & (self.transposition_table.len() - 1)
+    }
+
+    //None once the node budget is used up, nothing searched after that is stored.
+    fn alpha_beta(&mut self, state: &GameState, mut alpha: Score, mut beta: Score) -> Option<Score> {
+        if self.nodes >= self.node_limit {
+            return None;
+        }
+        self.nodes += 1;
+        if state.is_terminal() {
+            return Some(self.terminal_value(state));
+        }
+        let key = SolverKey::new(state);
+        let slot = self.table_slot(&key);
+        //Bounds are stored relative to the window of the caller, a value inside the window is exact even if the table narrowed it.
+        let (alpha_start, beta_start) = (alpha, beta);
+        let mut tt_action = None;
+        if let Some((slot_key, entry)) = &self.transposition_table[slot] && *slot_key == key {
+            match entry.bound {
+                Bound::Exact => return Some(entry.value),
+                Bound::Lower => alpha = alpha.max(entry.value),
+                Bound::Upper => beta = beta.min(entry.value),
+            }
+            if alpha >= beta {
+                return Some(entry.value);
+            }
+            tt_action = Some(entry.best_action);
+        }
+        let mut actions = state.legal_actions();
+        //Try the stored best action first, then plays getting rid of many cards, and passing last.
+        actions.sort_by_key(|action| {
+            let played = if let Action::Play { cards, .. } = action { cards.count_ones() } else { 0 };
+            (Some(*action) != tt_action, *action == Action::Pass, u32::MAX - played)
+        });
+        let maximizing = is_team_1(state.turn);
+        let mut best_value = if maximizing { Score::MIN } else { Score::MAX };
+        let mut best_action = actions[0];
+        for action in actions {
+            let mut child = state.clone();
+            child.apply_unchecked(action);
+            let value = self.alpha_beta(&child, alpha, beta)?;
+            if maximizing && value > best_value || !maximizing && value < best_value {
+                best_value = value;
+                best_action = action;
+            }
+            if maximizing {
+                alpha = alpha.max(value);
+            } else {
+                beta = beta.min(value);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+        let bound = if best_value <= alpha_start {
+            Bound::Upper
+        } else if best_value >= beta_start {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        let cards_left = state.hands.iter().map(|hand| hand.count_ones()).sum::<u32>() as u8;
+        if self.transposition_table[slot].as_ref().is_none_or(|(_, entry)| entry.cards_left <= cards_left) {
+            self.transposition_table[slot] = Some((key, TranspositionEntry { value: best_value, bound, best_action, cards_left }));
+        }
+        Some(best_value)
+    }
+}
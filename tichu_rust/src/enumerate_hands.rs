@@ -1,74 +1,209 @@
+use crate::bit_ops::pdep;
 use crate::countable_properties::{CountableProperty, Counter};
 use crate::hand;
 use crate::tichu_hand::*;
-use std::time::Instant;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+//The enumeration is split into tasks which fix the special cards and the amounts of the first SPLIT_DEPTH card types.
+//Tasks are counted on all threads and their counters are added up, which gives the same Counter as a sequential run.
+const SPLIT_DEPTH: usize = 3;
+
+struct EnumerationTask {
+    special_card_hand: Hand,
+    multiplicity: u64, //Number of special card sets the task stands for
+    other_cards: [u32; 13],
+    cards_sum: u32,
+    current_index: usize,
+}
 
 pub fn count_special_card_sensitive_property<
-    P: CountableProperty,
+    P: CountableProperty + Send + Sync,
     const TARGET_NUM_CARDS: u32,
 >(
     kind: P,
 ) -> Counter<P> {
-    let start = Instant::now();
-    let mut global_counter = Counter::new(kind.clone());
+    count_special_card_sensitive_property_with_threads::<P, TARGET_NUM_CARDS>(kind, default_threads())
+}
+
+pub fn count_special_card_sensitive_property_with_threads<
+    P: CountableProperty + Send + Sync,
+    const TARGET_NUM_CARDS: u32,
+>(
+    kind: P,
+    threads: usize,
+) -> Counter<P> {
+    let mut tasks = Vec::new();
     for special_card_bits in 0..16 {
-        let mut local_counter = Counter::new(kind.clone());
-        let mut other_cards = [0; 13];
-        let special_card_hand = unsafe {
-            use std::arch::x86_64::_pdep_u64;
-            _pdep_u64(special_card_bits, hand!(DRAGON, PHOENIX, MAHJONG, DOG))
-        };
-        count_property_recursive_upwards::<P, TARGET_NUM_CARDS>(
-            &mut other_cards,
-            special_card_hand,
-            special_card_hand.count_ones(),
-            0,
-            &mut local_counter,
-        );
-        global_counter = global_counter + local_counter;
-        println!("{}", global_counter);
+        let special_card_hand = pdep(special_card_bits, hand!(DRAGON, PHOENIX, MAHJONG, DOG));
+        split_recursive_upwards::<TARGET_NUM_CARDS>(&mut [0; 13], special_card_hand, 1, special_card_hand.count_ones(), 0, &mut tasks);
     }
-    let duration = start.elapsed();
-    println!("Time elapsed is: {:?}", duration);
-    println!(
-        "Searched {:.2} hands per second",
-        global_counter.hands_evaluated as f64 / duration.as_secs_f64()
-    );
-    global_counter
+    count_enumeration_tasks::<P, TARGET_NUM_CARDS>(kind, &tasks, threads)
 }
 
 pub fn count_special_card_invariant_property<
-    P: CountableProperty,
+    P: CountableProperty + Send + Sync,
+    const TARGET_NUM_CARDS: u32,
+>(
+    kind: P,
+) -> Counter<P> {
+    count_special_card_invariant_property_with_threads::<P, TARGET_NUM_CARDS>(kind, default_threads())
+}
+
+pub fn count_special_card_invariant_property_with_threads<
+    P: CountableProperty + Send + Sync,
     const TARGET_NUM_CARDS: u32,
 >(
     kind: P,
+    threads: usize,
 ) -> Counter<P> {
-    let start = Instant::now();
     let special_card_amount_to_frequency: [u64; 5] = [1, 4, 6, 4, 1];
-    let mut global_counter = Counter::new(kind.clone());
     let special_card_hands = [0u64, hand!(DRAGON), hand!(DRAGON, PHOENIX), hand!(DRAGON,PHOENIX, DOG), hand!(DRAGON,PHOENIX,DOG, MAHJONG)];
+    let mut tasks = Vec::new();
     for special_card_amount in (0usize..=4).rev() {
-        let mut local_counter = Counter::new(kind.clone());
-        let mut other_cards = [0; 13];
-
-        count_property_recursive_upwards::<P, TARGET_NUM_CARDS>(
-            &mut other_cards,
+        split_recursive_upwards::<TARGET_NUM_CARDS>(
+            &mut [0; 13],
             special_card_hands[special_card_amount],
+            special_card_amount_to_frequency[special_card_amount],
             special_card_amount as u32,
             0,
-            &mut local_counter,
+            &mut tasks,
         );
-        global_counter =
-            global_counter + local_counter * special_card_amount_to_frequency[special_card_amount];
-        println!("{}", global_counter);
     }
-    let duration = start.elapsed();
-    println!("Time elapsed is: {:?}", duration);
-    println!(
-        "Searched {:.2} hands per second",
-        global_counter.hands_evaluated as f64 / duration.as_secs_f64()
-    );
-    global_counter
+    count_enumeration_tasks::<P, TARGET_NUM_CARDS>(kind, &tasks, threads)
+}
+
+pub(crate) fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |threads| threads.get())
+}
+
+//Same recursion and pruning as count_property_recursive_upwards, but stops at SPLIT_DEPTH and records the state as a task.
+fn split_recursive_upwards<const TARGET_NUM_CARDS: u32>(
+    other_cards: &mut [u32; 13],
+    special_card_hand: Hand,
+    multiplicity: u64,
+    cards_sum: u32,
+    current_index: usize,
+    tasks: &mut Vec<EnumerationTask>,
+) {
+    if cards_sum == TARGET_NUM_CARDS || current_index == SPLIT_DEPTH {
+        if cards_sum <= TARGET_NUM_CARDS {
+            tasks.push(EnumerationTask { special_card_hand, multiplicity, other_cards: *other_cards, cards_sum, current_index });
+        }
+        return;
+    }
+    if cards_sum > TARGET_NUM_CARDS || cards_sum + (13 - current_index as u32) * 4 < TARGET_NUM_CARDS {
+        return;
+    }
+    for card_amount in 0..=4 {
+        other_cards[current_index] = card_amount;
+        split_recursive_upwards::<TARGET_NUM_CARDS>(other_cards, special_card_hand, multiplicity, cards_sum + card_amount, current_index + 1, tasks);
+    }
+}
+
+//Threads take the next task from a shared index, so long and short tasks even out. Nothing is printed, the enumerate command reports the time.
+fn count_tasks<P: CountableProperty + Send + Sync, T: Sync>(
+    kind: P,
+    tasks: &[T],
+    threads: usize,
+    count_task: impl Fn(&T) -> Counter<P> + Sync,
+) -> Counter<P> {
+    let next_task = AtomicUsize::new(0);
+    let thread_counters: Vec<Counter<P>> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads.max(1)).map(|_| scope.spawn(|| {
+            let mut thread_counter = Counter::new(kind.clone());
+            while let Some(task) = tasks.get(next_task.fetch_add(1, Ordering::Relaxed)) {
+                thread_counter = thread_counter + count_task(task);
+            }
+            thread_counter
+        })).collect();
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+    thread_counters.into_iter().fold(Counter::new(kind), |acc, inc| acc + inc)
+}
+
+fn count_enumeration_tasks<P: CountableProperty + Send + Sync, const TARGET_NUM_CARDS: u32>(
+    kind: P,
+    tasks: &[EnumerationTask],
+    threads: usize,
+) -> Counter<P> {
+    count_tasks(kind.clone(), tasks, threads, |task| {
+        let mut task_counter = Counter::new(kind.clone());
+        let mut other_cards = task.other_cards;
+        count_property_recursive_upwards::<P, TARGET_NUM_CARDS>(
+            &mut other_cards,
+            task.special_card_hand,
+            task.cards_sum,
+            task.current_index,
+            &mut task_counter,
+        );
+        task_counter * task.multiplicity
+    })
+}
+
+//Counts the property over all hands of TARGET_NUM_CARDS cards that contain the fixed cards and none of the excluded cards,
+//e.g. the 6 card completions of a first_8 with TARGET_NUM_CARDS = 14. Every completion is a different hand and counted once.
+//Fixed cards break the color symmetry, so unlike the enumeration of all hands this visits every completion.
+pub fn count_completions<P: CountableProperty + Send + Sync, const TARGET_NUM_CARDS: u32>(
+    kind: P,
+    fixed: Hand,
+    excluded: Hand,
+) -> Counter<P> {
+    count_completions_with_threads::<P, TARGET_NUM_CARDS>(kind, fixed, excluded, default_threads())
+}
+
+pub fn count_completions_with_threads<P: CountableProperty + Send + Sync, const TARGET_NUM_CARDS: u32>(
+    kind: P,
+    fixed: Hand,
+    excluded: Hand,
+    threads: usize,
+) -> Counter<P> {
+    count_completions_to_size(kind, fixed, excluded, TARGET_NUM_CARDS, threads)
+}
+
+//count_completions for a number of cards only known at runtime, e.g. the cards left in an opponent's hand.
+pub fn count_completions_to_size<P: CountableProperty + Send + Sync>(
+    kind: P,
+    fixed: Hand,
+    excluded: Hand,
+    target_num_cards: u32,
+    threads: usize,
+) -> Counter<P> {
+    assert_eq!(fixed & excluded, 0, "Fixed and excluded cards overlap");
+    assert!(fixed.count_ones() <= target_num_cards, "More than {} fixed cards", target_num_cards);
+    let missing = target_num_cards - fixed.count_ones();
+    let available = MASK_ALL & !fixed & !excluded;
+    //One task per lowest added card, or a single task if the fixed cards are already complete.
+    let mut tasks = Vec::new();
+    if missing == 0 {
+        tasks.push((fixed, 0, 0));
+    } else {
+        let mut remaining = available;
+        while remaining != 0 {
+            let card = remaining & remaining.wrapping_neg();
+            remaining ^= card;
+            tasks.push((fixed | card, remaining, missing - 1));
+        }
+    }
+    count_tasks(kind.clone(), &tasks, threads, |&(hand, remaining, missing)| {
+        let mut task_counter = Counter::new(kind.clone());
+        count_completions_recursive(hand, remaining, missing, &mut task_counter);
+        task_counter
+    })
+}
+
+//Adds missing cards out of remaining to hand, deciding for the lowest remaining card whether it is taken.
+fn count_completions_recursive<P: CountableProperty>(hand: Hand, remaining: Hand, missing: u32, counter: &mut Counter<P>) {
+    if missing == 0 {
+        counter.count_hand(&hand, 1);
+        return;
+    }
+    if remaining.count_ones() < missing {
+        return;
+    }
+    let card = remaining & remaining.wrapping_neg();
+    count_completions_recursive(hand | card, remaining ^ card, missing - 1, counter);
+    count_completions_recursive(hand, remaining ^ card, missing, counter);
 }
 
 #[inline(always)]
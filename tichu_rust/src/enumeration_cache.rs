@@ -0,0 +1,129 @@
+use std::io::{Error, ErrorKind};
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use crate::countable_properties::{CountableProperty, Counter, PropertyRange};
+use crate::enumerate_hands::{count_special_card_invariant_property, count_special_card_sensitive_property};
+
+pub const DEFAULT_CACHE_DIR: &str = "enumeration_cache";
+
+//Counters of finished enumerations, one JSON file per property, number of cards and enumeration in the directory.
+//Exact counts do not depend on the enumeration that produced them, only hands_evaluated does. A special card sensitive
+//property counted with the invariant enumeration is wrong though, so both are stored separately.
+pub struct EnumerationCache {
+    dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EnumerationKind {
+    SpecialCardInvariant,
+    SpecialCardSensitive,
+}
+impl EnumerationKind {
+    fn file_suffix(&self) -> &'static str {
+        match self {
+            EnumerationKind::SpecialCardInvariant => "invariant",
+            EnumerationKind::SpecialCardSensitive => "sensitive",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedCounter {
+    property: String,
+    version: u32, //PropertyRange::VERSION of the property when it was counted
+    enumeration: EnumerationKind,
+    target_num_cards: u32,
+    hands_evaluated: u64,
+    hands_counted: u64,
+    property_counted: Vec<u64>,
+}
+
+fn property_key<P: CountableProperty>(property: &P) -> String {
+    format!("{:?}", property)
+}
+
+impl EnumerationCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        EnumerationCache { dir: dir.into() }
+    }
+    //e.g. CountJoint_CountBombs0_1_CountLongestStraight_14_invariant.json
+    pub fn path<P: CountableProperty>(&self, property: &P, target_num_cards: u32, enumeration: EnumerationKind) -> PathBuf {
+        let key: String = property_key(property).chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+        let key = key.split('_').filter(|part| !part.is_empty()).collect::<Vec<_>>().join("_");
+        self.dir.join(format!("{}_{}_{}.json", key, target_num_cards, enumeration.file_suffix()))
+    }
+
+    //None if the counter was never stored. Files of another property, version, enumeration or size are InvalidData.
+    pub fn load<P: CountableProperty>(&self, property: &P, target_num_cards: u32, enumeration: EnumerationKind) -> std::io::Result<Option<Counter<P>>> {
+        let content = match std::fs::read_to_string(self.path(property, target_num_cards, enumeration)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let cached: CachedCounter = serde_json::from_str(&content).map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+        if cached.property != property_key(property) || cached.target_num_cards != target_num_cards || cached.enumeration != enumeration {
+            return Err(Error::new(ErrorKind::InvalidData, format!(
+                "Cached counter is for {} with {} cards, {:?}", cached.property, cached.target_num_cards, cached.enumeration,
+            )));
+        }
+        if cached.version != P::VERSION {
+            return Err(Error::new(ErrorKind::InvalidData, format!("Cached counter has version {}, expected {}", cached.version, P::VERSION)));
+        }
+        let mut counter = Counter::new(property.clone());
+        if cached.property_counted.len() != counter.property_counted.len() {
+            return Err(Error::new(ErrorKind::InvalidData, format!("Cached counter has {} entries, expected {}", cached.property_counted.len(), counter.property_counted.len())));
+        }
+        counter.hands_evaluated = cached.hands_evaluated;
+        counter.hands_counted = cached.hands_counted;
+        counter.property_counted.copy_from_slice(&cached.property_counted);
+        Ok(Some(counter))
+    }
+
+    pub fn store<P: CountableProperty>(&self, counter: &Counter<P>, target_num_cards: u32, enumeration: EnumerationKind) -> std::io::Result<()> {
+        let cached = CachedCounter {
+            property: property_key(&counter.property),
+            version: P::VERSION,
+            enumeration,
+            target_num_cards,
+            hands_evaluated: counter.hands_evaluated,
+            hands_counted: counter.hands_counted,
+            property_counted: counter.property_counted.to_vec(),
+        };
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.path(&counter.property, target_num_cards, enumeration), serde_json::to_string_pretty(&cached).unwrap())
+    }
+
+    //Loads the counter, or counts and stores it. An unreadable or outdated cache file is counted again and overwritten,
+    //a failed store only loses the cache entry, not the result.
+    pub fn get_or_count<P: CountableProperty>(
+        &self,
+        property: P,
+        target_num_cards: u32,
+        enumeration: EnumerationKind,
+        count: impl FnOnce(P) -> Counter<P>,
+    ) -> Counter<P> {
+        let path = self.path(&property, target_num_cards, enumeration);
+        match self.load(&property, target_num_cards, enumeration) {
+            Ok(Some(counter)) => return counter,
+            Ok(None) => {}
+            Err(err) => eprintln!("Ignoring cache file {}: {}", path.display(), err),
+        }
+        let counter = count(property);
+        if let Err(err) = self.store(&counter, target_num_cards, enumeration) {
+            eprintln!("Could not store {}: {}", path.display(), err);
+        }
+        counter
+    }
+    pub fn get_or_count_invariant<P: CountableProperty + Send + Sync, const TARGET_NUM_CARDS: u32>(&self, property: P) -> Counter<P> {
+        self.get_or_count(property, TARGET_NUM_CARDS, EnumerationKind::SpecialCardInvariant, count_special_card_invariant_property::<P, TARGET_NUM_CARDS>)
+    }
+    pub fn get_or_count_sensitive<P: CountableProperty + Send + Sync, const TARGET_NUM_CARDS: u32>(&self, property: P) -> Counter<P> {
+        self.get_or_count(property, TARGET_NUM_CARDS, EnumerationKind::SpecialCardSensitive, count_special_card_sensitive_property::<P, TARGET_NUM_CARDS>)
+    }
+}
+
+impl Default for EnumerationCache {
+    fn default() -> Self {
+        EnumerationCache::new(DEFAULT_CACHE_DIR)
+    }
+}
@@ -3,11 +3,20 @@ pub mod tichu_hand;
 pub mod enumerate_hands;
 pub mod countable_properties;
 pub mod enumeration_results;
+pub mod enumeration_cache;
+pub mod monte_carlo;
+pub mod opponent_hands;
+pub mod dealer;
 pub mod bsw_database;
 pub mod street_detection_tricks;
 pub mod pair_street_detection_trick;
+pub mod move_generation;
+pub mod game_state;
+pub mod double_dummy_solver;
+pub mod database_query;
 pub mod bsw_binary_format;
 pub mod analysis;
+pub mod bit_ops;
 
 use numpy::{PyArray2, PyArrayMethods, ToPyArray, PyReadwriteArray2};
 use pyo3::prelude::*;
@@ -17,6 +26,7 @@ use crate::tichu_hand::{CardIndex, Hand, MASK_ALL, TichuHand, SPECIAL_CARD, PHOE
 use crate::bsw_binary_format::player_round_hand::PlayerRoundHand;
 use crate::bsw_database::DataBase;
 use crate::analysis::exchange_stats::get_exchange_card_type;
+use crate::bit_ops::{pdep, pext};
 
 //We mostly duplicate code/delegate to the Rust structs here. This provides clear seperation
 // of the limited amount of functions we export to Python, which is what I currently prefer.
@@ -39,10 +49,7 @@ pub const COMBINATIONS_TO_2_14_WITH_3_BITS_SET: [u64; 364] = {
 pub fn get_legal_outgoing_card_combinations(hand: Hand) -> Vec<(Hand, u8)> {
     let mut res = Vec::with_capacity(1092);
     for combination in COMBINATIONS_TO_2_14_WITH_3_BITS_SET.iter() {
-        let deposited_combination_in_hand = unsafe {
-            use std::arch::x86_64::_pdep_u64;
-            _pdep_u64(*combination, hand)
-        };
+        let deposited_combination_in_hand = pdep(*combination, hand);
         let mut temp: Hand = deposited_combination_in_hand;
         while temp != 0 {
             let lsb_card = temp.get_lsb_card();
@@ -221,10 +228,7 @@ pub fn transform_into_np90_array(final_hand: Hand, in_partner: u8, out_partner:
 }
 
 pub fn transform_hand_to_lower_56_bits(hand: Hand) -> u64 {
-    unsafe {
-        use std::arch::x86_64::_pext_u64;
-        _pext_u64(hand, MASK_ALL)
-    }
+    pext(hand, MASK_ALL)
 }
 #[pyclass]
 #[derive(Clone)]
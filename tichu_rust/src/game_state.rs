@@ -0,0 +1,226 @@
+use crate::hand;
+use crate::tichu_hand::*;
+use crate::bsw_binary_format::binary_format_constants::*;
+use crate::bsw_binary_format::round::Round;
+use crate::bsw_binary_format::trick::Trick;
+use crate::move_generation::{fulfills_wish, generate_legal_moves};
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Action {
+    Pass,
+    Play { cards: Hand, hand_type: HandType, wish: Option<CardType> }, //wish is only set when the mahjong is played, None means no wish
+    GiftDragon(PlayerIDInternal),
+}
+
+#[derive(Debug)]
+pub enum IllegalActionError {
+    RoundIsOver,
+    NotLegal { action: Action, player: PlayerIDInternal },
+    NotABomb { cards: Hand, player: PlayerIDInternal }, //No bomb of the player which beats the current trick
+}
+
+//Forward playable state of a round, starting after the exchange.
+//Only the player whose turn it is can act through apply. Bombs out of turn interrupt the trick through apply_bomb.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct GameState {
+    pub hands: [Hand; 4],
+    pub trick: Trick, //Moves of the current trick; the trick type is set by the first move.
+    pub top: Option<HandType>, //Hand to beat in the current trick, None when leading. A phoenix singleton carries its value on top of the previous card.
+    pub turn: PlayerIDInternal,
+    pub passes: u8, //Passes since the last play in the current trick
+    pub wish: Option<CardType>, //Active unfulfilled mahjong wish
+    pub ranks: [Rank; 4], //Same layout as RoundLog::play_round, players still playing are RANK_4
+    pub next_rank: Rank,
+    pub points: [Score; 4], //Card points collected in won tricks
+    pub dragon_gift_pending: bool, //The dragon won the trick, the trick winner (turn) has to gift it
+}
+
+impl GameState {
+    pub fn new(hands: [Hand; 4]) -> Self {
+        let turn = hands.iter().position(|hand| hand & hand!(MAHJONG) != 0u64).expect("No player holds the mahjong") as PlayerIDInternal;
+        GameState {
+            hands,
+            trick: Trick::default(),
+            top: None,
+            turn,
+            passes: 0,
+            wish: None,
+            ranks: [RANK_4; 4],
+            next_rank: RANK_1,
+            points: [0; 4],
+            dragon_gift_pending: false,
+        }
+    }
+    pub fn from_round(round: &Round) -> Self {
+        GameState::new(round.get_starting_hands())
+    }
+
+    pub fn is_active(&self, player: PlayerIDInternal) -> bool {
+        self.hands[player as usize] != 0u64
+    }
+    fn next_active_player(&self, player: PlayerIDInternal) -> PlayerIDInternal {
+        let mut next = (player + 1) % 4;
+        while !self.is_active(next) {
+            next = (next + 1) % 4;
+        }
+        next
+    }
+    pub fn is_double_win(&self) -> bool {
+        self.next_rank >= RANK_3 && self.ranks.iter().enumerate().any(|(player, rank)| *rank == RANK_1 && self.ranks[TEAMMATE_PLAYERS[player] as usize] == RANK_2)
+    }
+    pub fn is_terminal(&self) -> bool {
+        self.is_double_win() || self.next_rank >= RANK_4 && !self.dragon_gift_pending
+    }
+
+    pub fn legal_actions(&self) -> Vec<Action> {
+        let mut actions = Vec::new();
+        if self.is_terminal() {
+            return actions;
+        }
+        if self.dragon_gift_pending {
+            let opponents = [(self.turn + 1) % 4, (self.turn + 3) % 4];
+            let any_active = opponents.iter().any(|player| self.is_active(*player));
+            for player in opponents {
+                if self.is_active(player) || !any_active {
+                    actions.push(Action::GiftDragon(player));
+                }
+            }
+            return actions;
+        }
+        let mut moves = Vec::new();
+        generate_legal_moves(self.hands[self.turn as usize], self.top.as_ref(), self.wish, &mut moves);
+        //A player who can fulfill the wish has to.
+        let must_fulfill_wish = self.wish.is_some_and(|wish| moves.iter().any(|(cards, _)| fulfills_wish(*cards, wish)));
+        if self.top.is_some() && !must_fulfill_wish {
+            actions.push(Action::Pass);
+        }
+        for (cards, hand_type) in moves {
+            actions.push(Action::Play { cards, hand_type, wish: None });
+            if cards & hand!(MAHJONG) != 0u64 {
+                for wish in TWO..=ACE {
+                    actions.push(Action::Play { cards, hand_type, wish: Some(wish) });
+                }
+            }
+        }
+        actions
+    }
+
+    pub fn apply(&mut self, action: Action) -> Result<(), IllegalActionError> {
+        if self.is_terminal() {
+            return Err(IllegalActionError::RoundIsOver);
+        }
+        if !self.legal_actions().contains(&action) {
+            return Err(IllegalActionError::NotLegal { action, player: self.turn });
+        }
+        self.apply_unchecked(action);
+        Ok(())
+    }
+
+    //Any active player can bomb the current trick, also out of turn. The turn then moves on from the bombing player.
+    pub fn apply_bomb(&mut self, player: PlayerIDInternal, cards: Hand) -> Result<(), IllegalActionError> {
+        if self.is_terminal() {
+            return Err(IllegalActionError::RoundIsOver);
+        }
+        //Nobody can interrupt before the lead or while the dragon is gifted.
+        let mut moves = Vec::new();
+        if self.top.is_some() && !self.dragon_gift_pending && self.is_active(player) {
+            generate_legal_moves(self.hands[player as usize], self.top.as_ref(), None, &mut moves);
+        }
+        let Some((_, hand_type)) = moves.into_iter().find(|(bomb, hand_type)| *bomb == cards && matches!(hand_type, HandType::Bomb4(_) | HandType::BombStreet(_, _))) else {
+            return Err(IllegalActionError::NotABomb { cards, player });
+        };
+        if player == self.turn {
+            //In turn the wish still has to be fulfilled if possible.
+            return self.apply(Action::Play { cards, hand_type, wish: None });
+        }
+        self.turn = player;
+        self.apply_unchecked(Action::Play { cards, hand_type, wish: None });
+        Ok(())
+    }
+
+    //Applies an action from legal_actions without validating it.
+    pub fn apply_unchecked(&mut self, action: Action) {
+        match action {
+            Action::Pass => {
+                self.passes += 1;
+                let trick_player = self.trick.get_player(self.trick.trick_log.len() - 1);
+                let needed_passes = (0..4).filter(|player| *player != trick_player && self.is_active(*player)).count();
+                if self.passes as usize >= needed_passes {
+                    self.end_trick();
+                } else {
+                    self.turn = self.next_active_player(self.turn);
+                }
+            }
+            Action::Play { cards, hand_type, wish } => {
+                let player = self.turn;
+                if self.trick.trick_log.is_empty() {
+                    self.trick.trick_type = hand_type.get_trick_type();
+                }
+                self.trick.trick_log.push((player, cards));
+                self.top = Some(hand_type.on_top_of(self.top.as_ref()));
+                self.passes = 0;
+                if self.wish.is_some_and(|current| fulfills_wish(cards, current)) {
+                    self.wish = None;
+                }
+                if cards & hand!(MAHJONG) != 0u64 {
+                    self.wish = wish;
+                }
+                self.hands[player as usize] ^= cards;
+                if self.hands[player as usize] == 0u64 {
+                    self.ranks[player as usize] = self.next_rank;
+                    self.next_rank += 1;
+                }
+                if self.is_double_win() {
+                    return;
+                }
+                if hand_type == HandType::Dog || self.next_rank >= RANK_4 {
+                    self.end_trick();
+                } else {
+                    self.turn = self.next_active_player(player);
+                }
+            }
+            Action::GiftDragon(receiver) => {
+                self.points[receiver as usize] += self.trick.played_cards().get_card_points();
+                self.dragon_gift_pending = false;
+                self.start_trick(self.trick.get_trick_winner());
+            }
+        }
+    }
+
+    fn end_trick(&mut self) {
+        let winner = self.trick.get_trick_winner();
+        if self.trick.has_to_gift_trick() {
+            self.dragon_gift_pending = true;
+            self.turn = winner;
+            return;
+        }
+        self.points[winner as usize] += self.trick.played_cards().get_card_points();
+        self.start_trick(winner);
+    }
+    fn start_trick(&mut self, leader: PlayerIDInternal) {
+        self.trick = Trick::default();
+        self.top = None;
+        self.passes = 0;
+        if self.next_rank < RANK_4 {
+            self.turn = if self.is_active(leader) { leader } else { self.next_active_player(leader) };
+        }
+    }
+
+    //Same result as RoundLog::play_round: ranks, card points and whether the round ended in a double win. None while the round is still played.
+    pub fn round_result(&self) -> Option<([Rank; 4], [Score; 4], bool)> {
+        if !self.is_terminal() {
+            return None;
+        }
+        if self.is_double_win() {
+            return Some((self.ranks, [0; 4], true));
+        }
+        let mut player_scores = self.points;
+        let first_player = self.ranks.iter().position(|x| *x == RANK_1).unwrap();
+        let third_player = self.ranks.iter().position(|x| *x == RANK_3).unwrap();
+        let fourth_player = self.ranks.iter().position(|x| *x == RANK_4).unwrap();
+        player_scores[first_player] += player_scores[fourth_player];
+        player_scores[fourth_player] = 0;
+        player_scores[third_player] += self.hands[fourth_player].get_card_points();
+        Some((self.ranks, player_scores, false))
+    }
+}
@@ -3,86 +3,62 @@ pub mod tichu_hand;
 pub mod enumerate_hands;
 pub mod countable_properties;
 pub mod enumeration_results;
+pub mod enumeration_cache;
+pub mod monte_carlo;
+pub mod opponent_hands;
+pub mod dealer;
 pub mod bsw_database;
 pub mod street_detection_tricks;
 pub mod pair_street_detection_trick;
+pub mod move_generation;
+pub mod game_state;
+pub mod double_dummy_solver;
+pub mod database_query;
 pub mod bsw_binary_format;
 pub mod analysis;
+pub mod bit_ops;
+pub mod cli;
 
-use std::collections::HashMap;
-use crate::analysis::bomb_stats::{evaluate_bomb_stats, evaluate_bombs_in_play};
-use crate::analysis::exchange_stats::evaluate_exchange_stats;
-use crate::analysis::first_8_transition_probability::calculate_transition_probabilities;
-use crate::analysis::gt_stats::{evaluate_gt_call_rates, evaluate_gt_stats, evaluate_gt_win_probs, HandCategory};
-use crate::analysis::general_stats::{evaluate_general_stats, evaluate_general_stats_onlyr0};
-use crate::analysis::parsing_stats::evaluate_parsing_stats;
-use crate::analysis::street_stats::{evaluate_lose_four_to_queen, evaluate_lose_tichujana_hand, evaluate_streets_in_play, evaluate_streets_size_four_become_real_streets};
-use crate::bsw_binary_format::binary_format_constants::CALL_GRAND_TICHU;
-use crate::tichu_hand::*;
-use crate::bsw_database::DataBase;
-fn filter_db(db: DataBase){
-    let games_wrs = db.collect_winrate_players();
-    let mut good_players = HashMap::new();
-    for (player, (games, wins)) in games_wrs{
-        if games >= 100 && games as f64 * 0.55 <= wins as f64{
-            good_players.insert(player, db.players[player].clone());
-        }
-    }
-    println!("All players: {}", db.players.len());
-    println!("Good players: {}", good_players.len());
-    let mut new_db = DataBase{games: Vec::new(), players: db.players.clone()};
-    new_db.games = db.games.into_iter().filter(|game| game.player_ids.iter().all(|player_id| good_players.contains_key(&(*player_id as usize)))).collect();
-    println!("Filterd games: {} and {} rounds", new_db.games.len(),  new_db.games.iter().fold(0, |acc, inc| acc + inc.rounds.len() ));
-    new_db.write("bsw_filtered.db").unwrap();
-
-}
+use clap::Parser;
+use crate::cli::Cli;
 
-//4. GT statistics: Get started
 fn main() {
-    //let db = DataBase::from_bsw().unwrap();
-    //db.write("bsw.db").unwrap();
-
-    let db = DataBase::read("bsw.db").unwrap();
-    println!("Loaded {} games and {} rounds!", db.games.len(), db.games.iter().fold(0, |acc, inc| acc + inc.rounds.len() ));
-    //filter_db(db);
-    //evaluate_parsing_stats(&db);
-    //evaluate_general_stats(&db);
-    evaluate_general_stats_onlyr0(&db);
-    //evaluate_bomb_stats(&db);
-    //evaluate_bombs_in_play(&db);
-    //evaluate_streets_in_play(&db);
-    //evaluate_streets_size_four_become_real_streets(&db);
-    //evaluate_lose_tichujana_hand(&db);
-    //evaluate_lose_four_to_queen(&db);
-    //evaluate_exchange_stats(&db);
-    //evaluate_gt_stats(&db);
-    //evaluate_gt_win_probs(&db);
-    //evaluate_gt_call_rates(enumeration_results::count_gt_hand_category());
-
-
-
-
-    //enumeration_results::count_bombs_0_1();
-    //enumeration_results::count_straight_bombs_0_1();
-    //enumeration_results::count_gt_hands();
-    //enumeration_results::count_gt_bombs_0_1();
-    //enumeration_results::count_gt_hand_has_four_aces();
-    //enumeration_results::count_gt_hand_category();
-    //enumeration_results::count_first14_hand_category();
-    //enumeration_results::count_bombs_0_1();
-    //enumeration_results::count_longest_straight_distribution();
-    //enumeration_results::count_longest_straight_flush_distribution();
-    //HandCategory::print_category_lists();
-    //calculate_transition_probabilities();
+    cli::run(Cli::parse());
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::countable_properties::{CountAll, CountBombs0_1};
-    use crate::enumerate_hands::count_special_card_invariant_property;
+    use crate::countable_properties::{CountAll, CountBombs0_1, CountHandCategory, CountIndependent, CountJoint, CountLongestStraight, CountableProperty, Counter, PlayerHand, PlayersWith, PropertyRange, SingleValueProperty};
+    use crate::monte_carlo::{sample_completions, sample_deals, sample_deals_from, sample_property, DealRng};
+    use crate::dealer::{Dealer, DealtRound, RoundResult};
+    use crate::opponent_hands::{UnseenCards, SEAT_LEFT, SEAT_PARTNER, SEAT_RIGHT};
+    use crate::enumerate_hands::{count_completions, count_completions_with_threads, count_special_card_invariant_property, count_special_card_invariant_property_with_threads, count_special_card_sensitive_property_with_threads};
     use crate::tichu_hand::*;
+    use crate::enumeration_cache::{EnumerationCache, EnumerationKind, DEFAULT_CACHE_DIR};
     use crate::street_detection_tricks::{is_street_fast, phoenix_used_as_street_extension};
     use crate::pair_street_detection_trick::{is_pair_street_fast};
+    use crate::game_state::{Action, GameState, IllegalActionError};
+    use crate::double_dummy_solver::{is_team_1, DoubleDummySolver, DEFAULT_NODE_BUDGET};
+    use crate::bsw_binary_format::binary_format_constants::*;
+    use crate::bsw_binary_format::round::Round;
+    use crate::bsw_binary_format::{game, round};
+    use crate::bsw_binary_format::round_log::{RoundLog, RoundLogIntegrityError, TurnAction};
+    use crate::bsw_binary_format::trick::Trick;
+    use crate::bsw_database::{BswParseError, BswParseErrorKind, DataBase, LegacyDataBase, ParseMode};
+    use crate::bsw_binary_format::file_header::{HEADER_SIZE, SCHEMA_VERSION_CURRENT};
+    use crate::bsw_binary_format::game::{Game, FLAG_CHANGED_DRAGON};
+    use crate::database_query::RoundView;
+    use crate::analysis::player_rating::{expected_score, EloConfig, PlayerRatings};
+    use crate::analysis::map_reduce::{map_reduce, map_reduce_with_threads, Accumulator};
+    use crate::analysis::bomb_stats::{BombCounts, BombStats};
+    use crate::analysis::statistics::{normal_cdf, two_proportion_z_test, Mean, Proportion, Z_95};
+    use crate::analysis::report::{AnalysisReport, ReportValue};
+    use crate::analysis::general_stats::evaluate_general_stats;
+    use crate::analysis::bomb_stats::evaluate_bomb_stats;
+    use clap::Parser;
+    use crate::cli::{replay, Cli, Command, OutputFormat, PlayerFilterArgs, StatsName};
+    use crate::bsw_binary_format::parse_diagnostic::{DiagnosticKind, ParseDiagnostic};
+    use crate::analysis::parsing_stats::evaluate_parsing_stats;
     use super::hand;
 
     #[test]
@@ -134,6 +110,284 @@ mod tests {
         assert_eq!(count_special_card_invariant_property::<CountBombs0_1, 8>(CountBombs0_1).property_counted[1], 4229667);
     }
 
+    #[test]
+    fn software_pdep_pext_match_bmi2() {
+        use crate::bit_ops::{pdep, pdep_software, pext, pext_software};
+        assert_eq!(pdep_software(0b101, hand!(DRAGON, PHOENIX, MAHJONG, DOG)), hand!(PHOENIX, DRAGON));
+        assert_eq!(pext_software(hand!(PHOENIX, DRAGON), hand!(DRAGON, PHOENIX, MAHJONG, DOG)), 0b101);
+        let mut state: u64 = 0x9E3779B97F4A7C15;
+        for _ in 0..10000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let (src, mask) = (state, state.rotate_left(29) & state.rotate_left(41));
+            assert_eq!(pext_software(pdep_software(src, mask), mask), src & 1u64.checked_shl(mask.count_ones()).map_or(u64::MAX, |bit| bit - 1));
+            #[cfg(target_arch = "x86_64")]
+            if is_x86_feature_detected!("bmi2") {
+                unsafe {
+                    assert_eq!(std::arch::x86_64::_pdep_u64(src, mask), pdep_software(src, mask));
+                    assert_eq!(std::arch::x86_64::_pext_u64(src, mask), pext_software(src, mask));
+                }
+            }
+            assert_eq!(pdep(src, mask), pdep_software(src, mask));
+            assert_eq!(pext(src, mask), pext_software(src, mask));
+        }
+    }
+
+    #[test]
+    fn threaded_enumeration_is_exact() {
+        //Counters of the single threaded enumeration before it was split into tasks.
+        for threads in [1, 4] {
+            let counter = count_special_card_invariant_property_with_threads::<CountLongestStraight, 8>(CountLongestStraight, threads);
+            assert_eq!((counter.hands_evaluated, counter.hands_counted), (39942474, 1420494075));
+            assert_eq!(counter.property_counted.as_slice(), [56692019, 558206516, 501553140, 211964320, 69500928, 18554880, 3629056, 393216, 0, 0, 0, 0, 0]);
+            let counter = count_special_card_sensitive_property_with_threads::<CountHandCategory, 8>(CountHandCategory, threads);
+            assert_eq!((counter.hands_evaluated, counter.hands_counted), (63188268, 1420494075));
+            assert_eq!(counter.property_counted[..8], [377348994, 73629072, 73629072, 12271512, 73629072, 12271512, 12271512, 1712304]);
+            assert_eq!(counter.property_counted[64..], [194580, 17296, 17296, 1128, 17296, 1128, 1128, 48, 17296, 1128, 1128, 48, 1128, 48, 48, 1]);
+        }
+    }
+
+    #[test]
+    fn completions_of_partial_hands() {
+        //Without fixed or excluded cards the completions are all hands.
+        let all = count_special_card_sensitive_property_with_threads::<CountHandCategory, 4>(CountHandCategory, 1);
+        let completions = count_completions_with_threads::<CountHandCategory, 4>(CountHandCategory, 0, 0, 4);
+        assert_eq!(completions.hands_counted, all.hands_counted);
+        assert_eq!(completions.hands_evaluated, 367290);
+        assert_eq!(completions.property_counted, all.property_counted);
+        //A first_8 with four aces always ends as a 14 card hand with a bomb. 34 cards are left after excluding 14 more.
+        let first_8 = hand!(ACE+YELLOW, ACE+BLUE, ACE+GREEN, ACE+RED, TWO+RED, THREE+BLUE, DOG, DRAGON);
+        let excluded = hand!(KING+YELLOW, KING+BLUE, KING+GREEN, KING+RED, QUEEN+YELLOW, QUEEN+BLUE, QUEEN+GREEN, QUEEN+RED,
+            JACK+YELLOW, JACK+BLUE, JACK+GREEN, JACK+RED, PHOENIX, MAHJONG);
+        let completions = count_completions::<CountBombs0_1, 14>(CountBombs0_1, first_8, excluded);
+        assert_eq!(completions.hands_counted, 1344904);
+        assert_eq!(completions.property_counted.as_slice(), [0, 1344904]);
+        //Only the red two, three and four are left, one of them completes the hand to a red straight bomb.
+        let fixed = hand!(FIVE+RED, SIX+RED, SEVEN+RED, EIGHT+RED, NINE+RED, TEN+RED, JACK+RED, QUEEN+RED, KING+RED, ACE+RED, DOG, DRAGON, PHOENIX);
+        let excluded = MASK_ALL & !fixed & !hand!(TWO+RED, THREE+RED, FOUR+RED);
+        let completions = count_completions_with_threads::<CountLongestStraight, 14>(CountLongestStraight, fixed, excluded, 2);
+        assert_eq!(completions.hands_counted, 3);
+        assert_eq!(completions.property_counted.iter().sum::<u64>(), 3);
+    }
+
+    #[test]
+    fn combined_properties_match_single_enumerations() {
+        let bombs = count_special_card_invariant_property_with_threads::<CountBombs0_1, 6>(CountBombs0_1, 2);
+        let straight = count_special_card_invariant_property_with_threads::<CountLongestStraight, 6>(CountLongestStraight, 2);
+        let joint = count_special_card_invariant_property_with_threads::<_, 6>(CountJoint(CountBombs0_1, CountLongestStraight), 2);
+        assert_eq!(joint.hands_counted, bombs.hands_counted);
+        assert_eq!(joint.property_counted.iter().sum::<u64>(), joint.hands_counted);
+        let (joint_bombs, joint_straight) = joint.marginals();
+        assert_eq!(joint_bombs.property_counted, bombs.property_counted);
+        assert_eq!(joint_straight.property_counted, straight.property_counted);
+        //Straight bombs of length 5 with an unrelated sixth card.
+        assert!(joint.joint(1, 4) > 0);
+        assert_eq!(joint.joint(0, 5) + joint.joint(1, 5), straight.property_counted[5]);
+
+        let category = count_special_card_sensitive_property_with_threads::<CountHandCategory, 6>(CountHandCategory, 2);
+        let sensitive_bombs = count_special_card_sensitive_property_with_threads::<CountBombs0_1, 6>(CountBombs0_1, 2);
+        let independent = count_special_card_sensitive_property_with_threads::<_, 6>(
+            CountIndependent(CountBombs0_1, CountIndependent(CountLongestStraight, CountHandCategory)), 2);
+        let (independent_bombs, rest) = independent.split();
+        let (independent_straight, independent_category) = rest.split();
+        assert_eq!(independent_bombs.property_counted, sensitive_bombs.property_counted);
+        assert_eq!(independent_straight.property_counted, straight.property_counted);
+        assert_eq!(independent_category.property_counted, category.property_counted);
+        assert_eq!(independent_category.hands_evaluated, category.hands_evaluated);
+    }
+
+    #[test]
+    fn enumeration_cache_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("tichu_enumeration_cache_{}", std::process::id()));
+        let cache = EnumerationCache::new(&dir);
+        let property = CountJoint(CountBombs0_1, CountLongestStraight);
+        let invariant = EnumerationKind::SpecialCardInvariant;
+        assert!(cache.load(&property, 5, invariant).unwrap().is_none());
+        let counted = cache.get_or_count_invariant::<_, 5>(property.clone());
+        assert_eq!(cache.path(&property, 5, invariant), dir.join("CountJoint_CountBombs0_1_CountLongestStraight_5_invariant.json"));
+        let loaded = cache.get_or_count(property.clone(), 5, invariant, |_| panic!("Stored counter is not used"));
+        assert_eq!((loaded.hands_evaluated, loaded.hands_counted), (counted.hands_evaluated, counted.hands_counted));
+        assert_eq!(loaded.property_counted, counted.property_counted);
+        //The sensitive enumeration is stored separately.
+        assert!(cache.load(&property, 5, EnumerationKind::SpecialCardSensitive).unwrap().is_none());
+
+        //A file of another property or an older version is rejected, a corrupt one is counted again.
+        std::fs::copy(cache.path(&property, 5, invariant), cache.path(&CountBombs0_1, 5, invariant)).unwrap();
+        assert_eq!(cache.load(&CountBombs0_1, 5, invariant).err().unwrap().kind(), std::io::ErrorKind::InvalidData);
+        let stored = std::fs::read_to_string(cache.path(&property, 5, invariant)).unwrap();
+        std::fs::write(cache.path(&property, 5, invariant), stored.replace("\"version\": 2", "\"version\": 1")).unwrap();
+        assert_eq!(cache.load(&property, 5, invariant).err().unwrap().kind(), std::io::ErrorKind::InvalidData);
+        std::fs::write(cache.path(&property, 5, invariant), "{").unwrap();
+        assert_eq!(cache.load(&property, 5, invariant).err().unwrap().kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(cache.get_or_count_invariant::<_, 5>(property.clone()).property_counted, counted.property_counted);
+        assert!(cache.load(&property, 5, invariant).unwrap().is_some());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        //The tables shipped with the repository.
+        let shipped = EnumerationCache::new(DEFAULT_CACHE_DIR);
+        let category = shipped.load(&CountHandCategory, 8, EnumerationKind::SpecialCardSensitive).unwrap().unwrap();
+        assert_eq!(category.hands_counted, 1420494075);
+        assert_eq!(category.property_counted.iter().sum::<u64>(), 1420494075);
+        for counter in [shipped.load(&CountBombs0_1, 14, invariant).unwrap().unwrap().proportions(), shipped.load(&CountLongestStraight, 14, invariant).unwrap().unwrap().proportions()] {
+            assert_eq!(counter.iter().map(|proportion| proportion.successes).sum::<usize>(), 5804731963800);
+        }
+        assert_eq!(shipped.load(&CountAll, 14, invariant).unwrap().unwrap().hands_evaluated, shipped.load(&CountBombs0_1, 14, invariant).unwrap().unwrap().hands_evaluated);
+    }
+
+    #[test]
+    fn monte_carlo_estimates() {
+        //Share of 14 card hands with a bomb, see enumeration_results::count_bombs_0_1.
+        let exact_rate = 294663199638.0 / 5804731963800.0;
+        let sampled = sample_property::<CountBombs0_1, 14>(CountBombs0_1, 100000, 7);
+        assert_eq!(sampled.hands_counted, 100000);
+        let (low, high) = sampled.proportions()[1].wilson_interval(3.29);
+        assert!(low < exact_rate && exact_rate < high, "{} not in [{}, {}]", exact_rate, low, high);
+        assert_eq!(sample_property::<CountBombs0_1, 14>(CountBombs0_1, 100000, 7).property_counted, sampled.property_counted);
+        assert_ne!(sample_property::<CountBombs0_1, 14>(CountBombs0_1, 100000, 8).property_counted, sampled.property_counted);
+
+        //Sampled completions agree with the exact ones.
+        let first_8 = hand!(ACE+YELLOW, ACE+BLUE, ACE+GREEN, KING+RED, TWO+RED, THREE+BLUE, DOG, DRAGON);
+        let exact = count_completions::<CountBombs0_1, 14>(CountBombs0_1, first_8, 0).proportions()[1].rate();
+        let sampled = sample_completions::<CountBombs0_1, 14>(CountBombs0_1, first_8, 0, 20000, 1);
+        let (low, high) = sampled.proportions()[1].wilson_interval(3.29);
+        assert!(low < exact && exact < high, "{} not in [{}, {}]", exact, low, high);
+
+        //Deals: the hand of one player is a random 14 card hand, and PlayersWith counts over all four hands.
+        let deals = sample_deals(CountJoint(PlayerHand(2, CountBombs0_1), PlayersWith(CountBombs0_1)), 50000, 3);
+        let (player_2, players) = deals.marginals();
+        let (low, high) = player_2.proportions()[1].wilson_interval(3.29);
+        assert!(low < exact_rate && exact_rate < high, "{} not in [{}, {}]", exact_rate, low, high);
+        assert_eq!(deals.joint(1, 0), 0);
+        let (low, high) = players.mean().confidence_interval(3.29);
+        assert!(low < 4.0 * exact_rate && 4.0 * exact_rate < high, "{} not in [{}, {}]", 4.0 * exact_rate, low, high);
+
+        let mut rng = DealRng::new(5);
+        for _ in 0..100 {
+            let deal = rng.deal_from([first_8, 0, hand!(PHOENIX), 0]);
+            assert!(deal.iter().all(|hand| hand.count_ones() == 14));
+            assert_eq!(deal.iter().fold(0, |acc, hand| acc | hand), MASK_ALL);
+            assert_eq!(deal[0] & first_8, first_8);
+            assert!(deal[2] & hand!(PHOENIX) != 0);
+        }
+    }
+
+    #[test]
+    fn counter_mean() {
+        let mut counter = Counter::new(CountLongestStraight);
+        counter.property_counted[2] = 3;
+        counter.property_counted[4] = 1;
+        counter.hands_counted = 4;
+        assert_eq!(counter.mean().mean(), 2.5);
+        assert_eq!(counter.mean().variance(), 1.0);
+        assert_eq!(counter.proportions()[2], Proportion::new(3, 4));
+    }
+
+    #[derive(Debug, Clone)]
+    struct CountBeatingStreet(CardType, u8);
+    impl PropertyRange for CountBeatingStreet {
+        type UpperBound = generic_array::typenum::U2;
+    }
+    impl SingleValueProperty for CountBeatingStreet {
+        fn count(&self, hand: &Hand) -> usize {
+            hand.legal_moves(Some(&HandType::Street(self.0, self.1)), None).iter().any(|(_, hand_type)| matches!(hand_type,
+                HandType::Street(lowest, length) | HandType::BombStreet(lowest, length) if *length == self.1 && *lowest > self.0)) as usize
+        }
+    }
+
+    #[test]
+    fn opponent_hand_probabilities_match_enumeration() {
+        use crate::bit_ops::pdep;
+        //Late in the round: 18 unseen cards, six in each hand, the Dog was passed to the partner.
+        let pool = MASK_KINGS | hand!(FIVE+RED, SIX+RED, SEVEN+RED, EIGHT+RED, NINE+RED, TEN+YELLOW, JACK+YELLOW, QUEEN+YELLOW,
+            TWO+BLUE, THREE+BLUE, FOUR+BLUE, PHOENIX, DOG, MAHJONG);
+        let my_hand = hand!(TWO+RED, THREE+RED, FOUR+RED, TEN+RED, JACK+RED, QUEEN+RED, ACE+RED, ACE+BLUE, ACE+GREEN, ACE+YELLOW, TWO+GREEN, THREE+GREEN, FOUR+GREEN, DRAGON);
+        let unseen = UnseenCards::with_seen(my_hand, MASK_ALL & !pool & !my_hand, [6, 6, 6]).with_known(SEAT_PARTNER, hand!(DOG));
+        for seat in [SEAT_LEFT, SEAT_PARTNER, SEAT_RIGHT] {
+            let bombs = unseen.seat_distribution(seat, CountBombs0_1);
+            assert_eq!(unseen.bomb_probability(seat), Proportion::new(bombs.property_counted[1] as usize, bombs.hands_counted as usize));
+            for (lowest, length) in [(SPECIAL_CARD, 5), (TWO, 5), (FOUR, 5), (SIX, 5), (THREE, 6), (NINE, 5)] {
+                let streets = unseen.seat_distribution(seat, CountBeatingStreet(lowest, length));
+                assert_eq!(unseen.beating_street_probability(seat, lowest, length), Proportion::new(streets.property_counted[1] as usize, streets.hands_counted as usize), "Street({}, {})", lowest, length);
+            }
+        }
+        assert_eq!(unseen.card_probability(SEAT_PARTNER, DOG), Proportion::new(1, 1));
+        assert_eq!(unseen.card_probability(SEAT_LEFT, DOG), Proportion::new(0, 1));
+        assert_eq!(unseen.card_probability(SEAT_PARTNER, PHOENIX), Proportion::new(5, 17));
+        assert_eq!(unseen.opponents_card_probability(PHOENIX), Proportion::new(12, 17));
+        assert_eq!(unseen.opponents_card_probability(DRAGON), Proportion::new(0, 1));
+
+        //Right after the deal, compared with sampled deals.
+        let my_hand = hand!(TWO+RED, THREE+RED, FOUR+RED, TEN+RED, JACK+RED, QUEEN+RED, ACE+RED, ACE+BLUE, ACE+GREEN, ACE+YELLOW, FIVE+GREEN, SIX+GREEN, SEVEN+BLUE, DRAGON);
+        let unseen = UnseenCards::new(my_hand);
+        let exact = unseen.bomb_probability(SEAT_LEFT);
+        assert_eq!(exact.trials, 52860229080);
+        let sampled = sample_deals_from(PlayerHand(1, CountBombs0_1), 20000, 11, |rng| rng.deal_from([my_hand, 0, 0, 0])).proportions()[1];
+        let (low, high) = sampled.wilson_interval(3.29);
+        assert!(low < exact.rate() && exact.rate() < high, "{} not in [{}, {}]", exact.rate(), low, high);
+        assert_eq!(unseen.bomb_probability(SEAT_RIGHT), exact);
+        //Either opponent, with the right opponent of player 0 as player 1 and the left one as player 3.
+        let exact = unseen.opponents_bomb_probability();
+        let sampled = sample_deals_from(CountJoint(PlayerHand(1, CountBombs0_1), PlayerHand(3, CountBombs0_1)), 20000, 12, |rng| rng.deal_from([my_hand, 0, 0, 0]));
+        let (low, high) = Proportion::new((sampled.hands_counted - sampled.joint(0, 0)) as usize, sampled.hands_counted as usize).wilson_interval(3.29);
+        assert!(low < exact.rate() && exact.rate() < high, "{} not in [{}, {}]", exact.rate(), low, high);
+
+        //Either opponent, against all distributions of a small pool.
+        let pool = MASK_KINGS | hand!(FIVE+RED, SIX+RED, SEVEN+RED, EIGHT+RED, NINE+RED, TEN+YELLOW, JACK+YELLOW, TWO+BLUE, PHOENIX);
+        let unseen = UnseenCards::with_seen(my_hand, MASK_ALL & !pool & !my_hand, [5, 3, 5]).with_known(SEAT_RIGHT, hand!(KING+RED));
+        let either = |property: &dyn Fn(Hand) -> bool| {
+            let (mut accepted, mut trials) = (0, 0);
+            let open = pool ^ hand!(KING+RED);
+            for left_bits in (0..1u64 << 12).filter(|bits| bits.count_ones() == 5) {
+                let left = pdep(left_bits, open);
+                for right_bits in (0..1u64 << 7).filter(|bits| bits.count_ones() == 4) {
+                    let right = pdep(right_bits, open ^ left) | hand!(KING+RED);
+                    trials += 1;
+                    accepted += (property(left) || property(right)) as usize;
+                }
+            }
+            Proportion::new(accepted, trials)
+        };
+        assert_eq!(unseen.opponents_bomb_probability(), either(&|hand| CountBombs0_1.count(&hand) == 1));
+        for (lowest, length) in [(SPECIAL_CARD, 5), (FOUR, 5), (SIX, 5), (TWO, 6)] {
+            assert_eq!(unseen.opponents_beating_street_probability(lowest, length), either(&|hand| CountBeatingStreet(lowest, length).count(&hand) == 1), "Street({}, {})", lowest, length);
+        }
+    }
+
+    #[test]
+    fn seeded_dealer() {
+        let mut dealer = Dealer::new(42);
+        let dealt: Vec<DealtRound> = (0..200).map(|_| dealer.deal_round()).collect();
+        let result = RoundResult { ranks: [RANK_1, RANK_2, RANK_4, RANK_3], calls: [CALL_TICHU, CALL_NONE, CALL_NONE, CALL_GRAND_TICHU], card_points_team_1: 70 };
+        for dealt_round in dealt.iter() {
+            let round = dealt_round.to_round(result).unwrap();
+            assert!(round.player_rounds.iter().all(|player_round| player_round.first_8 & !player_round.first_14 == 0));
+            assert_eq!(round.get_starting_hands(), dealt_round.get_starting_hands());
+            assert!(!GameState::new(dealt_round.get_starting_hands()).legal_actions().is_empty());
+            //The round carries the given result, not a double win.
+            assert!(round.player_rounds.iter().all(|player_round| player_round.round_score() == (170, -170)));
+        }
+        //The cards passed to the left opponent are not always the lowest.
+        let left_lowest = dealt.iter().flat_map(|dealt_round| dealt_round.exchange).filter(|cards| cards[0] < cards[1] && cards[0] < cards[2]).count();
+        assert!((200..=333).contains(&left_lowest), "{} of 800 passes to the left are the lowest card", left_lowest);
+        //Same seed, same deals.
+        let mut dealer = Dealer::new(42);
+        assert!(dealt.iter().all(|dealt_round| dealer.deal_round() == *dealt_round));
+        assert_ne!(Dealer::new(43).deal(), Dealer::new(42).deal());
+
+        let mut dealer = Dealer::new(7).with_first_8(PLAYER_0, hand!(DRAGON, PHOENIX)).with_first_14(PLAYER_2, MASK_ACES).with_first_14(PLAYER_2, hand!(DOG));
+        for _ in 0..50 {
+            let deal = dealer.deal();
+            assert_eq!(deal.first_8[0] & hand!(DRAGON, PHOENIX), hand!(DRAGON, PHOENIX));
+            assert_eq!(deal.first_14[2] & (MASK_ACES | hand!(DOG)), MASK_ACES | hand!(DOG));
+            assert_eq!(deal.first_14.iter().fold(0, |acc, hand| acc | hand), MASK_ALL);
+            assert!(deal.first_8.iter().all(|hand| hand.count_ones() == 8));
+            //Passing a card that is not in the hand is rejected.
+            let exchange = [[deal.first_14[1].get_lsb_card(), 0, 0]; 4];
+            assert!(DealtRound { deal, exchange }.to_round(result).is_err());
+        }
+    }
+
     #[test]
     fn hand_type_pairs() {
         assert!(matches!(hand!(TWO+RED, TWO+BLUE).hand_type(), Some(HandType::Pairs(card)) if card == TWO));
@@ -263,4 +517,793 @@ mod tests {
         assert!(matches!(hand!(TWO+RED, TWO+YELLOW, FOUR+BLUE, FOUR+YELLOW, KING+RED, KING+BLUE).hand_type(), None));
         assert!(matches!(hand!(TWO+RED, TWO+YELLOW, THREE+RED, THREE+YELLOW, FOUR+BLUE, FOUR+GREEN, FIVE+YELLOW, PHOENIX, SIX+BLUE, SIX+YELLOW).hand_type(), Some(HandType::PairStreet(card, length)) if card == TWO && length == 10));
     }
-}
\ No newline at end of file
+
+    fn random_hand(seed: &mut u64, cards: u32) -> Hand {
+        let mut hand = 0u64;
+        while hand.count_ones() < cards {
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            hand |= hand!((*seed % 64) as CardIndex) & MASK_ALL;
+        }
+        hand
+    }
+
+    fn brute_force_legal_moves(hand: Hand, top: Option<&HandType>) -> Vec<(Hand, HandType)> {
+        let beats = |hand_type: &HandType, top: &HandType| {
+            let (trick_type, top_trick_type) = (hand_type.get_trick_type(), top.get_trick_type());
+            if *hand_type == HandType::Dog || *top == HandType::Dog {
+                return false;
+            }
+            if trick_type >= TRICK_BOMB4 {
+                return top_trick_type < TRICK_BOMB4 || trick_type > top_trick_type || trick_type == top_trick_type && hand_type.is_bigger_than_same_handtype(top);
+            }
+            trick_type == top_trick_type && hand_type.is_bigger_than_same_handtype(top)
+        };
+        let mut res = Vec::new();
+        let mut subset = hand;
+        while subset != 0 {
+            if let Some(hand_type) = subset.hand_type() && top.is_none_or(|top| beats(&hand_type, top)) {
+                res.push((subset, hand_type.on_top_of(top)));
+            }
+            subset = (subset - 1) & hand;
+        }
+        res
+    }
+
+    #[test]
+    fn legal_moves_match_brute_force() {
+        let tops = [None, Some(HandType::Singleton(SINGLETON_MAHJONG, MAHJONG)), Some(HandType::Singleton(SINGLETON_PHOENIX_LEAD, PHOENIX)), Some(HandType::Singleton(2 * KING + 1, PHOENIX)),
+            Some(HandType::Singleton(SINGLETON_DRAGON, DRAGON)), Some(HandType::Singleton(2 * NINE, NINE + RED)),
+            Some(HandType::Pairs(SIX)), Some(HandType::Triplets(THREE)), Some(HandType::FullHouse(ACE, FIVE)), Some(HandType::PairStreet(TWO, 4)), Some(HandType::PairStreet(FIVE, 6)),
+            Some(HandType::Street(SPECIAL_CARD, 5)), Some(HandType::Street(SIX, 6)), Some(HandType::Bomb4(SEVEN)), Some(HandType::BombStreet(THREE, 5)), Some(HandType::Dog)];
+        let mut seed = 0x2545F4914F6CDD1Du64;
+        //Phoenix below an ace high street, phoenix next to the mahjong, two true pairs with the phoenix, pair street gaps.
+        let edge_hands = [hand!(JACK + RED, QUEEN + BLUE, KING + GREEN, ACE + RED, ACE + BLUE, PHOENIX), hand!(TWO + RED, THREE + BLUE, FOUR + GREEN, FIVE + RED, PHOENIX),
+            hand!(MAHJONG, THREE + BLUE, FOUR + GREEN, FIVE + RED, SIX + YELLOW, PHOENIX), hand!(NINE + RED, NINE + BLUE, FOUR + GREEN, FOUR + RED, TEN + RED, PHOENIX),
+            hand!(FIVE + RED, FIVE + BLUE, SIX + GREEN, SEVEN + RED, SEVEN + GREEN, EIGHT + RED, PHOENIX)];
+        for i in 0..40 + edge_hands.len() {
+            let hand = if i < 40 {
+                random_hand(&mut seed, 13) | if i % 2 == 0 { hand!(PHOENIX) } else { random_hand(&mut seed, 1) }
+            } else {
+                edge_hands[i - 40]
+            };
+            for top in tops.iter() {
+                let mut moves = hand.legal_moves(top.as_ref(), None);
+                let mut expected = brute_force_legal_moves(hand, top.as_ref());
+                moves.sort_by_key(|(cards, _)| *cards);
+                expected.sort_by_key(|(cards, _)| *cards);
+                assert_eq!(moves, expected, "Hand {} on top {:?}", hand.pretty_print(), top);
+            }
+        }
+    }
+
+    #[test]
+    fn legal_moves_wish() {
+        let hand = hand!(SEVEN+RED, SEVEN+BLUE, EIGHT+YELLOW, KING+GREEN, PHOENIX, DOG);
+        //Leading with a wish for a seven: every play has to contain a seven.
+        let moves = hand.legal_moves(None, Some(SEVEN));
+        assert!(!moves.is_empty() && moves.iter().all(|(cards, _)| cards & MASK_SEVENS != 0));
+        //On a pair of nines, the seven can not be played, so the wish does not restrict.
+        let moves = hand.legal_moves(Some(&HandType::Pairs(NINE)), Some(SEVEN));
+        assert_eq!(moves, vec![(hand!(KING+GREEN, PHOENIX), HandType::Pairs(KING))]);
+        //Bombs fulfill the wish as well.
+        let hand = hand!(SEVEN+RED, SEVEN+BLUE, SEVEN+YELLOW, SEVEN+GREEN, ACE+RED);
+        assert_eq!(hand.legal_moves(Some(&HandType::Singleton(2 * KING, KING + YELLOW)), Some(SEVEN)), vec![(MASK_SEVENS, HandType::Bomb4(SEVEN))]);
+    }
+
+    fn random_deal(seed: &mut u64) -> [Hand; 4] {
+        let mut cards: Vec<CardIndex> = (0..64).filter(|card| hand!(*card) & MASK_ALL != 0).collect();
+        for i in (1..cards.len()).rev() {
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            cards.swap(i, (*seed % (i as u64 + 1)) as usize);
+        }
+        let mut hands = [0u64; 4];
+        for (i, card) in cards.iter().enumerate() {
+            hands[i / 14] |= hand!(*card);
+        }
+        hands
+    }
+
+    #[test]
+    fn game_state_random_playouts() {
+        let mut seed = 0x9E3779B97F4A7C15u64;
+        for _ in 0..200 {
+            let mut state = GameState::new(random_deal(&mut seed));
+            while !state.is_terminal() {
+                let actions = state.legal_actions();
+                assert!(!actions.is_empty());
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                state.apply(actions[(seed % actions.len() as u64) as usize]).unwrap();
+                assert_eq!(state.hands.iter().fold(0u64, |acc, hand| acc | hand).count_ones(), state.hands.iter().map(|hand| hand.count_ones()).sum::<u32>());
+            }
+            assert!(state.apply(Action::Pass).is_err());
+            let (ranks, scores, double_win) = state.round_result().unwrap();
+            if double_win {
+                assert_eq!(scores, [0; 4]);
+            } else {
+                assert_eq!(scores.iter().sum::<Score>(), 100);
+                let mut sorted_ranks = ranks;
+                sorted_ranks.sort();
+                assert_eq!(sorted_ranks, [RANK_1, RANK_2, RANK_3, RANK_4]);
+            }
+        }
+    }
+
+    #[test]
+    fn game_state_dog_and_dragon() {
+        let mut state = GameState::new([hand!(MAHJONG, DOG, FIVE+YELLOW), hand!(DRAGON, KING+YELLOW), hand!(TWO+YELLOW, TEN+YELLOW), hand!(THREE+YELLOW, SEVEN+YELLOW)]);
+        assert_eq!(state.turn, PLAYER_0);
+        assert!(!state.legal_actions().contains(&Action::Pass));
+        //The dog passes the lead to the partner.
+        state.apply(Action::Play { cards: hand!(DOG), hand_type: HandType::Dog, wish: None }).unwrap();
+        assert_eq!((state.turn, state.top), (PLAYER_2, None));
+        state.apply(Action::Play { cards: hand!(TEN+YELLOW), hand_type: HandType::Singleton(2 * TEN, TEN+YELLOW), wish: None }).unwrap();
+        state.apply(Action::Pass).unwrap();
+        state.apply(Action::Pass).unwrap();
+        state.apply(Action::Play { cards: hand!(DRAGON), hand_type: HandType::Singleton(SINGLETON_DRAGON, DRAGON), wish: None }).unwrap();
+        //Nobody can beat the dragon, so the trick has to be gifted to an opponent.
+        assert_eq!(state.legal_actions(), vec![Action::Pass]);
+        state.apply(Action::Pass).unwrap();
+        state.apply(Action::Pass).unwrap();
+        state.apply(Action::Pass).unwrap();
+        assert_eq!((state.turn, state.dragon_gift_pending), (PLAYER_1, true));
+        assert_eq!(state.legal_actions(), vec![Action::GiftDragon(PLAYER_2), Action::GiftDragon(PLAYER_0)]);
+        assert!(state.apply(Action::GiftDragon(PLAYER_3)).is_err());
+        state.apply(Action::GiftDragon(PLAYER_2)).unwrap();
+        assert_eq!(state.points, [0, 0, 35, 0]);
+        assert_eq!(state.turn, PLAYER_1);
+        state.apply(Action::Play { cards: hand!(KING+YELLOW), hand_type: HandType::Singleton(2 * KING, KING+YELLOW), wish: None }).unwrap();
+        assert_eq!((state.ranks[PLAYER_1 as usize], state.turn), (RANK_1, PLAYER_2));
+    }
+
+    #[test]
+    fn game_state_interrupting_bomb() {
+        let sevens = hand!(SEVEN+YELLOW, SEVEN+BLUE, SEVEN+GREEN, SEVEN+RED);
+        let hands = [hand!(MAHJONG, TWO+YELLOW), hand!(TEN+YELLOW, THREE+YELLOW), sevens | hand!(FIVE+YELLOW), hand!(KING+YELLOW, FOUR+YELLOW)];
+        let singleton = |card: CardIndex| Action::Play { cards: hand!(card), hand_type: hand!(card).hand_type().unwrap(), wish: None };
+        let mut state = GameState::new(hands);
+        assert!(state.apply_bomb(PLAYER_2, sevens).is_err()); //Nothing to bomb before the lead
+        state.apply(singleton(MAHJONG)).unwrap();
+        assert!(matches!(state.apply_bomb(PLAYER_1, hand!(TEN+YELLOW)), Err(IllegalActionError::NotABomb { player: PLAYER_1, .. })));
+        //Player 2 bombs before player 1 had its turn, the turn moves on from the bomb.
+        state.apply_bomb(PLAYER_2, sevens).unwrap();
+        assert_eq!((state.turn, state.top, state.passes), (PLAYER_3, Some(HandType::Bomb4(SEVEN)), 0));
+        assert_eq!(state.trick.trick_log, vec![(PLAYER_0, hand!(MAHJONG)), (PLAYER_2, sevens)]);
+        for _ in 0..3 {
+            state.apply(Action::Pass).unwrap();
+        }
+        assert_eq!((state.turn, state.top), (PLAYER_2, None));
+        state.apply(singleton(FIVE+YELLOW)).unwrap();
+        state.apply(singleton(KING+YELLOW)).unwrap();
+        state.apply(Action::Pass).unwrap();
+        state.apply(Action::Pass).unwrap();
+        state.apply(singleton(FOUR+YELLOW)).unwrap();
+        state.apply(Action::Pass).unwrap();
+        state.apply(Action::Pass).unwrap();
+        state.apply(singleton(TWO+YELLOW)).unwrap();
+        assert!(state.apply_bomb(PLAYER_1, hand!(TEN+YELLOW)).is_err());
+
+        //The replayed round log agrees with the interrupted state.
+        let trick = |trick_log: Vec<(PlayerIDInternal, Hand)>| Trick { trick_type: TRICK_SINGLETON, trick_log };
+        let (round, round_log) = round_with_log(hands, vec![
+            trick(vec![(PLAYER_0, hand!(MAHJONG)), (PLAYER_2, sevens)]),
+            trick(vec![(PLAYER_2, hand!(FIVE+YELLOW)), (PLAYER_3, hand!(KING+YELLOW))]),
+            trick(vec![(PLAYER_3, hand!(FOUR+YELLOW))]),
+            trick(vec![(PLAYER_0, hand!(TWO+YELLOW))]),
+        ], None);
+        assert_eq!(round_log.play_round(&round).ok(), state.round_result());
+        assert_eq!(state.round_result().unwrap().0, [RANK_3, RANK_4, RANK_1, RANK_2]);
+    }
+
+    fn round_with_log(hands: [Hand; 4], tricks: Vec<Trick>, mahjong_wish: Option<CardType>) -> (Round, RoundLog) {
+        let mut round = Round::default();
+        for (player_round, hand) in round.player_rounds.iter_mut().zip(hands) {
+            player_round.first_14 = hand ^ hand!(PHOENIX); //All exchange cards are PHOENIX with empty extras, which toggles it once in final_14.
+        }
+        let mut round_log = RoundLog { mahjong_wish, ..Default::default() };
+        for trick in tricks.iter() {
+            trick.serialize_into(&mut round_log);
+        }
+        (round, round_log)
+    }
+
+    #[test]
+    fn round_log_wish_integrity() {
+        let hands = [hand!(MAHJONG, TWO+YELLOW), hand!(SEVEN+YELLOW, THREE+YELLOW), hand!(EIGHT+YELLOW, FOUR+YELLOW), hand!(NINE+YELLOW, FIVE+YELLOW)];
+        let trick = |trick_log: Vec<(PlayerIDInternal, Hand)>| Trick { trick_type: TRICK_SINGLETON, trick_log };
+        let (round, round_log) = round_with_log(hands, vec![trick(vec![(PLAYER_0, hand!(MAHJONG)), (PLAYER_1, hand!(SEVEN+YELLOW)), (PLAYER_2, hand!(EIGHT+YELLOW)), (PLAYER_3, hand!(NINE+YELLOW))])], Some(SEVEN));
+        assert!(round_log.integrity_check(&round).is_ok());
+        //Player 1 passes while holding the wished seven.
+        let (round, round_log) = round_with_log(hands, vec![trick(vec![(PLAYER_0, hand!(MAHJONG)), (PLAYER_2, hand!(EIGHT+YELLOW))])], Some(SEVEN));
+        assert!(matches!(round_log.integrity_check(&round), Err(RoundLogIntegrityError::WishNotFulfilled { trick_num: 0, move_idx: 1, player: PLAYER_1, wish: SEVEN })));
+        //Player 1 plays another card instead of the wished seven.
+        let (round, round_log) = round_with_log(hands, vec![trick(vec![(PLAYER_0, hand!(MAHJONG)), (PLAYER_1, hand!(THREE+YELLOW))])], Some(SEVEN));
+        assert!(matches!(round_log.integrity_check(&round), Err(RoundLogIntegrityError::WishNotFulfilled { trick_num: 0, move_idx: 1, player: PLAYER_1, wish: SEVEN })));
+        //Without a wish, the same log is fine.
+        let (round, round_log) = round_with_log(hands, vec![trick(vec![(PLAYER_0, hand!(MAHJONG)), (PLAYER_1, hand!(THREE+YELLOW))])], Some(SPECIAL_CARD));
+        assert!(round_log.integrity_check(&round).is_ok());
+        //Player 3 can only beat the ace with the bomb of sevens and passes, the wish does not force the bomb.
+        let hands = [hand!(MAHJONG, TWO+YELLOW), hand!(THREE+YELLOW, FOUR+YELLOW), hand!(ACE+YELLOW, NINE+YELLOW), MASK_SEVENS | hand!(FIVE+YELLOW)];
+        let (round, round_log) = round_with_log(hands, vec![trick(vec![(PLAYER_0, hand!(MAHJONG)), (PLAYER_1, hand!(THREE+YELLOW)), (PLAYER_2, hand!(ACE+YELLOW))])], Some(SEVEN));
+        assert!(round_log.integrity_check(&round).is_ok());
+    }
+
+    #[test]
+    fn phoenix_singleton_values() {
+        let phoenix = hand!(PHOENIX).hand_type().unwrap();
+        let single = |card: CardIndex| hand!(card).hand_type().unwrap();
+        //A led phoenix counts as 1.5: a two beats it, the mahjong does not.
+        assert_eq!(phoenix, HandType::Singleton(SINGLETON_PHOENIX_LEAD, PHOENIX));
+        assert!(single(TWO + RED).is_bigger_than_same_handtype(&phoenix));
+        assert!(!single(MAHJONG).is_bigger_than_same_handtype(&phoenix));
+        //A phoenix on a king is beaten by an ace, but not by another king.
+        let phoenix_on_king = phoenix.on_top_of(Some(&single(KING + BLUE)));
+        assert_eq!(phoenix_on_king, HandType::Singleton(2 * KING + 1, PHOENIX));
+        assert!(!single(KING + RED).is_bigger_than_same_handtype(&phoenix_on_king));
+        assert!(single(ACE + RED).is_bigger_than_same_handtype(&phoenix_on_king));
+        assert!(single(DRAGON).is_bigger_than_same_handtype(&phoenix.on_top_of(Some(&single(ACE + RED)))));
+        //The phoenix beats every singleton but the dragon.
+        assert!(phoenix.is_bigger_than_same_handtype(&single(ACE + RED)));
+        assert!(!phoenix.is_bigger_than_same_handtype(&single(DRAGON)));
+        assert!(!single(ACE + RED).is_bigger_than_same_handtype(&single(DRAGON)));
+        assert_eq!(hand!(ACE + RED, KING + BLUE, PHOENIX).legal_moves(Some(&single(KING + GREEN)), None),
+            vec![(hand!(PHOENIX), HandType::Singleton(2 * KING + 1, PHOENIX)), (hand!(ACE + RED), single(ACE + RED))]);
+    }
+
+    #[test]
+    fn can_beat_all_hand_types() {
+        let dragon = HandType::Singleton(SINGLETON_DRAGON, DRAGON);
+        let ace = HandType::Singleton(2 * ACE, ACE + RED);
+        let street = HandType::Street(TWO, 5);
+        assert!(HandType::Bomb4(THREE).can_beat(&HandType::Bomb4(TWO)) && !HandType::Bomb4(TWO).can_beat(&HandType::Bomb4(THREE)));
+        assert!(HandType::BombStreet(TWO, 5).can_beat(&HandType::Bomb4(ACE)) && !HandType::Bomb4(ACE).can_beat(&HandType::BombStreet(TWO, 5)));
+        assert!(HandType::BombStreet(TWO, 6).can_beat(&HandType::BombStreet(NINE, 5)) && !HandType::BombStreet(NINE, 5).can_beat(&HandType::BombStreet(TWO, 6)));
+        assert!(HandType::BombStreet(THREE, 5).can_beat(&HandType::BombStreet(TWO, 5)) && !HandType::BombStreet(TWO, 5).can_beat(&HandType::BombStreet(TWO, 5)));
+        for bomb in [HandType::Bomb4(TWO), HandType::BombStreet(TWO, 5)] {
+            assert!(bomb.can_beat(&dragon) && bomb.can_beat(&street) && bomb.can_beat(&HandType::FullHouse(ACE, KING)));
+            assert!(!dragon.can_beat(&bomb) && !street.can_beat(&bomb) && !bomb.can_beat(&HandType::Dog) && !HandType::Dog.can_beat(&bomb));
+        }
+        assert!(dragon.can_beat(&ace) && !ace.can_beat(&dragon) && !HandType::Singleton(SINGLETON_PHOENIX_LEAD, PHOENIX).can_beat(&dragon));
+        assert!(!street.can_beat(&ace) && !HandType::Street(THREE, 6).can_beat(&street) && HandType::Street(THREE, 5).can_beat(&street));
+        assert!(!HandType::Pairs(ACE).can_beat(&HandType::Triplets(TWO)) && !HandType::Dog.can_beat(&HandType::Dog));
+    }
+
+    fn minimax(state: &GameState) -> Score {
+        if let Some((ranks, card_points, double_win)) = state.round_result() {
+            let team_1_first = ranks[PLAYER_0 as usize] == RANK_1 || ranks[PLAYER_2 as usize] == RANK_1;
+            return match (double_win, team_1_first) {
+                (true, true) => 200,
+                (true, false) => -200,
+                _ => card_points[0] + card_points[2] - card_points[1] - card_points[3],
+            };
+        }
+        let values = state.legal_actions().into_iter().map(|action| {
+            let mut child = state.clone();
+            child.apply_unchecked(action);
+            minimax(&child)
+        });
+        if is_team_1(state.turn) { values.max().unwrap() } else { values.min().unwrap() }
+    }
+
+    #[test]
+    fn double_dummy_solver_matches_minimax() {
+        let mut seed = 0xD1B54A32D192ED03u64;
+        for _ in 0..12 {
+            let mut state = GameState::new(random_deal(&mut seed));
+            while state.hands.iter().map(|hand| hand.count_ones()).sum::<u32>() > 10 && !state.is_terminal() {
+                let actions = state.legal_actions();
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                state.apply_unchecked(actions[(seed % actions.len() as u64) as usize]);
+            }
+            let result = DoubleDummySolver::new([CALL_NONE; 4]).solve(&state).unwrap();
+            assert_eq!(result.value, minimax(&state), "{:?}", state);
+            //Entries pushed out of a tiny table only cost time, never the value.
+            assert_eq!(DoubleDummySolver::with_limits([CALL_NONE; 4], 8, DEFAULT_NODE_BUDGET).solve(&state).unwrap().value, result.value);
+            //The principal variation reaches the solved outcome.
+            let mut end = state.clone();
+            for action in result.principal_variation.iter() {
+                end.apply(*action).unwrap();
+            }
+            assert_eq!(end.round_result().unwrap(), (result.ranks, result.card_points, result.double_win));
+        }
+    }
+
+    #[test]
+    fn double_dummy_solver_tichu_call() {
+        //Player 0 could go out first with the pair of aces, but player 2 called tichu. Leading the mahjong instead lets player 2 win the trick
+        //with the king and go out first with the three.
+        let hands = [hand!(MAHJONG, ACE+RED, ACE+BLUE), hand!(SIX+YELLOW, NINE+RED), hand!(THREE+YELLOW, KING+RED), hand!(FOUR+YELLOW, FIVE+RED)];
+        let with_call = DoubleDummySolver::new([CALL_NONE, CALL_NONE, CALL_TICHU, CALL_NONE]).solve(&GameState::new(hands)).unwrap();
+        let without_call = DoubleDummySolver::new([CALL_NONE; 4]).solve(&GameState::new(hands)).unwrap();
+        assert_eq!(with_call.ranks[PLAYER_2 as usize], RANK_1);
+        assert!(with_call.value >= 100 && with_call.value - without_call.value <= 200);
+    }
+
+    #[test]
+    fn double_dummy_solver_node_budget() {
+        let mut seed = 0x2545F4914F6CDD1Du64;
+        let state = GameState::new(random_deal(&mut seed));
+        //A full round does not fit into a small budget, the result is unknown instead of a guess.
+        let mut solver = DoubleDummySolver::with_limits([CALL_NONE; 4], 1 << 10, 100_000);
+        assert!(solver.solve(&state).is_none());
+        assert_eq!(solver.nodes, 100_000);
+        //The budget counts per solve call, a small endgame is solved afterwards.
+        let hands = [hand!(MAHJONG, ACE+RED, ACE+BLUE), hand!(SIX+YELLOW, NINE+RED), hand!(THREE+YELLOW, KING+RED), hand!(FOUR+YELLOW, FIVE+RED)];
+        let expected = DoubleDummySolver::new([CALL_NONE; 4]).solve(&GameState::new(hands)).unwrap();
+        assert_eq!(solver.solve(&GameState::new(hands)).unwrap().value, expected.value);
+    }
+
+    //Plays a random round with GameState and records it as a RoundLog, together with the state before every play.
+    fn random_round_log(seed: &mut u64) -> (Round, RoundLog, Vec<GameState>, Vec<(PlayerIDInternal, Action)>, GameState) {
+        let hands = random_deal(seed);
+        let mut state = GameState::new(hands);
+        let mut tricks: Vec<Trick> = Vec::new();
+        let mut states_before_plays = Vec::new();
+        let mut actions_played = Vec::new();
+        let (mut mahjong_wish, mut dragon_player_gift) = (None, None);
+        while !state.is_terminal() {
+            let actions = state.legal_actions();
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            let action = actions[(*seed % actions.len() as u64) as usize];
+            match action {
+                Action::Play { cards, hand_type, wish } => {
+                    states_before_plays.push(state.clone());
+                    if state.trick.trick_log.is_empty() {
+                        tricks.push(Trick { trick_type: hand_type.get_trick_type(), trick_log: Vec::new() });
+                    }
+                    tricks.last_mut().unwrap().trick_log.push((state.turn, cards));
+                    if cards & hand!(MAHJONG) != 0u64 {
+                        mahjong_wish = Some(wish.unwrap_or(SPECIAL_CARD));
+                    }
+                }
+                Action::GiftDragon(player) => dragon_player_gift = Some(player),
+                Action::Pass => {}
+            }
+            actions_played.push((state.turn, action));
+            state.apply(action).unwrap();
+        }
+        let (round, mut round_log) = round_with_log(hands, tricks, mahjong_wish);
+        round_log.dragon_player_gift = dragon_player_gift;
+        (round, round_log, states_before_plays, actions_played, state)
+    }
+
+    #[test]
+    fn round_log_states_match_game_state() {
+        let mut seed = 0x94D049BB133111EBu64;
+        for _ in 0..100 {
+            let (round, round_log, states_before_plays, _, end_state) = random_round_log(&mut seed);
+            assert!(round_log.integrity_check(&round).is_ok());
+            let states: Vec<_> = round_log.states(&round).collect();
+            assert_eq!(states.len(), states_before_plays.len());
+            for ((round_state, (player, hand)), game_state) in states.iter().zip(states_before_plays.iter()) {
+                assert_eq!(*player, game_state.turn);
+                assert_eq!(round_state.hands, game_state.hands);
+                assert_eq!(round_state.played_cards, MASK_ALL ^ game_state.hands.iter().fold(0u64, |acc, hand| acc | hand));
+                assert_eq!((round_state.top, round_state.wish, round_state.ranks, round_state.points), (game_state.top, game_state.wish, game_state.ranks, game_state.points));
+                assert_eq!(round_state.trick.trick_log, game_state.trick.trick_log);
+                assert_eq!(round_state.leader, game_state.trick.trick_log.first().map_or(*player, |(leader, _)| *leader));
+                assert_eq!(hand & game_state.hands[*player as usize], *hand);
+            }
+            let (ranks, scores, double_win) = round_log.play_round(&round).unwrap();
+            assert_eq!(end_state.round_result().unwrap(), (ranks, scores, double_win));
+        }
+    }
+
+    #[test]
+    fn round_log_turns_match_game_state() {
+        let mut seed = 0xBF58476D1CE4E5B9u64;
+        for _ in 0..100 {
+            let (round, round_log, states_before_plays, actions_played, _) = random_round_log(&mut seed);
+            let turns: Vec<(PlayerIDInternal, TurnAction)> = round_log.turns(&round).into_iter().flatten().filter(|(_, turn_action)| *turn_action != TurnAction::Skipped).collect();
+            let mut states_before_plays = states_before_plays.iter();
+            let mut expected: Vec<(PlayerIDInternal, TurnAction)> = Vec::new();
+            for (player, action) in actions_played {
+                match action {
+                    Action::Play { cards, hand_type, .. } => {
+                        //The log can not tell passes followed by a bomb in turn from a bomb out of turn, it reads the bomb as an interrupt.
+                        let passes = states_before_plays.next().unwrap().passes as usize;
+                        if hand_type.get_trick_type() >= TRICK_BOMB4 && passes > 0 {
+                            expected.truncate(expected.len() - passes);
+                            expected.push((player, TurnAction::Interrupt(cards)));
+                        } else {
+                            expected.push((player, TurnAction::Play(cards)));
+                        }
+                    }
+                    Action::Pass => expected.push((player, TurnAction::Pass)),
+                    Action::GiftDragon(gift_player) => expected.push((player, TurnAction::GiftDragon(gift_player))),
+                }
+            }
+            assert_eq!(turns, expected);
+        }
+        //A bomb on the own move is an interrupt, finished players are skipped.
+        let hands = [hand!(MAHJONG), hand!(TWO+YELLOW, TWO+BLUE, TWO+GREEN, TWO+RED, FIVE+RED), hand!(THREE+YELLOW, ACE+RED), hand!(FOUR+YELLOW, KING+RED)];
+        let trick = Trick { trick_type: TRICK_SINGLETON, trick_log: vec![(PLAYER_0, hand!(MAHJONG)), (PLAYER_1, hand!(FIVE+RED)), (PLAYER_1, MASK_TWOS)] };
+        let (round, round_log) = round_with_log(hands, vec![trick], Some(SPECIAL_CARD));
+        assert_eq!(round_log.turns(&round), vec![vec![(PLAYER_0, TurnAction::Play(hand!(MAHJONG))), (PLAYER_1, TurnAction::Play(hand!(FIVE+RED))), (PLAYER_1, TurnAction::Interrupt(MASK_TWOS)),
+            (PLAYER_2, TurnAction::Pass), (PLAYER_3, TurnAction::Pass), (PLAYER_0, TurnAction::Skipped)]]);
+        //Player 3 bombs the mahjong out of turn, the interrupt is attributed to player 3 and players 1 and 2 never had their turn.
+        let hands = [hand!(MAHJONG, NINE+YELLOW), hand!(FIVE+RED, SIX+RED), hand!(THREE+YELLOW, ACE+RED), MASK_TWOS | hand!(FOUR+YELLOW)];
+        let trick = Trick { trick_type: TRICK_SINGLETON, trick_log: vec![(PLAYER_0, hand!(MAHJONG)), (PLAYER_3, MASK_TWOS)] };
+        let (round, round_log) = round_with_log(hands, vec![trick], Some(SPECIAL_CARD));
+        assert_eq!(round_log.turns(&round), vec![vec![(PLAYER_0, TurnAction::Play(hand!(MAHJONG))), (PLAYER_3, TurnAction::Interrupt(MASK_TWOS)),
+            (PLAYER_0, TurnAction::Pass), (PLAYER_1, TurnAction::Pass), (PLAYER_2, TurnAction::Pass)]]);
+    }
+
+    #[test]
+    fn bsw_parse_errors() {
+        let dir = std::env::temp_dir().join(format!("tichu_bsw_parse_errors_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("Spiel_1.csv"), "1;Anna;0;1\n1;Ben;1;0\n1;Cleo;2;1\n1;Dan;3;0\nx;Anna;0;1\n2;Ben;1;0\n2;Cleo;2;1\n2;Dan;3;0\n").unwrap();
+        std::fs::write(dir.join("Runde_1.csv"), "1;1;0;;1;0;0;0;gizHsF2!;gizHsF2tpAaDkK;giz;OS3;OS3HsF2tpAaDkK\n").unwrap();
+        let dir_str = dir.display().to_string();
+
+        let err = DataBase::from_bsw_dir(&dir_str, ParseMode::Strict).err().unwrap();
+        assert!(err.file.ends_with("Spiel_1.csv"));
+        assert_eq!((err.line, err.column, err.kind), (5, 1, BswParseErrorKind::InvalidNumber("x".to_string())));
+
+        //Lenient: the broken line and with it its game are dropped, the round with the broken card is excluded and with it the whole game.
+        let malformed_lines = |database: &DataBase| -> Vec<BswParseError> {
+            database.diagnostics.iter().filter_map(|diagnostic| match &diagnostic.kind {
+                DiagnosticKind::MalformedLine(err) => Some(err.clone()),
+                _ => None,
+            }).collect()
+        };
+        let database = DataBase::from_bsw_dir(&dir_str, ParseMode::Lenient).unwrap();
+        let errors = malformed_lines(&database);
+        assert_eq!(errors.len(), 3);
+        assert_eq!((errors[1].line, errors[1].kind.clone()), (9, BswParseErrorKind::MissingField));
+        assert!(errors[2].file.ends_with("Runde_1.csv"));
+        assert_eq!((errors[2].line, errors[2].column, errors[2].kind.clone()), (1, 9, BswParseErrorKind::InvalidCard('!')));
+        assert!(database.diagnostics_of_game(1).all(|diagnostic| diagnostic.round == Some(0) && diagnostic.excludes_round() || matches!(diagnostic.kind, DiagnosticKind::GameDeleted)));
+        assert!(database.games.is_empty());
+        assert_eq!(database.players.len(), 4);
+
+        //A dropped line in the middle only loses its own game, the games after it are still read.
+        std::fs::write(dir.join("Spiel_1.csv"), "1;Anna;0;1\n1;Ben;1;0\n1;Cleo;2;1\n1;Dan;3;0\n2;Ben;1;0\n2;Cleo;2;1\n2;Dan;3;0\n3;Anna;0;0\n3;Ben;1;1\n3;Cleo;2;0\n3;Dan;3;1\n4;Anna;0;1\n4;Ben;1;0\n4;Cleo;2;1\n4;Dan;3;0\n").unwrap();
+        std::fs::write(dir.join("Runde_1.csv"), "").unwrap();
+        let err = DataBase::from_bsw_dir(&dir_str, ParseMode::Strict).err().unwrap();
+        assert_eq!((err.line, err.kind.clone()), (8, BswParseErrorKind::GameIdMismatch { expected: 2, found: 3 }));
+        let database = DataBase::from_bsw_dir(&dir_str, ParseMode::Lenient).unwrap();
+        assert_eq!(malformed_lines(&database), vec![err]);
+        let mut game_ids: Vec<u32> = database.games.iter().map(|game| game.original_bsw_id).collect();
+        game_ids.sort();
+        assert_eq!(game_ids, vec![1, 3, 4]);
+
+        assert!(matches!(DataBase::from_bsw_dir(&dir.join("missing").display().to_string(), ParseMode::Lenient).err().unwrap().kind, BswParseErrorKind::Io(_)));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn bsw_corrupt_lines_drop_only_their_game() {
+        let dir = std::env::temp_dir().join(format!("tichu_bsw_corrupt_lines_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        //Game 2 has a corrupt Spiel line, round 1 of game 1 has a rank out of range which used to spill into the card score bits.
+        std::fs::write(dir.join("Spiel_1.csv"), "1;Anna;0;1\n1;Ben;1;0\n1;Cleo;2;1\n1;Dan;3;0\n2;Anna;0;1\n2;Ben;1;x\n2;Cleo;2;1\n2;Dan;3;0\n3;Anna;0;0\n3;Ben;1;1\n3;Cleo;2;0\n3;Dan;3;1\n").unwrap();
+        std::fs::write(dir.join("Runde_1.csv"), "1;1;0;;99;0;0;0;gizHsF2t;gizHsF2tpAaDkK;giz;OS3;OS3HsF2tpAaDkK\n").unwrap();
+        let dir_str = dir.display().to_string();
+
+        let err = DataBase::from_bsw_dir(&dir_str, ParseMode::Strict).err().unwrap();
+        assert_eq!((err.line, err.column, err.kind), (6, 4, BswParseErrorKind::InvalidNumber("x".to_string())));
+
+        let database = DataBase::from_bsw_dir(&dir_str, ParseMode::Lenient).unwrap();
+        let errors: Vec<(Option<u32>, Option<usize>, BswParseError)> = database.diagnostics.iter().filter_map(|diagnostic| match &diagnostic.kind {
+            DiagnosticKind::MalformedLine(err) => Some((diagnostic.game_id, diagnostic.round, err.clone())),
+            _ => None,
+        }).collect();
+        assert_eq!(errors.len(), 3);
+        assert_eq!((errors[0].0, errors[0].2.line), (None, 6));
+        assert_eq!((errors[1].0, errors[1].2.kind.clone()), (None, BswParseErrorKind::GameIdMismatch { expected: 2, found: 3 }));
+        assert_eq!((errors[2].0, errors[2].1), (Some(1), Some(0)));
+        assert_eq!((errors[2].2.column, errors[2].2.kind.clone()), (5, BswParseErrorKind::InvalidNumber("99".to_string())));
+        //Game 1 only had the broken round and is deleted, game 2 is dropped with its line, game 3 is untouched.
+        assert!(database.diagnostics_of_game(1).any(|diagnostic| matches!(diagnostic.kind, DiagnosticKind::GameDeleted)));
+        assert_eq!(database.games.iter().map(|game| game.original_bsw_id).collect::<Vec<u32>>(), vec![3]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn database_file_header() {
+        let dir = std::env::temp_dir().join(format!("tichu_database_file_header_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("test.db").display().to_string();
+        let players = vec!["Anna".to_string(), "Ben".to_string()];
+        DataBase { games: Vec::new(), players: players.clone(), diagnostics: Vec::new() }.write(&path).unwrap();
+        let header = DataBase::read_header(&path).unwrap().unwrap();
+        assert_eq!(header.version, SCHEMA_VERSION_CURRENT);
+        assert_eq!(DataBase::read(&path).unwrap().players, players);
+
+        //Corrupting the payload is detected instead of decoding garbage.
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[HEADER_SIZE + 1] ^= 0xFF;
+        std::fs::write(&path, &bytes).unwrap();
+        assert_eq!(DataBase::read(&path).err().unwrap().kind(), std::io::ErrorKind::InvalidData);
+        std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+        assert_eq!(DataBase::read(&path).err().unwrap().kind(), std::io::ErrorKind::InvalidData);
+
+        //Legacy files without header are migrated.
+        std::fs::write(&path, bitcode::encode(&LegacyDataBase { games: Vec::new(), players: players.clone() })).unwrap();
+        assert!(DataBase::read_header(&path).unwrap().is_none());
+        let migrated = DataBase::read(&path).unwrap();
+        assert_eq!(migrated.players, players);
+        assert!(migrated.diagnostics.is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn scored_round(ranks: [Rank; 4], calls: [TichuCall; 4], card_score_team_1: Score) -> Round {
+        let mut round = Round::default();
+        for (player, player_round) in round.player_rounds.iter_mut().enumerate() {
+            player_round.extras |= (player as u64) << 44 | ((card_score_team_1 + 25) as u64) << 54;
+            for other in 0..4 {
+                player_round.extras |= (calls[other] as u64) << (36 + 2 * other) | (ranks[other] as u64) << (46 + 2 * other);
+            }
+        }
+        round
+    }
+
+    #[test]
+    fn database_query() {
+        let game_a = Game {
+            rounds: vec![
+                (scored_round([RANK_1, RANK_2, RANK_3, RANK_4], [CALL_TICHU, CALL_NONE, CALL_NONE, CALL_NONE], 60), RoundLog::default()),
+                (scored_round([RANK_2, RANK_1, RANK_4, RANK_3], [CALL_NONE; 4], 50), RoundLog::default()),
+            ],
+            player_ids: [0, 1, 2, 3],
+            original_bsw_id: 10,
+            parsing_flags: 0,
+        };
+        let mut dragon_round = scored_round([RANK_2, RANK_1, RANK_4, RANK_3], [CALL_NONE; 4], 30);
+        dragon_round.player_rounds[0].first_14 = hand!(DRAGON);
+        let game_b = Game { rounds: vec![(dragon_round, RoundLog::default())], player_ids: [0, 1, 4, 5], original_bsw_id: 11, parsing_flags: FLAG_CHANGED_DRAGON };
+        let db = DataBase { games: vec![game_a, game_b], players: (0..6).map(|player| player.to_string()).collect(), diagnostics: Vec::new() };
+
+        assert_eq!(db.query().count_rounds(), 3);
+        assert_eq!(db.query().with_players(&[4]).count_rounds(), 1);
+        assert_eq!(db.query().only_players([0, 1, 2, 3].into_iter().collect()).games().map(|game| game.original_bsw_id).collect::<Vec<_>>(), vec![10]);
+        assert_eq!(db.query().with_parsing_flags(FLAG_CHANGED_DRAGON).count_rounds(), 1);
+        assert_eq!(db.query().without_parsing_flags(FLAG_CHANGED_DRAGON).round_index(1..).count_rounds(), 1);
+        assert_eq!(db.query().with_call(CALL_TICHU).rounds().map(|view| (view.game.original_bsw_id, view.round_idx)).collect::<Vec<_>>(), vec![(10, 0)]);
+        assert_eq!(db.query().with_call(CALL_NONE).count_rounds(), 2);
+        assert_eq!(db.query().with_player_call(PLAYER_1, CALL_TICHU).count_rounds(), 0);
+        assert_eq!(db.query().with_player_hand(PLAYER_0, |player_round| player_round.first_14 & hand!(DRAGON) != 0).count_rounds(), 1);
+
+        //Game A stands at 160:40 before its second round.
+        let leading = db.query().with_score_before(|(team_1, team_2)| team_1 > team_2);
+        let views: Vec<RoundView> = leading.rounds().collect();
+        assert_eq!(views.len(), 1);
+        assert_eq!((views[0].round_idx, views[0].score_before), (1, (160, 40)));
+        let filtered = leading.to_database();
+        assert_eq!(filtered.games.len(), 1);
+        assert_eq!(filtered.games[0].rounds.len(), 1);
+        assert_eq!(filtered.games[0].rounds[0].0.player_rounds[0].round_score(), (50, 50));
+    }
+
+    #[test]
+    fn parsing_stats() {
+        let mut round = scored_round([RANK_1, RANK_2, RANK_3, RANK_4], [CALL_NONE; 4], 50);
+        round.parsing_flags = FLAG_CHANGED_DRAGON | round::FLAG_CHANGED_ROUND_SCORE;
+        let game = Game { rounds: vec![(round, RoundLog::default())], player_ids: [0, 1, 2, 3], original_bsw_id: 7, parsing_flags: FLAG_CHANGED_DRAGON | game::FLAG_CHANGED_ROUND_SCORE };
+        let mut db = DataBase { games: vec![game], players: (0..4).map(|player| player.to_string()).collect(), diagnostics: Vec::new() };
+        let count = |db: &DataBase, label: &str| match evaluate_parsing_stats(db).get(label) {
+            Some(ReportValue::Integer(count)) => *count,
+            _ => panic!("{} missing", label),
+        };
+        //Without diagnostics, e.g. imported before they were recorded, the flags of the kept rounds are counted.
+        assert!(evaluate_parsing_stats(&db).get("Source").is_some());
+        assert_eq!((count(&db, "Dragon changes"), count(&db, "Round-Result changes with dragon"), count(&db, "Affected games")), (1, 1, 1));
+
+        //The score of round 1 was corrected, but the round was excluded afterwards.
+        let diagnostic = |round: usize, kind: DiagnosticKind| ParseDiagnostic { game_id: Some(7), round: Some(round), kind };
+        let score_changed = || DiagnosticKind::RoundScoreChanged { parsed: (0, 100), calculated: (100, 0), double_win: false, dragon_changed: false };
+        db.diagnostics = vec![diagnostic(0, score_changed()), diagnostic(1, score_changed()), diagnostic(1, DiagnosticKind::RoundNotFinishedPlaying)];
+        assert!(evaluate_parsing_stats(&db).get("Source").is_none());
+        assert_eq!(count(&db, "Round-Result changes"), 1);
+        assert_eq!(count(&db, "Round-Result changes without dragon"), 1);
+        assert_eq!(count(&db, "Excluded rounds due to RoundNotFinishedPlaying"), 1);
+    }
+
+    #[test]
+    fn player_ratings() {
+        //Team 1 wins one round 160:40, team 2 wins 40:160. Ids are out of order to check games are rated by BSW id.
+        let won_round = || scored_round([RANK_1, RANK_2, RANK_3, RANK_4], [CALL_TICHU, CALL_NONE, CALL_NONE, CALL_NONE], 60);
+        let lost_round = || scored_round([RANK_2, RANK_1, RANK_4, RANK_3], [CALL_NONE, CALL_TICHU, CALL_NONE, CALL_NONE], 40);
+        let game = |id: u32, player_ids: [PlayerIDGlobal; 4], won: bool| Game {
+            rounds: vec![(if won { won_round() } else { lost_round() }, RoundLog::default())], player_ids, original_bsw_id: id, parsing_flags: 0,
+        };
+        let db = DataBase {
+            games: vec![game(3, [0, 1, 2, 4], true), game(1, [0, 1, 2, 3], true), game(2, [1, 0, 3, 2], false)],
+            players: (0..5).map(|player| player.to_string()).collect(),
+            diagnostics: Vec::new(),
+        };
+        let config = EloConfig::default();
+        let ratings = PlayerRatings::compute(&db, config);
+        assert_eq!(ratings.games, vec![3, 3, 3, 2, 1]);
+        assert_eq!(ratings.history[0].iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![1, 2, 3]);
+        //The first game is even, winners gain half the provisional k factor.
+        assert!((ratings.history[0][0].1 - (config.initial_rating + config.provisional_k_factor / 2.0)).abs() < 1e-9);
+        assert!(ratings.ratings[0] > ratings.ratings[2] - 1e-9 && ratings.ratings[2] > ratings.ratings[3] && ratings.ratings[3] > ratings.ratings[1]);
+        //Every player is still provisional, so every game moves the same amount of rating from one team to the other.
+        assert!((ratings.ratings.iter().sum::<f64>() - 5.0 * config.initial_rating).abs() < 1e-9);
+        assert_eq!(ratings.rating_before(0, 1, &config), config.initial_rating);
+        assert_eq!(ratings.rating_before(0, 3, &config), ratings.history[0][1].1);
+        assert_eq!(ratings.players_above(config.initial_rating, 3), [0, 2].into_iter().collect());
+        assert!((expected_score(1600.0, 1400.0) + expected_score(1400.0, 1600.0) - 1.0).abs() < 1e-12);
+
+        //Past the provisional games, player 0 wins the last game with the k factor while the new player 4 loses with the provisional one.
+        let config = EloConfig { provisional_games: 1, ..EloConfig::default() };
+        let ratings = PlayerRatings::compute(&db, config);
+        let gain_0 = ratings.ratings[0] - ratings.history[0][1].1;
+        let loss_4 = config.initial_rating - ratings.ratings[4];
+        assert!(gain_0 > 0.0 && (gain_0 / config.k_factor - loss_4 / config.provisional_k_factor).abs() < 1e-9);
+        assert!(ratings.ratings.iter().sum::<f64>() < 5.0 * config.initial_rating - 1.0);
+    }
+
+    #[derive(Default)]
+    struct GameAndRoundCount(usize, usize);
+    impl Accumulator for GameAndRoundCount {
+        fn add_round(&mut self, _view: &RoundView) {
+            self.1 += 1;
+        }
+        fn add_game(&mut self, _game: &Game) {
+            self.0 += 1;
+        }
+        fn merge(&mut self, other: Self) {
+            self.0 += other.0;
+            self.1 += other.1;
+        }
+    }
+
+    #[test]
+    fn map_reduce_matches_sequential() {
+        let mut seed = 0x2545F4914F6CDD1Du64;
+        let games = (0..25).map(|game_id| Game {
+            rounds: (0..3).map(|_| {
+                let (round, round_log, ..) = random_round_log(&mut seed);
+                (round, round_log)
+            }).collect(),
+            player_ids: [0, 1, 2, 3],
+            original_bsw_id: game_id,
+            parsing_flags: 0,
+        }).collect();
+        let db = DataBase { games, players: (0..4).map(|player| player.to_string()).collect(), diagnostics: Vec::new() };
+
+        let mut sequential = BombStats::default();
+        for view in db.query().rounds() {
+            sequential.add_round(&view);
+        }
+        let (counts, calls, strategies, origins) = &sequential;
+        assert!(counts.bombs_round > 0);
+        assert_eq!(calls.round_score_diff_given_bomb.map(|mean| mean.n), counts.bombs_team_rounds);
+        for team in 0..2 {
+            //Every round is in or out of the exchange strategy, every bomb of a team has exactly one origin.
+            assert_eq!(strategies.exch_rounds[team] + strategies.not_exch_rounds[team], counts.rounds);
+            let origin = origins.bomb_spawn_team[team] + origins.bombs_by_partner[team] + origins.bombs_with_one_exch_card[team] + origins.bombs_all_exch[team];
+            assert_eq!(origin, counts.bombs_team_rounds[team]);
+        }
+        for threads in [1, 2, 7, 64] {
+            assert_eq!(map_reduce_with_threads(&db.query(), BombStats::default, threads), sequential);
+        }
+        //Tuples compute several statistics in one pass, games without matching rounds are not visited.
+        let (bombs, GameAndRoundCount(games, rounds)) = map_reduce(&db.query().filter_games(|game| game.original_bsw_id < 20).round_index(1..2), <(BombCounts, GameAndRoundCount)>::default);
+        assert_eq!((bombs.rounds, games, rounds), (20, 20, 20));
+        let GameAndRoundCount(games, rounds) = map_reduce(&db.query().round_index(5..), GameAndRoundCount::default);
+        assert_eq!((games, rounds), (0, 0));
+    }
+
+    #[test]
+    fn statistics_intervals_and_tests() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-4;
+        let (low, high) = Proportion::new(5, 10).wilson_interval(Z_95);
+        assert!(close(low, 0.2366) && close(high, 0.7634));
+        let (low, high) = Proportion::new(0, 10).wilson_interval(Z_95);
+        assert!(low == 0.0 && close(high, 0.2775));
+        assert_eq!(Proportion::new(0, 0).wilson_interval(Z_95), (0.0, 1.0));
+
+        let mut mean = Mean::default();
+        [1.0, 2.0, 3.0, 4.0].iter().for_each(|x| mean.add(*x));
+        assert!(close(mean.mean(), 2.5) && close(mean.variance(), 5.0 / 3.0) && close(mean.standard_error(), 0.6455));
+        let mut merged = Mean::default();
+        merged.add(1.0);
+        merged.add(2.0);
+        let mut rest = Mean::default();
+        rest.add(3.0);
+        rest.add(4.0);
+        merged.merge(rest);
+        assert_eq!(merged, mean);
+        let mut single = Mean::default();
+        single.add(1.0);
+        assert!(single.variance().is_nan() && single.standard_error().is_nan());
+
+        assert!(close(normal_cdf(Z_95), 0.975) && close(normal_cdf(0.0), 0.5));
+        let (z, p_value) = two_proportion_z_test(Proportion::new(45, 100), Proportion::new(30, 100));
+        assert!(close(z, 2.1909) && close(p_value, 0.0285));
+        assert!(two_proportion_z_test(Proportion::new(0, 10), Proportion::new(0, 10)).1.is_nan());
+    }
+
+    #[test]
+    fn analysis_reports() {
+        let mut seed = 0x9E3779B97F4A7C15u64;
+        let games = (0..10).map(|game_id| Game {
+            rounds: (0..2).map(|_| {
+                let (round, round_log, ..) = random_round_log(&mut seed);
+                (round, round_log)
+            }).collect(),
+            player_ids: [0, 1, 2, 3],
+            original_bsw_id: game_id,
+            parsing_flags: 0,
+        }).collect();
+        let db = DataBase { games, players: (0..4).map(|player| player.to_string()).collect(), diagnostics: Vec::new() };
+
+        let report = evaluate_general_stats(&db);
+        assert!(matches!(report.get("Rounds"), Some(ReportValue::Integer(20))));
+        let Some(ReportValue::Proportions(tichu_calls)) = report.get("Tichu Calls") else { panic!("Tichu Calls missing") };
+        assert!(tichu_calls.len() == 4 && tichu_calls.iter().all(|proportion| proportion.trials == 20));
+        assert!(report.to_string().starts_with("Rounds: 20\nTichu Calls: ["));
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["name"], "general_stats");
+        assert_eq!(json["entries"][0], serde_json::json!({"label": "Rounds", "kind": "integer", "value": 20}));
+        assert_eq!(json["entries"][1]["kind"], "proportions");
+        assert_eq!(json["entries"][1]["value"][0]["trials"], 20);
+        assert!(json["entries"][1]["value"][0]["high"].as_f64().unwrap() > 0.0);
+
+        let bomb_report = evaluate_bomb_stats(&db);
+        let json: serde_json::Value = serde_json::from_str(&bomb_report.to_json()).unwrap();
+        let difference = json["entries"].as_array().unwrap().iter().find(|entry| entry["kind"] == "proportion_difference").unwrap();
+        //Every team follows the strategy in these rounds, NaN without trials is written as null.
+        assert_eq!((difference["value"]["a"]["trials"].as_u64(), difference["value"]["b"]["trials"].as_u64()), (Some(40), Some(0)));
+        assert!(difference["value"]["b"]["rate"].is_null() && difference["value"]["z"].is_null());
+
+        let mut report = AnalysisReport::new("test");
+        report.add("Counts", ReportValue::integer_matrix(&[[1usize, 2], [3, 4]]));
+        report.add("Rates", ReportValue::proportions_pairwise(&[1, 0], &[2, 0]));
+        let mut mean = Mean::default();
+        mean.add(1.0);
+        mean.add(3.0);
+        report.add("Means", ReportValue::Means(vec![mean]));
+        assert_eq!(report.to_string(), "Counts: [[1, 2], [3, 4]]\nRates: [50.0000 [9.4531, 90.5469], NaN [0.0000, 100.0000]] (Avg. NaN, Abs: [1, 0], Div: [2, 0])\nMeans: [2.0000 ± 1.9600] (All: 2.0000 ± 1.9600, N: [2])\n");
+        let csv = report.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "label;row;column;value;n;low;high");
+        assert_eq!(lines[4], "Counts;1;1;4;;;");
+        assert!(lines[5].starts_with("Rates;0;;0.5;2;0.0945"));
+        assert_eq!(lines.len(), 8);
+    }
+
+    #[test]
+    fn command_line_interface() {
+        let cli = Cli::try_parse_from(["tichu_rust", "stats", "gt", "--format", "json", "--db", "bsw_filtered.db", "--player", "a", "--player", "b"]).unwrap();
+        assert_eq!(cli.db.to_str(), Some("bsw_filtered.db"));
+        assert_eq!(cli.players, PlayerFilterArgs { players: vec!["a".to_string(), "b".to_string()], min_rating: None, min_games: 0 });
+        assert!(matches!(cli.command, Command::Stats { name: StatsName::Gt, format: OutputFormat::Json, output: None }));
+        let cli = Cli::try_parse_from(["tichu_rust", "--min-rating", "1600", "--min-games", "100", "filter", "out.db"]).unwrap();
+        assert_eq!((cli.players.min_rating, cli.players.min_games), (Some(1600.0), 100));
+        assert!(matches!(cli.command, Command::Filter { ref output } if output == "out.db"));
+        assert!(Cli::try_parse_from(["tichu_rust", "stats", "unknown"]).is_err());
+        assert!(Cli::try_parse_from(["tichu_rust", "replay", "12"]).is_err());
+
+        let mut seed = 0xD1B54A32D192ED03u64;
+        let game = |original_bsw_id: u32, player_ids: [PlayerIDGlobal; 4], seed: &mut u64| {
+            let (round, round_log, ..) = random_round_log(seed);
+            Game { rounds: vec![(round, round_log)], player_ids, original_bsw_id, parsing_flags: 0 }
+        };
+        let games = vec![game(7, [0, 1, 2, 3], &mut seed), game(8, [0, 1, 2, 4], &mut seed)];
+        let db = DataBase { games, players: ["ann", "bob", "cat", "dan", "eve"].map(|name| name.to_string()).to_vec(), diagnostics: Vec::new() };
+
+        let filter = PlayerFilterArgs { players: vec!["eve".to_string()], ..Default::default() };
+        assert_eq!(filter.apply(db.query().to_database()).unwrap().games.iter().map(|game| game.original_bsw_id).collect::<Vec<_>>(), vec![8]);
+        assert!(PlayerFilterArgs { players: vec!["zoe".to_string()], ..Default::default() }.apply(db.query().to_database()).is_err());
+
+        let replayed = replay(&db, 8, 0).unwrap();
+        assert!(replayed.starts_with("Game 8, round 0\nP0: ann (call") && replayed.contains("P3: eve") && replayed.contains("Trick 0"));
+        assert!(replay(&db, 8, 1).is_err() && replay(&db, 9, 0).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use crate::bit_ops::pdep;
+use crate::countable_properties::{CountableDealProperty, CountableProperty, Counter};
+use crate::tichu_hand::{Hand, MASK_ALL};
+
+//Estimates properties on random hands or deals where the exact enumeration of enumerate_hands is too expensive.
+//The results are Counters like the exact ones, with hands_counted = samples. Counter::proportions and Counter::mean give the
+//estimates with their standard errors. The same seed gives the same samples.
+
+//xorshift64* seeded through splitmix64, so that similar seeds give unrelated streams.
+pub struct DealRng {
+    state: u64,
+}
+
+impl DealRng {
+    pub fn new(seed: u64) -> Self {
+        let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+        DealRng { state: if z == 0 { 0x9E3779B97F4A7C15 } else { z } }
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+    //Uniform in 0..n, by multiply and shift. The bias is below n / 2^64.
+    pub fn below(&mut self, n: u64) -> u64 {
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+    //Uniform card of the hand, which must not be empty.
+    pub fn card_of(&mut self, hand: Hand) -> Hand {
+        pdep(1 << self.below(hand.count_ones() as u64), hand)
+    }
+    //Uniform subset with cards cards of available.
+    pub fn subset(&mut self, available: Hand, cards: u32) -> Hand {
+        assert!(available.count_ones() >= cards, "Can not draw {} out of {} cards", cards, available.count_ones());
+        let mut remaining = available;
+        let mut hand = 0;
+        for _ in 0..cards {
+            let card = self.card_of(remaining);
+            hand |= card;
+            remaining ^= card;
+        }
+        hand
+    }
+    //Uniform deal of the full deck into four hands of 14 cards.
+    pub fn deal(&mut self) -> [Hand; 4] {
+        self.deal_from([0; 4])
+    }
+    //Fills up the given partial hands to 14 cards each with the rest of the deck, e.g. around a known first_8 of player 0.
+    pub fn deal_from(&mut self, fixed: [Hand; 4]) -> [Hand; 4] {
+        let mut remaining = MASK_ALL & !fixed.iter().fold(0, |acc, hand| acc | hand);
+        let mut deal = fixed;
+        for hand in deal.iter_mut() {
+            let cards = self.subset(remaining, 14 - hand.count_ones());
+            *hand |= cards;
+            remaining ^= cards;
+        }
+        deal
+    }
+}
+
+//Counts the property on random hands of TARGET_NUM_CARDS cards, the sampled analog of count_special_card_sensitive_property.
+pub fn sample_property<P: CountableProperty, const TARGET_NUM_CARDS: u32>(kind: P, samples: u64, seed: u64) -> Counter<P> {
+    sample_completions::<P, TARGET_NUM_CARDS>(kind, 0, 0, samples, seed)
+}
+
+//Sampled analog of count_completions: random hands of TARGET_NUM_CARDS cards containing the fixed and none of the excluded cards.
+pub fn sample_completions<P: CountableProperty, const TARGET_NUM_CARDS: u32>(
+    kind: P,
+    fixed: Hand,
+    excluded: Hand,
+    samples: u64,
+    seed: u64,
+) -> Counter<P> {
+    assert_eq!(fixed & excluded, 0, "Fixed and excluded cards overlap");
+    assert!(fixed.count_ones() <= TARGET_NUM_CARDS, "More than {} fixed cards", TARGET_NUM_CARDS);
+    let mut rng = DealRng::new(seed);
+    let available = MASK_ALL & !fixed & !excluded;
+    let mut counter = Counter::new(kind);
+    for _ in 0..samples {
+        counter.count_hand(&(fixed | rng.subset(available, TARGET_NUM_CARDS - fixed.count_ones())), 1);
+    }
+    counter
+}
+
+//Counts the property on uniformly random deals.
+pub fn sample_deals<P: CountableDealProperty>(kind: P, samples: u64, seed: u64) -> Counter<P> {
+    sample_deals_from(kind, samples, seed, |rng| rng.deal())
+}
+
+//Counts the property on deals drawn by deal, e.g. with known hands via DealRng::deal_from, or followed by an exchange.
+pub fn sample_deals_from<P: CountableDealProperty>(kind: P, samples: u64, seed: u64, mut deal: impl FnMut(&mut DealRng) -> [Hand; 4]) -> Counter<P> {
+    let mut rng = DealRng::new(seed);
+    let mut counter = Counter::new(kind);
+    for _ in 0..samples {
+        counter.count_deal(&deal(&mut rng));
+    }
+    counter
+}
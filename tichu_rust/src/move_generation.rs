@@ -0,0 +1,297 @@
+use crate::hand;
+use crate::pair_street_detection_trick::is_pair_street_fast;
+use crate::street_detection_tricks::street_lowest_card;
+use crate::tichu_hand::*;
+
+//Generates every legal play of a hand against the current top of the trick (None when leading).
+//Every card combination is generated exactly once, typed with its strongest interpretation, which agrees with TichuHand::hand_type.
+//If a wish (TWO..=ACE) is active and the hand can play a combination containing the wished rank, only those combinations are generated.
+//Moves are appended to moves, so a buffer can be reused in hot loops.
+//Rank masks only decide which cards to combine. Whether the ranks of a street or pair street can be played is checked with
+//is_street_fast and is_pair_street_fast, and full houses are typed by is_fullhouse, the same as in TichuHand::hand_type.
+pub fn generate_legal_moves(hand: Hand, top: Option<&HandType>, wish: Option<CardType>, moves: &mut Vec<(Hand, HandType)>) {
+    let start = moves.len();
+    let ranks = RankMasks::new(hand);
+    match top {
+        None => {
+            if hand & hand!(DOG) != 0u64 {
+                moves.push((hand!(DOG), HandType::Dog));
+            }
+            add_singletons(hand, None, moves);
+            add_pairs(hand, &ranks, SPECIAL_CARD, moves);
+            add_triplets(hand, &ranks, SPECIAL_CARD, moves);
+            add_full_houses(hand, &ranks, SPECIAL_CARD, moves);
+            for length in (4..=14).step_by(2) {
+                add_pair_streets(hand, &ranks, length, None, moves);
+            }
+            for length in 5..=14 {
+                add_streets(hand, &ranks, length, None, moves);
+            }
+        }
+        Some(top_type) => match *top_type {
+            HandType::Dog => return, //The dog is never beaten, its trick ends immediately.
+            HandType::Singleton(_, _) => add_singletons(hand, Some(top_type), moves),
+            HandType::Pairs(card) => add_pairs(hand, &ranks, card, moves),
+            HandType::Triplets(card) => add_triplets(hand, &ranks, card, moves),
+            HandType::FullHouse(_, card) => add_full_houses(hand, &ranks, card, moves),
+            HandType::PairStreet(card, length) => add_pair_streets(hand, &ranks, length, Some(card), moves),
+            HandType::Street(card, length) => add_streets(hand, &ranks, length, Some(card), moves),
+            HandType::Bomb4(_) | HandType::BombStreet(_, _) => {}
+        },
+    }
+    add_bombs(hand, top, moves);
+    if let Some(wish) = wish {
+        restrict_to_wish(hand, wish, start, moves);
+    }
+}
+
+//Ranks of a hand in the yellow column (bit i is CardType i), by how many cards of the rank the hand holds.
+//Only normal cards are considered, so bit SPECIAL_CARD is never set.
+struct RankMasks {
+    at_least_one: Hand,
+    at_least_two: Hand,
+    at_least_three: Hand,
+    phoenix: Hand,
+}
+impl RankMasks {
+    fn new(hand: Hand) -> Self {
+        let yellow = hand & MASK_YELLOW;
+        let blue = (hand >> BLUE) & MASK_YELLOW;
+        let green = (hand >> GREEN) & MASK_YELLOW;
+        let red = (hand >> RED) & MASK_YELLOW;
+        RankMasks {
+            at_least_one: yellow | blue | green | red,
+            at_least_two: (yellow | blue) & (green | red) | yellow & blue | green & red,
+            at_least_three: (yellow & blue) & (green | red) | (green & red) & (yellow | blue),
+            phoenix: hand & hand!(PHOENIX),
+        }
+    }
+    //Ranks for which a combination of size cards can be formed, using the phoenix if available.
+    fn playable(&self, size: u32) -> Hand {
+        let has_phoenix = self.phoenix != 0u64;
+        match (size, has_phoenix) {
+            (1, _) | (2, true) => self.at_least_one,
+            (2, false) | (3, true) => self.at_least_two,
+            (3, false) => self.at_least_three,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[inline(always)]
+fn ranks_above(card: CardType) -> Hand {
+    MASK_YELLOW & !((2u64 << card) - 1)
+}
+
+pub fn fulfills_wish(cards: Hand, wish: CardType) -> bool {
+    wish >= TWO && cards & MASK_FOUR_OF_KIND[wish as usize - 1] != 0u64
+}
+
+fn restrict_to_wish(hand: Hand, wish: CardType, start: usize, moves: &mut Vec<(Hand, HandType)>) {
+    if !fulfills_wish(hand, wish) || !moves[start..].iter().any(|(cards, _)| fulfills_wish(*cards, wish)) {
+        return;
+    }
+    let mut kept = start;
+    for i in start..moves.len() {
+        if fulfills_wish(moves[i].0, wish) {
+            moves.swap(kept, i);
+            kept += 1;
+        }
+    }
+    moves.truncate(kept);
+}
+
+#[inline(always)]
+fn cards_of_rank(hand: Hand, card: CardType) -> Hand {
+    hand & MASK_FOUR_OF_KIND[card as usize - 1]
+}
+
+//Calls f for every subset of mask with exactly size cards. Masks are at most one rank, so at most 16 subsets are visited.
+#[inline(always)]
+fn for_each_subset_of_size<F: FnMut(Hand)>(mask: Hand, size: u32, mut f: F) {
+    let mut subset = mask;
+    loop {
+        if subset.count_ones() == size {
+            f(subset);
+        }
+        if subset == 0u64 {
+            break;
+        }
+        subset = (subset - 1) & mask;
+    }
+}
+
+fn add_singletons(hand: Hand, top: Option<&HandType>, moves: &mut Vec<(Hand, HandType)>) {
+    let mut cards = hand & !hand!(DOG);
+    while cards != 0u64 {
+        let card = cards.pop_some_card();
+        let hand_type = HandType::Singleton(get_singleton_value(card), card);
+        if top.is_none_or(|top_type| hand_type.can_beat(top_type)) {
+            moves.push((hand!(card), hand_type.on_top_of(top)));
+        }
+    }
+}
+
+fn add_pairs(hand: Hand, ranks: &RankMasks, beat: CardType, moves: &mut Vec<(Hand, HandType)>) {
+    let mut candidates = ranks.playable(2) & ranks_above(beat);
+    while candidates != 0u64 {
+        let card = candidates.pop_some_card();
+        let rank_cards = cards_of_rank(hand, card);
+        for_each_subset_of_size(rank_cards, 2, |pair| moves.push((pair, HandType::Pairs(card))));
+        if ranks.phoenix != 0u64 {
+            for_each_subset_of_size(rank_cards, 1, |single| moves.push((single | ranks.phoenix, HandType::Pairs(card))));
+        }
+    }
+}
+
+fn add_triplets(hand: Hand, ranks: &RankMasks, beat: CardType, moves: &mut Vec<(Hand, HandType)>) {
+    let mut candidates = ranks.playable(3) & ranks_above(beat);
+    while candidates != 0u64 {
+        let card = candidates.pop_some_card();
+        let rank_cards = cards_of_rank(hand, card);
+        for_each_subset_of_size(rank_cards, 3, |triplet| moves.push((triplet, HandType::Triplets(card))));
+        if ranks.phoenix != 0u64 {
+            for_each_subset_of_size(rank_cards, 2, |pair| moves.push((pair | ranks.phoenix, HandType::Triplets(card))));
+        }
+    }
+}
+
+fn add_full_houses(hand: Hand, ranks: &RankMasks, beat: CardType, moves: &mut Vec<(Hand, HandType)>) {
+    let phoenix = ranks.phoenix;
+    let mut push = |cards: Hand| moves.push((cards, cards.is_fullhouse().unwrap()));
+    let mut triplet_candidates = ranks.playable(3) & ranks_above(beat);
+    while triplet_candidates != 0u64 {
+        let triplet_card = triplet_candidates.pop_some_card();
+        let triplet_cards = cards_of_rank(hand, triplet_card);
+        let mut pair_candidates = ranks.playable(2) & !hand!(triplet_card);
+        while pair_candidates != 0u64 {
+            let pair_card = pair_candidates.pop_some_card();
+            let pair_cards = cards_of_rank(hand, pair_card);
+            //True triplet with a true pair or a phoenix pair.
+            for_each_subset_of_size(triplet_cards, 3, |triplet| {
+                for_each_subset_of_size(pair_cards, 2, |pair| push(triplet | pair));
+                if phoenix != 0u64 {
+                    for_each_subset_of_size(pair_cards, 1, |single| push(triplet | single | phoenix));
+                }
+            });
+            //Phoenix triplet with a true pair. Two true pairs plus phoenix are only generated with the phoenix in the higher pair.
+            if phoenix != 0u64 && pair_card < triplet_card {
+                for_each_subset_of_size(triplet_cards, 2, |triplet| {
+                    for_each_subset_of_size(pair_cards, 2, |pair| push(triplet | pair | phoenix));
+                });
+            }
+        }
+    }
+}
+
+fn add_pair_streets(hand: Hand, ranks: &RankMasks, length: u8, beat: Option<CardType>, moves: &mut Vec<(Hand, HandType)>) {
+    let pairs = (length / 2) as CardType;
+    let lowest_start = beat.map_or(TWO, |card| card + 1);
+    if lowest_start + pairs - 1 > ACE {
+        return;
+    }
+    for start in lowest_start..=ACE + 1 - pairs {
+        //One pair of every rank, completed by the phoenix if a rank has a single card.
+        let window = ((1u64 << pairs) - 1) << start;
+        if ranks.at_least_one & window != window {
+            continue;
+        }
+        let mut candidate = 0u64;
+        for card in start..start + pairs {
+            let rank_cards = cards_of_rank(hand, card);
+            let lowest = rank_cards & rank_cards.wrapping_neg();
+            let rest = rank_cards ^ lowest;
+            candidate |= lowest | rest & rest.wrapping_neg();
+        }
+        if candidate.count_ones() + 1 == length as u32 {
+            candidate |= ranks.phoenix;
+        }
+        if candidate.count_ones() != length as u32 || is_pair_street_fast(candidate) != Some(start) {
+            continue;
+        }
+        add_pair_streets_recursive(hand, start, start + pairs, 0u64, ranks.phoenix, HandType::PairStreet(start, length), moves);
+    }
+}
+
+fn add_pair_streets_recursive(hand: Hand, card: CardType, end: CardType, played: Hand, phoenix: Hand, hand_type: HandType, moves: &mut Vec<(Hand, HandType)>) {
+    if card == end {
+        moves.push((played, hand_type));
+        return;
+    }
+    let rank_cards = cards_of_rank(hand, card);
+    for_each_subset_of_size(rank_cards, 2, |pair| add_pair_streets_recursive(hand, card + 1, end, played | pair, phoenix, hand_type, moves));
+    if phoenix != 0u64 {
+        for_each_subset_of_size(rank_cards, 1, |single| add_pair_streets_recursive(hand, card + 1, end, played | single | phoenix, 0u64, hand_type, moves));
+    }
+}
+
+fn add_streets(hand: Hand, ranks: &RankMasks, length: u8, beat: Option<CardType>, moves: &mut Vec<(Hand, HandType)>) {
+    //Streets are enumerated over ranks SPECIAL_CARD(Mahjong)..=ACE, the same layout as street_detection_tricks::prepare_hand.
+    let lowest_start = beat.map_or(SPECIAL_CARD, |card| card + 1);
+    if lowest_start + length - 1 > ACE {
+        return;
+    }
+    let present = ranks.at_least_one | (hand & hand!(MAHJONG)) >> MAHJONG;
+    for start in lowest_start..=ACE + 1 - length {
+        //One card of every rank, completed by the phoenix if a rank is missing. Bit SPECIAL_CARD stands for the mahjong.
+        let window = ((1u64 << length) - 1) << start;
+        let mut candidate = present & window & !1u64 | (present & window & 1u64) << MAHJONG;
+        if candidate.count_ones() + 1 == length as u32 {
+            candidate |= ranks.phoenix;
+        }
+        if candidate.count_ones() != length as u32 || street_lowest_card(candidate) != Some(start) {
+            continue;
+        }
+        add_streets_recursive(hand, start, start, start + length, 0u64, ranks.phoenix, moves);
+    }
+}
+
+#[inline(always)]
+fn street_cards_of_rank(hand: Hand, card: CardType) -> Hand {
+    if card == SPECIAL_CARD { hand & hand!(MAHJONG) } else { cards_of_rank(hand, card) }
+}
+
+fn add_streets_recursive(hand: Hand, start: CardType, card: CardType, end: CardType, played: Hand, phoenix: Hand, moves: &mut Vec<(Hand, HandType)>) {
+    if card == end {
+        let length = end - start;
+        let is_flush = [MASK_YELLOW, MASK_BLUE, MASK_GREEN, MASK_RED].iter().any(|color| played & color == played);
+        if !is_flush {
+            moves.push((played, HandType::Street(start, length)));
+        }
+        return;
+    }
+    let mut rank_cards = street_cards_of_rank(hand, card);
+    while rank_cards != 0u64 {
+        let single = hand!(rank_cards.pop_some_card());
+        add_streets_recursive(hand, start, card + 1, end, played | single, phoenix, moves);
+    }
+    //The phoenix can not substitute the mahjong. As the lowest card it is only used if the street can not be extended above instead.
+    if phoenix != 0u64 && card != SPECIAL_CARD && (card != start || end > ACE) {
+        add_streets_recursive(hand, start, card + 1, end, played | phoenix, 0u64, moves);
+    }
+}
+
+fn add_bombs(hand: Hand, top: Option<&HandType>, moves: &mut Vec<(Hand, HandType)>) {
+    let beats_top = |bomb: &HandType| top.is_none_or(|top_type| bomb.can_beat(top_type));
+    for card in TWO..=ACE {
+        let bomb = HandType::Bomb4(card);
+        if hand & MASK_FOUR_OF_KIND[card as usize - 1] == MASK_FOUR_OF_KIND[card as usize - 1] && beats_top(&bomb) {
+            moves.push((MASK_FOUR_OF_KIND[card as usize - 1], bomb));
+        }
+    }
+    for color in [YELLOW, BLUE, GREEN, RED] {
+        let colored = (hand >> color) & MASK_YELLOW;
+        for start in TWO..=ACE - 4 {
+            for end in start + 5..=ACE + 1 {
+                let street = ((1u64 << end) - 1) & !((1u64 << start) - 1);
+                if colored & street != street {
+                    break;
+                }
+                let bomb = HandType::BombStreet(start, end - start);
+                if beats_top(&bomb) {
+                    moves.push((street << color, bomb));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use crate::analysis::statistics::Proportion;
+use crate::bit_ops::pext;
+use crate::countable_properties::{CountableProperty, Counter};
+use crate::enumerate_hands::{count_completions_to_size, default_threads};
+use crate::hand;
+use crate::tichu_hand::*;
+
+//Exact probabilities for the hands of the other three players, given my hand and the cards seen so far.
+//Every distribution of the unseen cards onto the three hands is equally likely. Probabilities are returned as Proportion
+//of hands with the property out of all possible hands of the seat, so successes and trials are exact counts.
+pub type Seat = usize;
+pub const SEAT_LEFT: Seat = 0;
+pub const SEAT_PARTNER: Seat = 1;
+pub const SEAT_RIGHT: Seat = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnseenCards {
+    pool: Hand, //Unseen cards whose seat is unknown
+    known: [Hand; 3], //Unseen cards known to be in a seat
+    hand_sizes: [u32; 3],
+}
+
+impl UnseenCards {
+    //Right after the deal: the other 42 cards are in three hands of 14.
+    pub fn new(my_hand: Hand) -> Self {
+        UnseenCards::with_seen(my_hand, 0, [14; 3])
+    }
+    //seen are the cards played so far, hand_sizes the number of cards left in the hands of the left opponent, partner and right opponent.
+    pub fn with_seen(my_hand: Hand, seen: Hand, hand_sizes: [u32; 3]) -> Self {
+        assert_eq!(my_hand & seen, 0, "Seen cards overlap with my hand");
+        let pool = MASK_ALL & !my_hand & !seen;
+        assert_eq!(pool.count_ones(), hand_sizes.iter().sum::<u32>(), "Hand sizes do not add up to the unseen cards");
+        UnseenCards { pool, known: [0; 3], hand_sizes }
+    }
+    //Cards known to be in a seat, e.g. the cards I passed to it in the exchange.
+    pub fn with_known(mut self, seat: Seat, cards: Hand) -> Self {
+        assert_eq!(cards & !self.pool, 0, "Known cards have to be unseen and not known elsewhere");
+        self.pool ^= cards;
+        self.known[seat] |= cards;
+        assert!(self.known[seat].count_ones() <= self.hand_sizes[seat], "More known cards than cards in the hand");
+        self
+    }
+
+    //Number of cards the seat gets out of the pool.
+    fn cards_drawn(&self, seat: Seat) -> u32 {
+        self.hand_sizes[seat] - self.known[seat].count_ones()
+    }
+    fn possible_hands(&self, seat: Seat) -> u64 {
+        binomial(self.pool.count_ones(), self.cards_drawn(seat))
+    }
+
+    pub fn card_probability(&self, seat: Seat, card: CardIndex) -> Proportion {
+        if self.known[seat] & hand!(card) != 0 {
+            return Proportion::new(1, 1);
+        }
+        if self.pool & hand!(card) == 0 {
+            return Proportion::new(0, 1);
+        }
+        Proportion::new(self.cards_drawn(seat) as usize, self.pool.count_ones() as usize)
+    }
+    //Chance that the left or the right opponent holds the card.
+    pub fn opponents_card_probability(&self, card: CardIndex) -> Proportion {
+        //Both have the same trials: the pool size, or 1 if the card is not in the pool.
+        let (left, right) = (self.card_probability(SEAT_LEFT, card), self.card_probability(SEAT_RIGHT, card));
+        Proportion::new(left.successes + right.successes, left.trials)
+    }
+    //Four of kind or straight bomb.
+    pub fn bomb_probability(&self, seat: Seat) -> Proportion {
+        self.count_accepted(seat, &BombAutomaton)
+    }
+    //Street of the given length with a higher lowest card than Street(lowest, length), also as straight bomb.
+    pub fn beating_street_probability(&self, seat: Seat, lowest: CardType, length: u8) -> Proportion {
+        self.count_accepted(seat, &StreetAutomaton { lowest, length })
+    }
+    //Chance that the left or the right opponent, or both, hold a bomb.
+    pub fn opponents_bomb_probability(&self) -> Proportion {
+        self.count_accepted_by_opponents(&BombAutomaton)
+    }
+    //Chance that the left or the right opponent, or both, can beat Street(lowest, length).
+    pub fn opponents_beating_street_probability(&self, lowest: CardType, length: u8) -> Proportion {
+        self.count_accepted_by_opponents(&StreetAutomaton { lowest, length })
+    }
+    //Distribution of any property of the hand of the seat, by enumerating all of its possible hands.
+    //Only feasible late in the round, right after the deal there are C(42, 14) hands.
+    pub fn seat_distribution<P: CountableProperty + Send + Sync>(&self, seat: Seat, kind: P) -> Counter<P> {
+        let excluded = MASK_ALL & !self.pool & !self.known[seat];
+        count_completions_to_size(kind, self.known[seat], excluded, self.hand_sizes[seat], default_threads())
+    }
+
+    //Counts the hands of the seat accepted by the automaton. Special cards are decided first, then the ranks from two to ace,
+    //tracking the automaton state and the number of cards drawn. The number of states stays small, so this is fast for any hand size.
+    fn count_accepted<A: RankAutomaton>(&self, seat: Seat, automaton: &A) -> Proportion {
+        let fixed = self.known[seat];
+        let drawn = self.cards_drawn(seat);
+        let mut states: HashMap<(A::State, u32), u64> = HashMap::new();
+        for_each_subset(self.pool & MASK_SPECIAL_CARDS, |specials| {
+            if specials.count_ones() <= drawn {
+                *states.entry((automaton.start(fixed | specials), specials.count_ones())).or_default() += 1;
+            }
+        });
+        for rank in TWO..=ACE {
+            let rank_mask = MASK_FOUR_OF_KIND[rank as usize - 1];
+            let next = next_colors(self.pool | fixed, rank);
+            let mut next_states: HashMap<(A::State, u32), u64> = HashMap::new();
+            for ((state, cards), count) in states {
+                for_each_subset(self.pool & rank_mask, |taken| {
+                    let cards = cards + taken.count_ones();
+                    if cards <= drawn {
+                        let colors = pext(fixed | taken, rank_mask) as u8;
+                        *next_states.entry((automaton.forget(automaton.step(state, rank, colors), next), cards)).or_default() += count;
+                    }
+                });
+            }
+            states = next_states;
+        }
+        let accepted: u64 = states.iter().filter(|((state, cards), _)| *cards == drawn && automaton.accepts(*state)).map(|(_, count)| count).sum();
+        Proportion::new(accepted as usize, self.possible_hands(seat) as usize)
+    }
+
+    //Like count_accepted, but every pool card goes to the left opponent, the partner or the right opponent, and the automaton reads
+    //both opponents' hands. Trials are the distributions of the pool onto the three hands. The automata accept for good, so once
+    //one opponent is accepted only the card counts are tracked. The partner gets the pool cards the opponents do not.
+    fn count_accepted_by_opponents<A: RankAutomaton>(&self, automaton: &A) -> Proportion {
+        let (fixed_left, fixed_right) = (self.known[SEAT_LEFT], self.known[SEAT_RIGHT]);
+        let (drawn_left, drawn_partner, drawn_right) = (self.cards_drawn(SEAT_LEFT), self.cards_drawn(SEAT_PARTNER), self.cards_drawn(SEAT_RIGHT));
+        let unless_accepted = |left: A::State, right: A::State| if automaton.accepts(left) || automaton.accepts(right) { None } else { Some((left, right)) };
+        let mut states: OpponentStates<A::State> = HashMap::new();
+        let mut distributed = self.pool & MASK_SPECIAL_CARDS;
+        for_each_distribution(distributed, |left, _, right| {
+            let pair = unless_accepted(automaton.start(fixed_left | left), automaton.start(fixed_right | right));
+            *states.entry((pair, left.count_ones(), right.count_ones())).or_default() += 1;
+        });
+        for rank in TWO..=ACE {
+            let rank_mask = MASK_FOUR_OF_KIND[rank as usize - 1];
+            distributed |= self.pool & rank_mask;
+            let (next_left, next_right) = (next_colors(self.pool | fixed_left, rank), next_colors(self.pool | fixed_right, rank));
+            let mut next_states: OpponentStates<A::State> = HashMap::new();
+            for ((pair, cards_left, cards_right), count) in states {
+                for_each_distribution(self.pool & rank_mask, |left, _, right| {
+                    let (cards_left, cards_right) = (cards_left + left.count_ones(), cards_right + right.count_ones());
+                    if cards_left <= drawn_left && cards_right <= drawn_right && distributed.count_ones() - cards_left - cards_right <= drawn_partner {
+                        let pair = pair.and_then(|(state_left, state_right)| unless_accepted(
+                            automaton.forget(automaton.step(state_left, rank, pext(fixed_left | left, rank_mask) as u8), next_left),
+                            automaton.forget(automaton.step(state_right, rank, pext(fixed_right | right, rank_mask) as u8), next_right),
+                        ));
+                        *next_states.entry((pair, cards_left, cards_right)).or_default() += count;
+                    }
+                });
+            }
+            states = next_states;
+        }
+        let accepted: u64 = states.iter()
+            .filter(|((pair, cards_left, cards_right), _)| pair.is_none() && *cards_left == drawn_left && *cards_right == drawn_right)
+            .map(|(_, count)| count).sum();
+        let pool = self.pool.count_ones();
+        Proportion::new(accepted as usize, (binomial(pool, drawn_left) * binomial(pool - drawn_left, drawn_right)) as usize)
+    }
+}
+
+//States of the left and right opponent, None once one of them is accepted, and the cards drawn by both, with their count.
+type OpponentStates<S> = HashMap<(Option<(S, S)>, u32, u32), u64>;
+
+//Reads a hand rank by rank. colors has one bit per color of the rank that is in the hand, yellow lowest.
+trait RankAutomaton {
+    type State: Copy + Eq + Hash;
+    fn start(&self, specials: Hand) -> Self::State;
+    fn step(&self, state: Self::State, rank: CardType, colors: u8) -> Self::State;
+    fn accepts(&self, state: Self::State) -> bool;
+    //Drops what can not matter anymore, given the colors the hand can still get at the next rank, so that more states merge.
+    fn forget(&self, state: Self::State, _next_colors: u8) -> Self::State {
+        state
+    }
+}
+
+//Colors of the next rank that are known to the seat or still in the pool, none after the ace.
+fn next_colors(possible: Hand, rank: CardType) -> u8 {
+    if rank == ACE { 0 } else { pext(possible, MASK_FOUR_OF_KIND[rank as usize]) as u8 }
+}
+
+struct BombAutomaton;
+//Length of the straight in each color ending at the current rank, None once a bomb is found.
+impl RankAutomaton for BombAutomaton {
+    type State = Option<[u8; 4]>;
+    fn start(&self, _: Hand) -> Self::State {
+        Some([0; 4])
+    }
+    fn step(&self, state: Self::State, _: CardType, colors: u8) -> Self::State {
+        let mut runs = state?;
+        if colors == 0b1111 {
+            return None;
+        }
+        for (color, run) in runs.iter_mut().enumerate() {
+            *run = if colors & (1 << color) != 0 { *run + 1 } else { 0 };
+            if *run == 5 {
+                return None;
+            }
+        }
+        Some(runs)
+    }
+    fn accepts(&self, state: Self::State) -> bool {
+        state.is_none()
+    }
+    //A straight that can not be continued at the next rank is no bomb.
+    fn forget(&self, state: Self::State, next_colors: u8) -> Self::State {
+        let mut runs = state?;
+        for (color, run) in runs.iter_mut().enumerate() {
+            if next_colors & (1 << color) == 0 {
+                *run = 0;
+            }
+        }
+        Some(runs)
+    }
+}
+
+struct StreetAutomaton {
+    lowest: CardType,
+    length: u8,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct StreetState {
+    found: bool,
+    phoenix: bool,
+    run: u8, //Ranks in a row ending at the current rank
+    run_with_phoenix: u8, //Longest window ending at the current rank with at most one missing rank, if the Phoenix is in the hand
+}
+impl RankAutomaton for StreetAutomaton {
+    type State = StreetState;
+    fn start(&self, specials: Hand) -> Self::State {
+        StreetState { found: false, phoenix: specials & hand!(PHOENIX) != 0, run: 0, run_with_phoenix: 0 }
+    }
+    fn step(&self, state: Self::State, rank: CardType, colors: u8) -> Self::State {
+        if state.found {
+            return state;
+        }
+        let (run, run_with_phoenix) = if colors != 0 {
+            (state.run + 1, if state.phoenix { state.run_with_phoenix + 1 } else { 0 })
+        } else {
+            (0, if state.phoenix { state.run + 1 } else { 0 })
+        };
+        let (run, run_with_phoenix) = (run.min(self.length), run_with_phoenix.min(self.length));
+        //The window of the last length ranks starts above the lowest card of the street to beat.
+        let found = rank >= self.lowest + self.length && (run == self.length || run_with_phoenix == self.length);
+        StreetState { found, phoenix: state.phoenix, run, run_with_phoenix }
+    }
+    fn accepts(&self, state: Self::State) -> bool {
+        state.found
+    }
+}
+
+fn for_each_subset(set: Hand, mut f: impl FnMut(Hand)) {
+    let mut subset = set;
+    loop {
+        f(subset);
+        if subset == 0 {
+            break;
+        }
+        subset = (subset - 1) & set;
+    }
+}
+
+//Splits the cards into the cards of the left opponent, the partner and the right opponent in every possible way.
+fn for_each_distribution(cards: Hand, mut f: impl FnMut(Hand, Hand, Hand)) {
+    for_each_subset(cards, |left| for_each_subset(cards ^ left, |right| f(left, cards ^ left ^ right, right)));
+}
+
+pub fn binomial(n: u32, k: u32) -> u64 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k) as u128;
+    (0..k).fold(1u128, |acc, i| acc * (n as u128 - i) / (i + 1)) as u64
+}
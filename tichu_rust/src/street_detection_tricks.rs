@@ -10,6 +10,12 @@ pub const fn is_street_fast(hand: Hand) -> Option<CardType> { //Returns lowest c
         None
     }
 }
+pub const fn street_lowest_card(hand: Hand) -> Option<CardType> { //Lowest card as typed by hand_type, the phoenix is only the lowest card if it can not extend the street above the ace.
+    match is_street_fast(hand) {
+        Some(card) if card + hand.count_ones() as CardType - 1 > ACE => Some(card - 1),
+        lowest => lowest,
+    }
+}
 pub const fn phoenix_used_as_street_extension(hand: Hand) -> bool { //Returns true only for streets that contain the phoenix and for which the phoenix does not fill a hole.
     if hand & hand!(PHOENIX) == 0 {
         return false;
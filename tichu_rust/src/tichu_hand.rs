@@ -1,8 +1,10 @@
+use bitcode::{Decode, Encode};
 use colored::Colorize;
 use phf::phf_map;
 use crate::bsw_binary_format::binary_format_constants::Score;
 use crate::pair_street_detection_trick::is_pair_street_fast;
-use crate::street_detection_tricks::{is_street_fast};
+use crate::street_detection_tricks::street_lowest_card;
+use crate::move_generation::generate_legal_moves;
 
 // Generic trait each datastructure for a Tichu Hand should implement
 pub trait TichuHand {
@@ -20,6 +22,8 @@ pub trait TichuHand {
     fn get_high_card_amt(&self) -> u32;
 
     fn count_triplets(&self) -> u32;
+
+    fn legal_moves(&self, top: Option<&HandType>, wish: Option<CardType>) -> Vec<(Hand, HandType)>; //top is None when leading
 }
 
 // Actual data structure we use is a u64:
@@ -212,6 +216,14 @@ static CARD_TO_CHAR: phf::Map<CardIndex, &'static str> = phf_map! {
     12u8 => "K",
     13u8 => "A"
 }; //If this is used only in non-speed related things, might be worth it to kick out the dependency and just use match.
+//Card type without color, e.g. for labels in machine-readable output.
+pub fn card_to_string(card: CardIndex) -> String {
+    if (1u64 << card) & MASK_SPECIAL_CARDS != 0u64 {
+        CARD_TO_CHAR[&card].to_string()
+    } else {
+        CARD_TO_CHAR[&(get_card_type(card))].to_string()
+    }
+}
 pub fn card_to_colored_string(card: CardIndex) -> String {
     if (1u64 << card) & MASK_SPECIAL_CARDS != 0u64 {
         CARD_TO_CHAR[&card].to_string()
@@ -263,10 +275,26 @@ pub const TRICK_BOMB12: TrickType = 30;
 pub const TRICK_BOMB13: TrickType = 31;
 
 
-#[derive(PartialEq, Debug)]
+//Value of a singleton in half ranks, so that a phoenix can be half a rank above the card it is played on.
+pub type SingletonValue = u8;
+pub const SINGLETON_MAHJONG: SingletonValue = 0;
+pub const SINGLETON_PHOENIX_LEAD: SingletonValue = 1; //A led phoenix counts as 1.5, between the mahjong and a two.
+pub const SINGLETON_DRAGON: SingletonValue = 2 * ACE + 2;
+
+pub const fn get_singleton_value(card: CardIndex) -> SingletonValue {
+    //The phoenix is valued as led, see HandType::on_top_of for a phoenix played on another singleton.
+    match card {
+        MAHJONG => SINGLETON_MAHJONG,
+        PHOENIX => SINGLETON_PHOENIX_LEAD,
+        DRAGON => SINGLETON_DRAGON,
+        _ => 2 * get_card_type(card),
+    }
+}
+
+#[derive(Encode, Decode, PartialEq, Eq, Hash, Debug, Clone, Copy)]
 pub enum HandType {
     Dog,
-    Singleton(CardType, CardIndex),
+    Singleton(SingletonValue, CardIndex),
     Pairs(CardType),
     Triplets(CardType),
     PairStreet(CardType, u8), //Value of lowest pair, length
@@ -278,8 +306,9 @@ pub enum HandType {
 impl HandType {
     pub fn is_bigger_than_same_handtype(&self, other: &HandType) -> bool {
         match (other, self) {
-            (HandType::Singleton(c1, c1_idx), HandType::Singleton(c2, c2_idx)) => {
-                c1 < c2 || (*c1 > 0 || *c1_idx == MAHJONG) && *c2_idx == PHOENIX || *c1 > 0 && *c2_idx == DRAGON || (*c1_idx == PHOENIX || *c1_idx == MAHJONG) && *c2_idx == DRAGON
+            (HandType::Singleton(v1, c1_idx), HandType::Singleton(v2, c2_idx)) => {
+                //The phoenix beats every singleton but the dragon, it takes its value only once played (see on_top_of).
+                if *c2_idx == PHOENIX { *c1_idx != DRAGON } else { v1 < v2 }
             }
             (HandType::Pairs(c1), HandType::Pairs(c2)) => c1 < c2,
             (HandType::Triplets(c1), HandType::Triplets(c2)) => c1 < c2,
@@ -291,6 +320,25 @@ impl HandType {
             (_, _) => unreachable!()
         }
     }
+    //Whether self can be played on top of top, for every combination of hand types.
+    //Bombs beat every non bomb, longer straight bombs beat shorter ones and straight bombs beat four of a kind. Nothing is played on or with the dog.
+    pub fn can_beat(&self, top: &HandType) -> bool {
+        let (trick_type, top_trick_type) = (self.get_trick_type(), top.get_trick_type());
+        if trick_type == TRICK_DOG || top_trick_type == TRICK_DOG {
+            return false;
+        }
+        if trick_type >= TRICK_BOMB4 && top_trick_type < TRICK_BOMB4 {
+            return true;
+        }
+        top_trick_type >= TRICK_BOMB4 && trick_type > top_trick_type || trick_type == top_trick_type && self.is_bigger_than_same_handtype(top)
+    }
+    //Effective hand type when played on top: a phoenix singleton is worth half a rank more than the singleton it is played on.
+    pub fn on_top_of(&self, top: Option<&HandType>) -> HandType {
+        match (self, top) {
+            (HandType::Singleton(_, PHOENIX), Some(HandType::Singleton(value, _))) => HandType::Singleton(value + 1, PHOENIX),
+            _ => *self,
+        }
+    }
     pub fn matches_trick_type(&self, trick_type: TrickType) -> bool {
         let self_trick_type = self.get_trick_type();
         self_trick_type < TRICK_BOMB4 && self_trick_type == trick_type || self_trick_type >= TRICK_BOMB4 && trick_type <= self_trick_type
@@ -322,7 +370,7 @@ impl TichuHand for Hand {
             if card == DOG {
                 return Some(HandType::Dog);
             } else {
-                return Some(HandType::Singleton(get_card_type(card), card));
+                return Some(HandType::Singleton(get_singleton_value(card), card));
             }
         }
         if cards == 2 {
@@ -368,14 +416,11 @@ impl TichuHand for Hand {
             }
         }
         //Can be street
-        if let Some(mut card_type) = is_street_fast(*self) {
+        if let Some(card_type) = street_lowest_card(*self) {
             //Need to check if its a bomb street
             if (self & MASK_YELLOW).count_ones() == cards || (self & MASK_BLUE).count_ones() == cards || (self & MASK_GREEN).count_ones() == cards || (self & MASK_RED).count_ones() == cards {
                 return Some(HandType::BombStreet(card_type, cards as u8));
             } else {
-                if card_type + cards as u8 - 1 > ACE {
-                    card_type -= 1; //Phoenix normally appended to top, but not if It can't :D
-                }
                 return Some(HandType::Street(card_type, cards as u8));
             }
         }
@@ -496,4 +541,10 @@ impl TichuHand for Hand {
         //bombs are counted twice!
         true_triplets.count_ones()
     }
+
+    fn legal_moves(&self, top: Option<&HandType>, wish: Option<CardType>) -> Vec<(Hand, HandType)> {
+        let mut moves = Vec::new();
+        generate_legal_moves(*self, top, wish, &mut moves);
+        moves
+    }
 }